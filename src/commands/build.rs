@@ -7,17 +7,98 @@ use std::process::Command;
 
 use crate::config::images::ImageConfig;
 use crate::config::settings::Settings;
-use crate::utils::ContainerRuntime;
+use crate::k8s::target::KubeTarget;
+use crate::utils::{ContainerRuntime, KueueDevError, Prerequisite};
 
 /// Valid component names that can be built
 const VALID_COMPONENTS: &[&str] = &["operator", "operand", "must-gather", "bundle"];
 
+/// How images are built and pushed
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum BuildMode {
+    /// Plain `build` for the host platform, then `push`
+    Native,
+    /// `docker buildx build --platform <list> --push`; the image list is pushed by the build
+    Buildx(String),
+    /// `podman build --platform <list> --manifest`, then `podman manifest push --all`
+    PodmanManifest(String),
+}
+
+impl BuildMode {
+    /// Pick the build mode for the requested platforms
+    fn select(
+        runtime: &ContainerRuntime,
+        platforms: &[String],
+        buildx_available: bool,
+    ) -> Result<Self> {
+        let platforms: Vec<&str> = platforms
+            .iter()
+            .map(|p| p.trim())
+            .filter(|p| !p.is_empty())
+            .collect();
+        if platforms.is_empty() {
+            return Ok(BuildMode::Native);
+        }
+        let list = platforms.join(",");
+
+        match runtime {
+            ContainerRuntime::Docker if buildx_available => Ok(BuildMode::Buildx(list)),
+            ContainerRuntime::Docker => Err(anyhow::anyhow!(
+                "--platform {} requires docker buildx, which was not found.\n\
+                 Install the buildx plugin (https://docs.docker.com/build/install-buildx/) \
+                 or build with podman instead",
+                list
+            )),
+            ContainerRuntime::Podman => Ok(BuildMode::PodmanManifest(list)),
+        }
+    }
+}
+
+/// Whether the docker buildx plugin is installed
+fn docker_buildx_available() -> bool {
+    Command::new("docker")
+        .args(["buildx", "version"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Options for `images build`
+#[derive(Debug, Clone, Default)]
+pub struct BuildOptions {
+    /// Components to build; empty builds all of them
+    pub components: Vec<String>,
+    /// Images file (defaults to the config file setting)
+    pub images_file: Option<String>,
+    pub parallel: bool,
+    /// Registry to also push a retagged copy of each image to
+    pub push_to: Option<String>,
+    /// Push the retagged copy to the local registry started by `registry start`
+    pub local_registry: bool,
+    /// Target platforms for a multi-arch build
+    pub platforms: Vec<String>,
+    /// Suffix appended to every configured image tag
+    pub tag_suffix: Option<String>,
+    /// Use the operator source's short git SHA as the tag suffix
+    pub tag_sha: bool,
+    /// Only build the images locally
+    pub no_push: bool,
+}
+
 /// Build and push container images
-pub fn build_and_push(
-    components: Vec<String>,
-    images_file: Option<String>,
-    parallel: bool,
-) -> Result<()> {
+pub fn build_and_push(options: BuildOptions) -> Result<()> {
+    let BuildOptions {
+        components,
+        images_file,
+        parallel,
+        push_to,
+        local_registry,
+        platforms,
+        tag_suffix,
+        tag_sha,
+        no_push,
+    } = options;
+
     // Load settings BEFORE changing directories
     // This ensures we read the config from where the user is running the command
     let images_file_path = if let Some(path) = images_file {
@@ -30,7 +111,30 @@ pub fn build_and_push(
     // Ensure we're in the operator source directory
     let source_path = crate::utils::ensure_operator_source_directory()?;
 
-    crate::log_info!("Building and pushing container images...");
+    if no_push && (push_to.is_some() || local_registry) {
+        return Err(anyhow::anyhow!(
+            "--push-to and --local-registry cannot be combined with --no-push"
+        ));
+    }
+    let push_to = if local_registry {
+        let registry = crate::k8s::registry::LocalRegistry::load().ok_or_else(|| {
+            anyhow::anyhow!("No local registry found. Start one with: kueue-dev registry start")
+        })?;
+        Some(registry.address())
+    } else {
+        push_to
+    };
+    if no_push && !platforms.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Multi-arch builds are pushed as part of the build; --platform cannot be combined with --no-push"
+        ));
+    }
+
+    if no_push {
+        crate::log_info!("Building container images (not pushing)...");
+    } else {
+        crate::log_info!("Building and pushing container images...");
+    }
     crate::log_info!("Kueue source path: {}", source_path.display());
 
     // Default to all components if none specified
@@ -56,51 +160,297 @@ pub fn build_and_push(
 
     // Load image configuration
     let images_path = PathBuf::from(&images_file_path);
-    let image_config = ImageConfig::load(&images_path).with_context(|| {
+    let mut image_config = ImageConfig::load(&images_path).with_context(|| {
         format!(
             "Failed to load image configuration from {}",
             images_file_path
         )
     })?;
 
+    let tag_suffix = if tag_sha {
+        Some(git_short_sha()?)
+    } else {
+        tag_suffix
+    };
+    if let Some(suffix) = &tag_suffix {
+        crate::log_info!("Appending tag suffix: {}", suffix);
+        image_config = image_config.with_tag_suffix(suffix)?;
+
+        if components.iter().any(|c| c == "bundle") {
+            crate::log_warn!(
+                "The bundle's related images come from {} and do not include the tag suffix",
+                images_file_path
+            );
+        }
+    }
+
     // Detect container runtime
     let runtime = ContainerRuntime::detect()?;
     crate::log_info!("Using container runtime: {}", runtime);
 
+    let buildx_available = matches!(runtime, ContainerRuntime::Docker)
+        && !platforms.is_empty()
+        && docker_buildx_available();
+    let mode = BuildMode::select(&runtime, &platforms, buildx_available)?;
+    match &mode {
+        BuildMode::Native => {}
+        BuildMode::Buildx(list) => crate::log_info!("Building for {} with docker buildx", list),
+        BuildMode::PodmanManifest(list) => {
+            crate::log_info!("Building for {} into podman manifest lists", list)
+        }
+    }
+
+    let push_to = push_to.as_deref();
+    if let Some(registry) = push_to {
+        crate::log_info!("Also pushing a copy of each image to: {}", registry);
+    }
+
     if parallel {
         crate::log_info!("Building components in parallel...");
-        build_parallel(&components, &image_config, &runtime, &images_file_path)?;
+        build_parallel(
+            &components,
+            &image_config,
+            &runtime,
+            &mode,
+            &images_file_path,
+            !no_push,
+            push_to,
+        )?;
     } else {
         // Build and push each component sequentially
         for component in &components {
-            build_and_push_component(component, &image_config, &runtime, &images_file_path)?;
+            build_and_push_component(
+                component,
+                &image_config,
+                &runtime,
+                &mode,
+                &images_file_path,
+                !no_push,
+                push_to,
+            )?;
         }
     }
 
     crate::log_info!("");
     crate::log_info!("==========================================");
-    crate::log_info!("All images built and pushed successfully!");
+    if no_push {
+        crate::log_info!("All images built successfully!");
+    } else {
+        crate::log_info!("All images built and pushed successfully!");
+    }
     crate::log_info!("==========================================");
     crate::log_info!("");
 
+    // With a suffix the tags differ from the images file, so show what to deploy
+    if tag_suffix.is_some() {
+        println!("Built images:");
+        for component in &components {
+            println!("  {}: {}", component, image_config.get(component)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Operand deployment restarted by [`rebuild_operand`]
+const OPERAND_DEPLOYMENT: &str = "kueue-controller-manager";
+
+/// Build the operand image without pushing, load it into a kind cluster and
+/// restart the kueue-controller-manager deployment so it picks up the new image
+pub fn rebuild_operand(
+    cluster_name: &str,
+    images_file: Option<String>,
+    namespace: Option<String>,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    // Resolve paths and settings BEFORE the build changes into the operator source
+    let settings = Settings::load()?;
+    let images_file = images_file.unwrap_or_else(|| settings.defaults.images_file.clone());
+    let namespace = namespace.unwrap_or_else(|| settings.kueue.namespace.clone());
+    // Restart on the kind cluster the image is loaded into, not the current context
+    let kubeconfig = crate::commands::deploy::resolve_kubeconfig(
+        &kubeconfig.map(|kc| kc.to_string_lossy().into_owned()),
+        cluster_name,
+    )?;
+
+    let cluster =
+        crate::k8s::kind::KindCluster::new(cluster_name, crate::k8s::kind::CniProvider::Calico);
+    let mut target = KubeTarget::new(Some(&kubeconfig));
+    target.context.get_or_insert_with(|| cluster.context_name());
+    if !cluster.exists()? {
+        return Err(anyhow::anyhow!(
+            "Cluster '{}' does not exist. Create it first with: kueue-dev cluster create --name {}",
+            cluster_name,
+            cluster_name
+        ));
+    }
+
+    build_and_push(BuildOptions {
+        components: vec!["operand".to_string()],
+        images_file: Some(images_file.clone()),
+        no_push: true,
+        ..Default::default()
+    })?;
+
+    // build_and_push resolved the images file from the operator source directory
+    let image_config = ImageConfig::load(&PathBuf::from(&images_file))?;
+    let operand = image_config.operand()?;
+
+    let runtime = ContainerRuntime::detect()?;
+    crate::log_info!(
+        "Loading {} into kind cluster '{}'...",
+        operand,
+        cluster_name
+    );
+    runtime.load_to_kind(operand, cluster_name)?;
+
+    crate::k8s::kubectl::rollout_restart(OPERAND_DEPLOYMENT, &namespace, "300s", &target)?;
+
+    crate::log_info!("Operand rebuilt and restarted: {}", operand);
+    Ok(())
+}
+
+/// Options for `images catalog`
+#[derive(Debug, Clone, Default)]
+pub struct CatalogOptions {
+    /// Images file (defaults to the config file setting)
+    pub images_file: Option<String>,
+    /// Catalog image to build; defaults to the `catalog` entry of the images file
+    pub tag: Option<String>,
+    /// Existing index image to add the bundle to
+    pub from_index: Option<String>,
+    /// Only build the catalog image locally
+    pub no_push: bool,
+}
+
+/// Build an OLM catalog (index) image containing the bundle image with
+/// `opm index add`, then push it unless `no_push` is set
+pub fn build_catalog(options: CatalogOptions) -> Result<()> {
+    let CatalogOptions {
+        images_file,
+        tag,
+        from_index,
+        no_push,
+    } = options;
+
+    let opm = crate::utils::CommonPrereqs::opm();
+    if opm.check().is_err() {
+        return Err(anyhow::anyhow!(
+            "opm is required to build a catalog image but was not found in PATH.
+{}",
+            opm.install_hint()
+        ));
+    }
+
+    let images_file = match images_file {
+        Some(path) => path,
+        None => Settings::load()?.defaults.images_file,
+    };
+    let image_config = ImageConfig::load(&PathBuf::from(&images_file))
+        .with_context(|| format!("Failed to load image configuration from {}", images_file))?;
+    let bundle = image_config.bundle()?;
+    let tag = match tag {
+        Some(tag) => tag,
+        None => image_config.get("catalog").map(str::to_string).context(
+            "No catalog image given. Pass --tag or add a 'catalog' entry to the images file",
+        )?,
+    };
+
+    let runtime = ContainerRuntime::detect()?;
+    crate::log_info!("Building catalog image {} from bundle {}...", tag, bundle);
+    if let Some(index) = &from_index {
+        crate::log_info!("Adding to existing index {}", index);
+    }
+
+    let output = Command::new("opm")
+        .args(opm_index_add_args(
+            bundle,
+            &tag,
+            from_index.as_deref(),
+            &runtime,
+        ))
+        .output()
+        .context("Failed to run opm index add")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        check_disk_full(&stderr, &format!("Catalog build for {}", tag), "opm")?;
+        return Err(anyhow::anyhow!(
+            "opm index add failed for {}:\n{}\n\
+             The bundle image must be pullable; build and push it with: kueue-dev images build bundle",
+            tag,
+            stderr
+        ));
+    }
+
+    if no_push {
+        crate::log_info!("Catalog image built: {}", tag);
+    } else {
+        crate::log_info!("Pushing {}...", tag);
+        push_image(&runtime, &tag)?;
+        crate::log_info!("Catalog image built and pushed: {}", tag);
+    }
     Ok(())
 }
 
+/// Arguments for `opm index add` building `tag` from `bundle`
+fn opm_index_add_args(
+    bundle: &str,
+    tag: &str,
+    from_index: Option<&str>,
+    runtime: &ContainerRuntime,
+) -> Vec<String> {
+    let mut args = vec![
+        "index".to_string(),
+        "add".to_string(),
+        "--bundles".to_string(),
+        bundle.to_string(),
+        "--tag".to_string(),
+        tag.to_string(),
+        "--container-tool".to_string(),
+        runtime.command().to_string(),
+    ];
+    if let Some(index) = from_index {
+        args.push("--from-index".to_string());
+        args.push(index.to_string());
+    }
+    args
+}
+
+/// Short SHA of HEAD in the current (operator source) directory
+fn git_short_sha() -> Result<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .context("Failed to run git rev-parse")?;
+
+    if !output.status.success() {
+        return Err(anyhow::anyhow!(
+            "git rev-parse --short HEAD failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
 /// Build and push components in parallel
 fn build_parallel(
     components: &[String],
     image_config: &ImageConfig,
     runtime: &ContainerRuntime,
+    mode: &BuildMode,
     images_file_path: &str,
+    push: bool,
+    push_to: Option<&str>,
 ) -> Result<()> {
-    use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+    use indicatif::{ProgressBar, ProgressStyle};
     use std::sync::{Arc, Mutex};
 
     // Set initial progress (0% complete)
     send_progress_update(0, components.len());
 
     // Create multi-progress for coordinating multiple progress bars
-    let multi_progress = Arc::new(MultiProgress::new());
+    let multi_progress = Arc::new(crate::utils::progress::create_multi_progress());
 
     // Shared error collection
     let errors: Arc<Mutex<Vec<String>>> = Arc::new(Mutex::new(Vec::new()));
@@ -120,7 +470,7 @@ fn build_parallel(
             let images_file_path = images_file_path.to_string();
 
             let handle = s.spawn(move || {
-                use owo_colors::OwoColorize;
+                use colored::Colorize;
 
                 // Create progress indicator for this component
                 let pb = mp.add(ProgressBar::new(4));
@@ -143,8 +493,11 @@ fn build_parallel(
                     &component,
                     image_config,
                     runtime,
+                    mode,
                     &pb,
                     &images_file_path,
+                    push,
+                    push_to,
                 ) {
                     Ok(_) => {
                         // Change style to remove spinner, keep only message
@@ -203,21 +556,29 @@ fn build_parallel(
 }
 
 /// Build and push a single component with progress tracking
+#[allow(clippy::too_many_arguments)]
 fn build_and_push_component_with_progress(
     component: &str,
     image_config: &ImageConfig,
     runtime: &ContainerRuntime,
+    mode: &BuildMode,
     pb: &indicatif::ProgressBar,
     images_file_path: &str,
+    push: bool,
+    push_to: Option<&str>,
 ) -> Result<()> {
-    use owo_colors::OwoColorize;
+    use colored::Colorize;
+
+    // Pushing a copy to another registry is an extra step
+    let steps = if push_to.is_some() { 5 } else { 4 };
+    pb.set_length(steps);
 
     // Step 1: Get image configuration
     pb.set_position(0);
     pb.set_message(format!(
         "{} {}",
         component.bright_blue().bold(),
-        "[1/4] Loading config...".dimmed()
+        format!("[1/{}] Loading config...", steps).dimmed()
     ));
 
     let image_tag = match component {
@@ -233,7 +594,7 @@ fn build_and_push_component_with_progress(
     pb.set_message(format!(
         "{} {}",
         component.bright_blue().bold(),
-        "[2/4] Locating Dockerfile...".dimmed()
+        format!("[2/{}] Locating Dockerfile...", steps).dimmed()
     ));
     let (dockerfile, context) = get_dockerfile_and_context(component)?;
 
@@ -242,22 +603,35 @@ fn build_and_push_component_with_progress(
     pb.set_message(format!(
         "{} {}",
         component.bright_blue().bold(),
-        "[3/4] Building image...".yellow()
+        format!("[3/{}] Building image...", steps).yellow()
     ));
     pb.enable_steady_tick(std::time::Duration::from_millis(100));
 
     // Get build args for this component
     let build_args = get_build_args(component, image_config, images_file_path)?;
-    build_image(runtime, &dockerfile, &context, image_tag, &build_args)?;
+    build_image(runtime, mode, &dockerfile, &context, image_tag, &build_args)?;
 
     // Step 4: Push the image
     pb.inc(1);
-    pb.set_message(format!(
-        "{} {}",
-        component.bright_blue().bold(),
-        "[4/4] Pushing image...".yellow()
-    ));
-    push_image(runtime, image_tag)?;
+    if push {
+        pb.set_message(format!(
+            "{} {}",
+            component.bright_blue().bold(),
+            format!("[4/{}] Pushing image...", steps).yellow()
+        ));
+        push_built_image(runtime, mode, image_tag)?;
+    }
+
+    // Step 5: Push a copy to the extra registry
+    if let Some(registry) = push_to {
+        pb.inc(1);
+        pb.set_message(format!(
+            "{} {}",
+            component.bright_blue().bold(),
+            format!("[5/{}] Pushing copy to {}...", steps, registry).yellow()
+        ));
+        retag_and_push(runtime, mode, image_tag, registry)?;
+    }
 
     pb.inc(1);
     Ok(())
@@ -268,7 +642,10 @@ fn build_and_push_component(
     component: &str,
     image_config: &ImageConfig,
     runtime: &ContainerRuntime,
+    mode: &BuildMode,
     images_file_path: &str,
+    push: bool,
+    push_to: Option<&str>,
 ) -> Result<()> {
     crate::log_info!("");
     crate::log_info!("==========================================");
@@ -299,12 +676,19 @@ fn build_and_push_component(
     }
 
     // Build the image
-    build_image(runtime, &dockerfile, &context, image_tag, &build_args)?;
+    build_image(runtime, mode, &dockerfile, &context, image_tag, &build_args)?;
 
-    // Push the image
-    push_image(runtime, image_tag)?;
+    if push {
+        push_built_image(runtime, mode, image_tag)?;
+        crate::log_info!("Successfully built and pushed: {}", image_tag);
+    } else {
+        crate::log_info!("Successfully built: {}", image_tag);
+    }
 
-    crate::log_info!("Successfully built and pushed: {}", image_tag);
+    if let Some(registry) = push_to {
+        let target = retag_and_push(runtime, mode, image_tag, registry)?;
+        crate::log_info!("Successfully pushed copy: {}", target);
+    }
 
     Ok(())
 }
@@ -370,9 +754,20 @@ fn get_dockerfile_and_context(component: &str) -> Result<(PathBuf, PathBuf)> {
     }
 }
 
+/// Build a Dockerfile for the host platform with the container runtime
+pub fn build_local_image(
+    runtime: &ContainerRuntime,
+    dockerfile: &Path,
+    context: &Path,
+    tag: &str,
+) -> Result<()> {
+    build_image(runtime, &BuildMode::Native, dockerfile, context, tag, &[])
+}
+
 /// Build a container image
 fn build_image(
     runtime: &ContainerRuntime,
+    mode: &BuildMode,
     dockerfile: &Path,
     context: &Path,
     tag: &str,
@@ -382,26 +777,29 @@ fn build_image(
 
     let runtime_cmd = runtime.command();
 
-    let mut cmd = Command::new(runtime_cmd);
-    cmd.args(["build", "-f"]).arg(dockerfile).args(["-t", tag]);
-
-    // Add build arguments
-    for (key, value) in build_args {
-        cmd.arg("--build-arg");
-        cmd.arg(format!("{}={}", key, value));
+    // --manifest adds to an existing list, so start from a fresh one
+    if matches!(mode, BuildMode::PodmanManifest(_)) {
+        Command::new(runtime_cmd)
+            .args(["manifest", "rm", tag])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .ok();
     }
 
-    cmd.arg(context)
+    let output = Command::new(runtime_cmd)
+        .args(build_command_args(
+            mode, dockerfile, context, tag, build_args,
+        ))
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-
-    let output = cmd
+        .stderr(Stdio::piped())
         .output()
         .with_context(|| format!("Failed to execute {} build command", runtime_cmd))?;
 
     if !output.status.success() {
         // On error, always show stderr
         let stderr = String::from_utf8_lossy(&output.stderr);
+        check_disk_full(&stderr, &format!("Image build for {}", tag), runtime_cmd)?;
         return Err(anyhow::anyhow!(
             "Image build failed for {}:\n{}",
             tag,
@@ -412,6 +810,127 @@ fn build_image(
     Ok(())
 }
 
+/// Turn a disk-full failure buried in runtime output into an error that says
+/// so up front, with cleanup suggestions
+fn check_disk_full(stderr: &str, operation: &str, runtime_cmd: &str) -> Result<()> {
+    let lines = crate::utils::disk_full_lines(stderr);
+    if lines.is_empty() {
+        return Ok(());
+    }
+
+    Err(KueueDevError::disk_full(operation, runtime_cmd)
+        .with_details(lines.join("\n"))
+        .into())
+}
+
+/// Arguments for the runtime's build command in the given mode
+fn build_command_args(
+    mode: &BuildMode,
+    dockerfile: &Path,
+    context: &Path,
+    tag: &str,
+    build_args: &[(String, String)],
+) -> Vec<String> {
+    let mut args: Vec<String> = match mode {
+        BuildMode::Native => vec!["build".into()],
+        BuildMode::Buildx(platforms) => vec![
+            "buildx".into(),
+            "build".into(),
+            "--platform".into(),
+            platforms.clone(),
+            "--push".into(),
+        ],
+        BuildMode::PodmanManifest(platforms) => vec![
+            "build".into(),
+            "--platform".into(),
+            platforms.clone(),
+            "--manifest".into(),
+            tag.into(),
+        ],
+    };
+
+    args.extend(["-f".into(), dockerfile.display().to_string()]);
+    // --manifest already names the list; tagging an image with the same name
+    // would clash with it
+    if !matches!(mode, BuildMode::PodmanManifest(_)) {
+        args.extend(["-t".into(), tag.into()]);
+    }
+
+    // Add build arguments
+    for (key, value) in build_args {
+        args.push("--build-arg".into());
+        args.push(format!("{}={}", key, value));
+    }
+
+    args.push(context.display().to_string());
+    args
+}
+
+/// Push a freshly built image in the given mode
+fn push_built_image(runtime: &ContainerRuntime, mode: &BuildMode, tag: &str) -> Result<()> {
+    match mode {
+        BuildMode::Native => push_image(runtime, tag),
+        // buildx already pushed the image list as part of the build
+        BuildMode::Buildx(_) => Ok(()),
+        BuildMode::PodmanManifest(_) => push_manifest(runtime, tag, tag),
+    }
+}
+
+/// Push a podman manifest list with all its images to `target`
+fn push_manifest(runtime: &ContainerRuntime, manifest: &str, target: &str) -> Result<()> {
+    use std::process::Stdio;
+
+    let runtime_cmd = runtime.command();
+
+    let output = Command::new(runtime_cmd)
+        .args(["manifest", "push", "--all", manifest])
+        .arg(format!("docker://{}", target))
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .with_context(|| format!("Failed to execute {} manifest push command", runtime_cmd))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        check_disk_full(
+            &stderr,
+            &format!("Manifest push for {}", target),
+            runtime_cmd,
+        )?;
+        return Err(anyhow::anyhow!(
+            "Manifest push failed for {}:\n{}",
+            target,
+            stderr
+        ));
+    }
+
+    Ok(())
+}
+
+/// Copy a pushed multi-arch image list to another reference with buildx
+fn copy_image_list(source: &str, target: &str) -> Result<()> {
+    use std::process::Stdio;
+
+    let output = Command::new("docker")
+        .args(["buildx", "imagetools", "create", "-t", target, source])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .context("Failed to execute docker buildx imagetools create")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!(
+            "Failed to copy {} to {}:\n{}",
+            source,
+            target,
+            stderr
+        ));
+    }
+
+    Ok(())
+}
+
 /// Push a container image
 fn push_image(runtime: &ContainerRuntime, tag: &str) -> Result<()> {
     use std::process::Stdio;
@@ -430,6 +949,7 @@ fn push_image(runtime: &ContainerRuntime, tag: &str) -> Result<()> {
     if !output.status.success() {
         // On error, always show stderr
         let stderr = String::from_utf8_lossy(&output.stderr);
+        check_disk_full(&stderr, &format!("Image push for {}", tag), runtime_cmd)?;
         return Err(anyhow::anyhow!(
             "Image push failed for {}:\n{}",
             tag,
@@ -440,6 +960,77 @@ fn push_image(runtime: &ContainerRuntime, tag: &str) -> Result<()> {
     Ok(())
 }
 
+/// Tag an existing local image with an additional reference
+fn tag_image(runtime: &ContainerRuntime, source: &str, target: &str) -> Result<()> {
+    use std::process::Stdio;
+
+    let runtime_cmd = runtime.command();
+
+    let output = Command::new(runtime_cmd)
+        .args(["tag", source, target])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .output()
+        .with_context(|| format!("Failed to execute {} tag command", runtime_cmd))?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow::anyhow!(
+            "Failed to tag {} as {}:\n{}",
+            source,
+            target,
+            stderr
+        ));
+    }
+
+    Ok(())
+}
+
+/// Retag a built image under another registry and push it.
+/// Returns the new image reference.
+fn retag_and_push(
+    runtime: &ContainerRuntime,
+    mode: &BuildMode,
+    image: &str,
+    registry: &str,
+) -> Result<String> {
+    let target = retag_reference(image, registry)?;
+    match mode {
+        BuildMode::Native => {
+            tag_image(runtime, image, &target)?;
+            push_image(runtime, &target)?;
+        }
+        BuildMode::Buildx(_) => copy_image_list(image, &target)?,
+        BuildMode::PodmanManifest(_) => push_manifest(runtime, image, &target)?,
+    }
+    Ok(target)
+}
+
+/// Rewrite an image reference to live under `registry`, keeping the
+/// repository path and tag (e.g. quay.io/me/kueue:dev -> reg:5000/me/kueue:dev)
+fn retag_reference(image: &str, registry: &str) -> Result<String> {
+    let registry = registry.trim_end_matches('/');
+    if registry.is_empty() {
+        return Err(anyhow::anyhow!("Registry for --push-to must not be empty"));
+    }
+    if image.contains('@') {
+        return Err(anyhow::anyhow!(
+            "Cannot retag digest reference {}; use a tagged image",
+            image
+        ));
+    }
+
+    // The first path component is a registry host if it looks like one
+    let repository = match image.split_once('/') {
+        Some((host, rest)) if host.contains('.') || host.contains(':') || host == "localhost" => {
+            rest
+        }
+        _ => image,
+    };
+
+    Ok(format!("{}/{}", registry, repository))
+}
+
 /// Send OSC 9;4 progress update
 /// Format: ESC ] 9 ; 4 ; <state> ; <progress> BEL
 /// state: 1 = percentage (0-100)
@@ -474,6 +1065,139 @@ mod tests {
         assert!(VALID_COMPONENTS.contains(&"must-gather"));
     }
 
+    #[test]
+    fn test_retag_reference() {
+        assert_eq!(
+            retag_reference("quay.io/me/kueue-operator:dev", "registry.example.com:5000").unwrap(),
+            "registry.example.com:5000/me/kueue-operator:dev"
+        );
+        assert_eq!(
+            retag_reference("localhost/kueue:latest", "quay.io/colleague/").unwrap(),
+            "quay.io/colleague/kueue:latest"
+        );
+        // No registry host in the source reference
+        assert_eq!(
+            retag_reference("me/kueue:v1", "localhost:5000").unwrap(),
+            "localhost:5000/me/kueue:v1"
+        );
+        assert!(retag_reference("quay.io/me/kueue@sha256:abc", "reg").is_err());
+        assert!(retag_reference("quay.io/me/kueue:dev", "/").is_err());
+    }
+
+    #[test]
+    fn test_build_mode_select() {
+        let platforms = vec!["linux/amd64".to_string(), " linux/arm64".to_string()];
+
+        assert_eq!(
+            BuildMode::select(&ContainerRuntime::Docker, &[], false).unwrap(),
+            BuildMode::Native
+        );
+        assert_eq!(
+            BuildMode::select(&ContainerRuntime::Docker, &platforms, true).unwrap(),
+            BuildMode::Buildx("linux/amd64,linux/arm64".to_string())
+        );
+        assert_eq!(
+            BuildMode::select(&ContainerRuntime::Podman, &platforms, false).unwrap(),
+            BuildMode::PodmanManifest("linux/amd64,linux/arm64".to_string())
+        );
+
+        let err = BuildMode::select(&ContainerRuntime::Docker, &platforms, false).unwrap_err();
+        assert!(err.to_string().contains("requires docker buildx"));
+    }
+
+    #[test]
+    fn test_build_command_args() {
+        let dockerfile = Path::new("/src/Dockerfile");
+        let context = Path::new("/src");
+        let build_args = vec![("RELATED_IMAGE_FILE".to_string(), "images.json".to_string())];
+
+        assert_eq!(
+            build_command_args(
+                &BuildMode::Native,
+                dockerfile,
+                context,
+                "img:dev",
+                &build_args
+            ),
+            vec![
+                "build",
+                "-f",
+                "/src/Dockerfile",
+                "-t",
+                "img:dev",
+                "--build-arg",
+                "RELATED_IMAGE_FILE=images.json",
+                "/src",
+            ]
+        );
+
+        let buildx = BuildMode::Buildx("linux/amd64,linux/arm64".to_string());
+        assert_eq!(
+            build_command_args(&buildx, dockerfile, context, "img:dev", &[]),
+            vec![
+                "buildx",
+                "build",
+                "--platform",
+                "linux/amd64,linux/arm64",
+                "--push",
+                "-f",
+                "/src/Dockerfile",
+                "-t",
+                "img:dev",
+                "/src",
+            ]
+        );
+
+        let manifest = BuildMode::PodmanManifest("linux/arm64".to_string());
+        assert_eq!(
+            build_command_args(&manifest, dockerfile, context, "img:dev", &[]),
+            vec![
+                "build",
+                "--platform",
+                "linux/arm64",
+                "--manifest",
+                "img:dev",
+                "-f",
+                "/src/Dockerfile",
+                "/src",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_opm_index_add_args() {
+        let args = opm_index_add_args(
+            "quay.io/me/bundle:dev",
+            "quay.io/me/catalog:dev",
+            None,
+            &ContainerRuntime::Podman,
+        );
+        assert_eq!(
+            args,
+            [
+                "index",
+                "add",
+                "--bundles",
+                "quay.io/me/bundle:dev",
+                "--tag",
+                "quay.io/me/catalog:dev",
+                "--container-tool",
+                "podman",
+            ]
+        );
+
+        let args = opm_index_add_args(
+            "quay.io/me/bundle:dev",
+            "quay.io/me/catalog:dev",
+            Some("quay.io/me/catalog:base"),
+            &ContainerRuntime::Docker,
+        );
+        assert_eq!(
+            &args[args.len() - 2..],
+            ["--from-index", "quay.io/me/catalog:base"]
+        );
+    }
+
     #[test]
     fn test_build_module() {
         // Basic compile test
@@ -1,286 +1,548 @@
-//! Cleanup command implementation for e2e test resources
+//! Cleanup command implementation for e2e test resources and the operator
 
+use crate::config::settings::Settings;
+use crate::install::olm;
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
 use anyhow::Result;
 use std::path::Path;
+use std::str::FromStr;
+
+/// What a cleanup run removes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupScope {
+    /// The operator and the e2e test resources
+    All,
+    /// The operator and its namespace
+    Operator,
+    /// e2e test resources
+    Tests,
+}
 
-/// Clean up e2e test resources
-pub fn cleanup(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Cleaning up e2e test resources...");
+impl FromStr for CleanupScope {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "all" => Ok(CleanupScope::All),
+            "operator" => Ok(CleanupScope::Operator),
+            "tests" => Ok(CleanupScope::Tests),
+            _ => Err(anyhow::anyhow!(
+                "Invalid cleanup scope: {}. Must be 'all', 'operator', or 'tests'",
+                s
+            )),
+        }
+    }
+}
 
-    // Delete test PriorityClasses (not system ones)
-    cleanup_priority_classes(kubeconfig)?;
+impl std::fmt::Display for CleanupScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CleanupScope::All => write!(f, "all"),
+            CleanupScope::Operator => write!(f, "operator"),
+            CleanupScope::Tests => write!(f, "tests"),
+        }
+    }
+}
 
-    // Delete test WorkloadPriorityClasses
-    cleanup_resource("workloadpriorityclass", None, kubeconfig)?;
+impl CleanupScope {
+    fn includes_operator(self) -> bool {
+        matches!(self, CleanupScope::All | CleanupScope::Operator)
+    }
 
-    // Delete all ClusterQueues
-    cleanup_resource("clusterqueue", None, kubeconfig)?;
+    fn includes_tests(self) -> bool {
+        matches!(self, CleanupScope::All | CleanupScope::Tests)
+    }
 
-    // Delete all ResourceFlavors
-    cleanup_resource("resourceflavor", None, kubeconfig)?;
+    /// What the confirmation prompt says will be deleted
+    fn description(self) -> &'static str {
+        match self {
+            CleanupScope::All => "the kueue-operator and all Kueue test resources",
+            CleanupScope::Operator => "the kueue-operator and its namespace",
+            CleanupScope::Tests => "all Kueue test resources",
+        }
+    }
+}
 
-    // Delete all Cohorts
-    cleanup_resource("cohort", None, kubeconfig)?;
+/// Cluster-wide Kueue resources removed by cleanup, in deletion order
+const KUEUE_RESOURCES: &[&str] = &[
+    "workloadpriorityclass",
+    "clusterqueue",
+    "resourceflavor",
+    "cohort",
+    "admissioncheck",
+];
+
+/// Merge patch that clears finalizers so deletion is not blocked
+const CLEAR_FINALIZERS: &str = r#"{"metadata":{"finalizers":[]}}"#;
+
+/// Everything a cleanup run would patch and delete
+#[derive(Debug, Default)]
+pub struct CleanupPlan {
+    /// Non-system PriorityClasses (`priorityclass.scheduling.k8s.io/<name>`)
+    pub priority_classes: Vec<String>,
+    /// Kueue resources by type, as returned by `kubectl get -o name`
+    pub resources: Vec<(&'static str, Vec<String>)>,
+    /// Workloads by test namespace
+    pub workloads: Vec<(String, Vec<String>)>,
+    /// Test namespaces (`namespace/<name>`)
+    pub namespaces: Vec<String>,
+}
 
-    // Delete all AdmissionChecks
-    cleanup_resource("admissioncheck", None, kubeconfig)?;
+impl CleanupPlan {
+    /// Query the cluster for the resources cleanup would touch.
+    /// Namespaces starting with one of `namespace_prefixes` are test namespaces.
+    /// Workloads are also collected from `workload_namespaces`, which are kept.
+    pub fn discover(
+        kubeconfig: Option<&Path>,
+        namespace_prefixes: &[String],
+        workload_namespaces: &[String],
+    ) -> Self {
+        let priority_classes = list_names(&["get", "priorityclasses", "-o", "name"], kubeconfig)
+            .into_iter()
+            .filter(|pc| !pc.contains("system-"))
+            .collect();
 
-    // Delete workloads in test namespaces
-    cleanup_test_workloads(kubeconfig)?;
+        let resources = KUEUE_RESOURCES
+            .iter()
+            .map(|resource_type| {
+                let names = list_names(
+                    &["get", resource_type, "-o", "name", "--all-namespaces"],
+                    kubeconfig,
+                );
+                (*resource_type, names)
+            })
+            .filter(|(_, names)| !names.is_empty())
+            .collect();
 
-    // Delete test namespaces
-    cleanup_test_namespaces(kubeconfig)?;
+        let namespaces = select_test_namespaces(
+            list_names(&["get", "ns", "-o", "name"], kubeconfig),
+            namespace_prefixes,
+        );
 
-    crate::log_info!("Cleanup complete!");
-    Ok(())
+        let workloads = workload_scan_namespaces(&namespaces, workload_namespaces)
+            .into_iter()
+            .filter_map(|namespace| {
+                let names = list_names(
+                    &["get", "workloads", "-n", &namespace, "-o", "name"],
+                    kubeconfig,
+                );
+                (!names.is_empty()).then_some((namespace, names))
+            })
+            .collect();
+
+        Self {
+            priority_classes,
+            resources,
+            workloads,
+            namespaces,
+        }
+    }
+
+    /// Whether there is nothing to clean up
+    pub fn is_empty(&self) -> bool {
+        self.priority_classes.is_empty()
+            && self.resources.is_empty()
+            && self.workloads.is_empty()
+            && self.namespaces.is_empty()
+    }
+
+    /// Print every resource in the plan
+    pub fn print(&self) {
+        if self.is_empty() {
+            println!("Nothing to clean up");
+            return;
+        }
+
+        print_group("PriorityClasses", &self.priority_classes);
+        for (resource_type, names) in &self.resources {
+            print_group(resource_type, names);
+        }
+        for (namespace, names) in &self.workloads {
+            print_group(&format!("Workloads in {}", namespace), names);
+        }
+        print_group("Namespaces", &self.namespaces);
+    }
 }
 
-/// Remove finalizers and delete a resource
-fn cleanup_resource(
-    resource_type: &str,
-    namespace: Option<&str>,
+fn print_group(title: &str, names: &[String]) {
+    if names.is_empty() {
+        return;
+    }
+    println!("{} ({}):", title, names.len());
+    for name in names {
+        println!("  {}", name);
+    }
+}
+
+/// Clean up the operator and/or e2e test resources, depending on `scope`.
+/// `extra_prefixes` are added to the configured test namespace prefixes, and
+/// workloads in `workload_namespaces` are deleted without deleting the namespace.
+/// Unless `yes` is set, the target cluster is shown and must be confirmed.
+pub fn cleanup(
     kubeconfig: Option<&Path>,
+    scope: CleanupScope,
+    dry_run: bool,
+    extra_prefixes: &[String],
+    workload_namespaces: &[String],
+    yes: bool,
 ) -> Result<()> {
-    crate::log_info!("Removing finalizers from {}...", resource_type);
-
-    // Get all resources of this type
-    let mut args = vec!["get", resource_type, "-o", "name"];
-    if let Some(ns) = namespace {
-        args.push("-n");
-        args.push(ns);
-    } else {
-        args.push("--all-namespaces");
-    }
-
-    let output = kubectl::run_kubectl_output(&args, kubeconfig);
-
-    if let Ok(resources_str) = output {
-        let resources: Vec<&str> = resources_str.lines().collect();
-
-        if !resources.is_empty() {
-            // Remove finalizers
-            for resource in &resources {
-                let resource = resource.trim();
-                if resource.is_empty() {
-                    continue;
-                }
-
-                crate::log_info!("Cleaning up {}", resource);
-
-                let mut patch_args = vec![
-                    "patch",
-                    resource,
-                    "--type=merge",
-                    "-p",
-                    r#"{"metadata":{"finalizers":[]}}"#,
-                ];
-                if let Some(ns) = namespace {
-                    patch_args.insert(1, "-n");
-                    patch_args.insert(2, ns);
-                }
-
-                kubectl::run_kubectl(&patch_args, kubeconfig).ok(); // Ignore errors
-            }
-
-            crate::log_info!("Deleting {}...", resource_type);
-
-            // Delete resources
-            let mut delete_args = vec!["delete", resource_type, "--all"];
-            if let Some(ns) = namespace {
-                delete_args.push("-n");
-                delete_args.push(ns);
-            } else {
-                delete_args.push("--all-namespaces");
-            }
-
-            if kubectl::run_kubectl(&delete_args, kubeconfig).is_ok() {
-                crate::log_info!("Successfully deleted {}", resource_type);
-            }
+    let settings = Settings::load()?;
+    let operator_namespace = settings.operator.namespace;
+    let mut namespace_prefixes = settings.tests.namespace_prefixes;
+    namespace_prefixes.extend(extra_prefixes.iter().cloned());
+
+    if dry_run {
+        if scope.includes_operator() {
+            crate::log_info!("Dry run: listing operator resources that cleanup would delete...");
+            print_operator_plan(&operator_namespace, kubeconfig);
+        }
+        if scope.includes_tests() {
+            crate::log_info!("Dry run: listing e2e test resources that cleanup would delete...");
+            CleanupPlan::discover(kubeconfig, &namespace_prefixes, workload_namespaces).print();
         }
+        return Ok(());
+    }
+
+    if !confirm_cluster_identity(kubeconfig, scope, yes)? {
+        crate::log_info!("Cleanup cancelled");
+        return Ok(());
     }
 
+    if scope.includes_operator() {
+        cleanup_operator(&operator_namespace, kubeconfig)?;
+    }
+    if scope.includes_tests() {
+        cleanup_test_resources(kubeconfig, &namespace_prefixes, workload_namespaces);
+    }
+
+    crate::log_info!("Cleanup complete!");
     Ok(())
 }
 
-/// Cleanup PriorityClasses (excluding system ones)
-fn cleanup_priority_classes(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Removing finalizers from non-system PriorityClasses...");
+/// Print what operator cleanup would remove from `namespace`
+fn print_operator_plan(namespace: &str, kubeconfig: Option<&Path>) {
+    let namespaces = list_names(
+        &[
+            "get",
+            "namespace",
+            namespace,
+            "-o",
+            "name",
+            "--ignore-not-found",
+        ],
+        kubeconfig,
+    );
+    if namespaces.is_empty() {
+        println!("Operator namespace {} does not exist", namespace);
+        return;
+    }
+    if olm::is_operator_installed(namespace, kubeconfig) {
+        println!("kueue-operator installation in {}", namespace);
+    }
+    print_group("Operator namespace", &namespaces);
+}
 
-    let output = kubectl::run_kubectl_output(&["get", "priorityclasses", "-o", "name"], kubeconfig);
+/// Uninstall the operator from `namespace` and delete the namespace
+fn cleanup_operator(namespace: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    crate::log_info!("Cleaning up the operator in {}...", namespace);
+    olm::uninstall_operator_if_exists(namespace, kubeconfig)?;
+
+    crate::log_info!("Deleting namespace {}...", namespace);
+    kubectl::run_kubectl(
+        &["delete", "namespace", namespace, "--ignore-not-found"],
+        &KubeTarget::new(kubeconfig),
+    )?;
+    crate::log_info!("Successfully deleted namespace {}", namespace);
+    Ok(())
+}
 
-    if let Ok(pcs_str) = output {
-        let priority_classes: Vec<&str> = pcs_str
-            .lines()
-            .filter(|line| !line.contains("system-"))
-            .collect();
+/// Delete the e2e test resources in the cluster
+fn cleanup_test_resources(
+    kubeconfig: Option<&Path>,
+    namespace_prefixes: &[String],
+    workload_namespaces: &[String],
+) {
+    crate::log_info!("Cleaning up e2e test resources...");
 
-        if !priority_classes.is_empty() {
-            // Remove finalizers
-            for pc in &priority_classes {
-                let pc = pc.trim();
-                if pc.is_empty() {
-                    continue;
-                }
-
-                crate::log_info!("Cleaning up {}", pc);
-
-                kubectl::run_kubectl(
-                    &[
-                        "patch",
-                        pc,
-                        "--type=merge",
-                        "-p",
-                        r#"{"metadata":{"finalizers":[]}}"#,
-                    ],
-                    kubeconfig,
-                )
-                .ok(); // Ignore errors
-            }
-
-            // Delete PriorityClasses
-            for pc in &priority_classes {
-                let pc = pc.trim();
-                if !pc.is_empty() && kubectl::run_kubectl(&["delete", pc], kubeconfig).is_ok() {
-                    crate::log_info!("Successfully deleted {}", pc);
-                }
-            }
+    let plan = CleanupPlan::discover(kubeconfig, namespace_prefixes, workload_namespaces);
+
+    // Delete test PriorityClasses (not system ones)
+    cleanup_priority_classes(&plan.priority_classes, kubeconfig);
+
+    // Delete WorkloadPriorityClasses, ClusterQueues, ResourceFlavors, Cohorts and AdmissionChecks
+    for (resource_type, names) in &plan.resources {
+        cleanup_resource(resource_type, names, kubeconfig);
+    }
+
+    // Delete workloads in test namespaces and the --namespace namespaces
+    for (namespace, workloads) in &plan.workloads {
+        cleanup_test_workloads(namespace, workloads, kubeconfig);
+    }
+
+    // Delete test namespaces
+    cleanup_test_namespaces(&plan.namespaces, kubeconfig);
+}
+
+/// Show which cluster cleanup is about to modify and ask for confirmation.
+/// Skipped with `--yes` or when `behavior.confirm_destructive` is false.
+fn confirm_cluster_identity(
+    kubeconfig: Option<&Path>,
+    scope: CleanupScope,
+    yes: bool,
+) -> Result<bool> {
+    let target = KubeTarget::new(kubeconfig);
+    let context = kubectl::current_context(&target).unwrap_or_else(|_| "<unknown>".to_string());
+    let server = kubectl::run_kubectl_output(
+        &[
+            "config",
+            "view",
+            "--minify",
+            "-o",
+            "jsonpath={.clusters[0].cluster.server}",
+        ],
+        &target,
+    )
+    .map(|s| s.trim().to_string())
+    .unwrap_or_else(|_| "<unknown>".to_string());
+
+    crate::log_info!("Target cluster:");
+    crate::log_info!("  Context: {}", context);
+    crate::log_info!("  Server:  {}", server);
+
+    if yes {
+        return Ok(true);
+    }
+
+    crate::utils::confirm(&format!(
+        "Delete {} on context '{}'?",
+        scope.description(),
+        context
+    ))
+}
+
+/// Run a `get ... -o name` query and return the non-empty names.
+/// Query failures (e.g. CRD not installed) yield an empty list.
+fn list_names(args: &[&str], kubeconfig: Option<&Path>) -> Vec<String> {
+    kubectl::run_kubectl_output(args, &KubeTarget::new(kubeconfig))
+        .map(|output| parse_names(&output))
+        .unwrap_or_default()
+}
+
+fn parse_names(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Names of the test namespaces (`namespace/<name>`) followed by the extra
+/// `workload_namespaces` that aren't already among them
+fn workload_scan_namespaces(
+    test_namespaces: &[String],
+    workload_namespaces: &[String],
+) -> Vec<String> {
+    let mut names: Vec<String> = test_namespaces
+        .iter()
+        .map(|ns| ns.strip_prefix("namespace/").unwrap_or(ns).to_string())
+        .collect();
+    for namespace in workload_namespaces {
+        if !names.contains(namespace) {
+            names.push(namespace.clone());
         }
     }
+    names
+}
 
-    Ok(())
+/// Keep the namespaces (`namespace/<name>`) whose name starts with one of `prefixes`
+fn select_test_namespaces(namespaces: Vec<String>, prefixes: &[String]) -> Vec<String> {
+    namespaces
+        .into_iter()
+        .filter(|ns| {
+            let name = ns.strip_prefix("namespace/").unwrap_or(ns);
+            prefixes
+                .iter()
+                .any(|prefix| !prefix.is_empty() && name.starts_with(prefix.as_str()))
+        })
+        .collect()
 }
 
-/// Cleanup workloads in test namespaces
-fn cleanup_test_workloads(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Removing finalizers from Workloads in test namespaces...");
-
-    let output = kubectl::run_kubectl_output(&["get", "ns", "-o", "name"], kubeconfig);
-
-    if let Ok(namespaces_str) = output {
-        let test_namespaces: Vec<&str> = namespaces_str
-            .lines()
-            .filter(|line| {
-                line.contains("e2e-")
-                    || line.contains("sts-e2e-")
-                    || line.contains("deployment-e2e-")
-                    || line.contains("lws-e2e-")
-                    || line.contains("pod-e2e-")
-                    || line.contains("jobset-e2e-")
-            })
-            .collect();
+/// Remove finalizers from a resource, ignoring errors
+fn clear_finalizers(resource: &str, namespace: Option<&str>, kubeconfig: Option<&Path>) {
+    let mut patch_args = vec!["patch", resource, "--type=merge", "-p", CLEAR_FINALIZERS];
+    if let Some(ns) = namespace {
+        patch_args.insert(1, "-n");
+        patch_args.insert(2, ns);
+    }
 
-        for ns in test_namespaces {
-            let namespace = ns.trim().strip_prefix("namespace/").unwrap_or(ns.trim());
-            if namespace.is_empty() {
-                continue;
-            }
-
-            crate::log_info!("Processing workloads in {}...", namespace);
-
-            let workloads_output = kubectl::run_kubectl_output(
-                &["get", "workloads", "-n", namespace, "-o", "name"],
-                kubeconfig,
-            );
-
-            if let Ok(workloads_str) = workloads_output {
-                for workload in workloads_str.lines() {
-                    let workload = workload.trim();
-                    if workload.is_empty() {
-                        continue;
-                    }
-
-                    crate::log_info!("Cleaning up {} in {}", workload, namespace);
-
-                    kubectl::run_kubectl(
-                        &[
-                            "patch",
-                            workload,
-                            "-n",
-                            namespace,
-                            "--type=merge",
-                            "-p",
-                            r#"{"metadata":{"finalizers":[]}}"#,
-                        ],
-                        kubeconfig,
-                    )
-                    .ok(); // Ignore errors
-                }
-
-                if kubectl::run_kubectl(
-                    &["delete", "workloads", "-n", namespace, "--all"],
-                    kubeconfig,
-                )
-                .is_ok()
-                {
-                    crate::log_info!("Successfully deleted workloads in {}", namespace);
-                }
-            }
+    kubectl::run_kubectl(&patch_args, &KubeTarget::new(kubeconfig)).ok(); // Ignore errors
+}
+
+/// Remove finalizers and delete all resources of a type
+fn cleanup_resource(resource_type: &str, resources: &[String], kubeconfig: Option<&Path>) {
+    crate::log_info!("Removing finalizers from {}...", resource_type);
+
+    for resource in resources {
+        crate::log_info!("Cleaning up {}", resource);
+        clear_finalizers(resource, None, kubeconfig);
+    }
+
+    crate::log_info!("Deleting {}...", resource_type);
+
+    if kubectl::run_kubectl(
+        &["delete", resource_type, "--all", "--all-namespaces"],
+        &KubeTarget::new(kubeconfig),
+    )
+    .is_ok()
+    {
+        crate::log_info!("Successfully deleted {}", resource_type);
+    }
+}
+
+/// Cleanup PriorityClasses (excluding system ones)
+fn cleanup_priority_classes(priority_classes: &[String], kubeconfig: Option<&Path>) {
+    crate::log_info!("Removing finalizers from non-system PriorityClasses...");
+
+    for pc in priority_classes {
+        crate::log_info!("Cleaning up {}", pc);
+        clear_finalizers(pc, None, kubeconfig);
+    }
+
+    // Delete PriorityClasses
+    for pc in priority_classes {
+        if kubectl::run_kubectl(&["delete", pc], &KubeTarget::new(kubeconfig)).is_ok() {
+            crate::log_info!("Successfully deleted {}", pc);
         }
     }
+}
+
+/// Cleanup workloads in a test namespace
+fn cleanup_test_workloads(namespace: &str, workloads: &[String], kubeconfig: Option<&Path>) {
+    crate::log_info!("Processing workloads in {}...", namespace);
+
+    for workload in workloads {
+        crate::log_info!("Cleaning up {} in {}", workload, namespace);
+        clear_finalizers(workload, Some(namespace), kubeconfig);
+    }
 
-    Ok(())
+    if kubectl::run_kubectl(
+        &["delete", "workloads", "-n", namespace, "--all"],
+        &KubeTarget::new(kubeconfig),
+    )
+    .is_ok()
+    {
+        crate::log_info!("Successfully deleted workloads in {}", namespace);
+    }
 }
 
 /// Cleanup test namespaces
-fn cleanup_test_namespaces(kubeconfig: Option<&Path>) -> Result<()> {
+fn cleanup_test_namespaces(namespaces: &[String], kubeconfig: Option<&Path>) {
     crate::log_info!("Removing finalizers from test namespaces...");
 
-    let output = kubectl::run_kubectl_output(&["get", "ns", "-o", "name"], kubeconfig);
-
-    if let Ok(namespaces_str) = output {
-        let test_namespaces: Vec<&str> = namespaces_str
-            .lines()
-            .filter(|line| {
-                line.contains("e2e-")
-                    || line.contains("sts-e2e-")
-                    || line.contains("deployment-e2e-")
-                    || line.contains("lws-e2e-")
-                    || line.contains("pod-e2e-")
-                    || line.contains("jobset-e2e-")
-            })
-            .collect();
+    for ns in namespaces {
+        crate::log_info!("Cleaning up {}", ns);
+        clear_finalizers(ns, None, kubeconfig);
+    }
 
-        if !test_namespaces.is_empty() {
-            // Remove finalizers
-            for ns in &test_namespaces {
-                let ns = ns.trim();
-                if ns.is_empty() {
-                    continue;
-                }
-
-                crate::log_info!("Cleaning up {}", ns);
-
-                kubectl::run_kubectl(
-                    &[
-                        "patch",
-                        ns,
-                        "--type=merge",
-                        "-p",
-                        r#"{"metadata":{"finalizers":[]}}"#,
-                    ],
-                    kubeconfig,
-                )
-                .ok(); // Ignore errors
-            }
-
-            // Delete namespaces
-            for ns in &test_namespaces {
-                let ns = ns.trim();
-                if !ns.is_empty() && kubectl::run_kubectl(&["delete", ns], kubeconfig).is_ok() {
-                    crate::log_info!("Successfully deleted {}", ns);
-                }
-            }
+    // Delete namespaces
+    for ns in namespaces {
+        if kubectl::run_kubectl(&["delete", ns], &KubeTarget::new(kubeconfig)).is_ok() {
+            crate::log_info!("Successfully deleted {}", ns);
         }
     }
-
-    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_cleanup_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_parse_names_skips_blank_lines() {
+        let output = "clusterqueue.kueue.x-k8s.io/cq-a\n\n  clusterqueue.kueue.x-k8s.io/cq-b  \n";
+        assert_eq!(
+            parse_names(output),
+            vec![
+                "clusterqueue.kueue.x-k8s.io/cq-a",
+                "clusterqueue.kueue.x-k8s.io/cq-b",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_select_test_namespaces() {
+        let namespaces = parse_names(
+            "namespace/default\n\
+             namespace/e2e-abc12\n\
+             namespace/jobset-e2e-xyz\n\
+             namespace/kube-system\n\
+             namespace/myteam-it-1\n\
+             namespace/openshift-kueue-operator\n",
+        );
+        let mut prefixes = vec!["e2e-".to_string(), "jobset-e2e-".to_string()];
+
+        assert_eq!(
+            select_test_namespaces(namespaces.clone(), &prefixes),
+            vec!["namespace/e2e-abc12", "namespace/jobset-e2e-xyz"]
+        );
+
+        // --namespace-prefix augments the configured prefixes
+        prefixes.push("myteam-".to_string());
+        assert_eq!(
+            select_test_namespaces(namespaces.clone(), &prefixes),
+            vec![
+                "namespace/e2e-abc12",
+                "namespace/jobset-e2e-xyz",
+                "namespace/myteam-it-1",
+            ]
+        );
+
+        // An empty prefix must not select everything
+        assert!(select_test_namespaces(namespaces, &[String::new()]).is_empty());
+    }
+
+    #[test]
+    fn test_workload_scan_namespaces() {
+        let test_namespaces = vec!["namespace/e2e-abc".to_string()];
+        assert_eq!(
+            workload_scan_namespaces(
+                &test_namespaces,
+                &["default".to_string(), "e2e-abc".to_string()]
+            ),
+            vec!["e2e-abc", "default"]
+        );
+        assert_eq!(
+            workload_scan_namespaces(&test_namespaces, &[]),
+            vec!["e2e-abc"]
+        );
+    }
+
+    #[test]
+    fn test_cleanup_scope() {
+        assert_eq!(
+            CleanupScope::from_str("Operator").unwrap(),
+            CleanupScope::Operator
+        );
+        assert_eq!(CleanupScope::Tests.to_string(), "tests");
+        assert!(CleanupScope::from_str("namespace").is_err());
+
+        assert!(CleanupScope::All.includes_operator() && CleanupScope::All.includes_tests());
+        assert!(!CleanupScope::Operator.includes_tests());
+        assert!(!CleanupScope::Tests.includes_operator());
+    }
+
+    #[test]
+    fn test_empty_plan() {
+        let mut plan = CleanupPlan::default();
+        assert!(plan.is_empty());
+
+        plan.namespaces.push("namespace/e2e-abc".to_string());
+        assert!(!plan.is_empty());
+    }
 }
@@ -1,19 +1,59 @@
 //! Cluster command implementations
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::str::FromStr;
 
 use crate::install::calico;
 use crate::k8s::kind::{CniProvider, KindCluster};
 use crate::k8s::nodes;
+use crate::k8s::registry::LocalRegistry;
+use crate::k8s::target::{KubeTarget, kube_cli};
+use crate::utils::ContainerRuntime;
+
+/// Options for creating a kind cluster
+#[derive(Debug, Clone, Default)]
+pub struct CreateOptions {
+    pub name: String,
+    pub cni: String,
+    /// Path to save the kubeconfig to (default: `defaults.kubeconfig_path`)
+    pub kubeconfig: Option<String>,
+    /// Merge the cluster's context into the default kubeconfig instead of
+    /// saving a standalone file
+    pub merge_kubeconfig: bool,
+    /// Reserve stdout for a single line that sets `KUBECONFIG` in this shell,
+    /// suitable for `eval`
+    pub export_shell: Option<String>,
+    /// `host:port` registries the nodes pull from over plain HTTP
+    pub insecure_registries: Vec<String>,
+    /// Kubernetes feature gates for every component, as `(name, enabled)`
+    pub feature_gates: Vec<(String, bool)>,
+    pub worker_labels: nodes::WorkerLabels,
+}
 
 /// Handle cluster create command
-pub fn create(name: String, cni: String, kubeconfig: Option<String>) -> Result<()> {
+pub fn create(options: CreateOptions) -> Result<()> {
+    let CreateOptions {
+        name,
+        cni,
+        kubeconfig,
+        merge_kubeconfig,
+        export_shell,
+        insecure_registries,
+        feature_gates,
+        worker_labels,
+    } = options;
+
+    if export_shell.is_some() {
+        crate::utils::reserve_stdout();
+    }
+
     // Ensure we're in the operator source directory
     crate::utils::ensure_operator_source_directory()?;
 
     use crate::config::settings::Settings;
-    use std::path::PathBuf;
 
     // Load settings for versions and other config
     let settings = Settings::load()?;
@@ -21,46 +61,61 @@ pub fn create(name: String, cni: String, kubeconfig: Option<String>) -> Result<(
     crate::log_info!("Creating kind cluster: {}", name);
 
     let cni_provider = CniProvider::from_str(&cni)?;
-    let cluster = KindCluster::new(name, cni_provider);
+    let runtime = ContainerRuntime::detect().ok();
+    let local_registry = runtime.as_ref().and_then(LocalRegistry::active);
+    if let Some(registry) = &local_registry {
+        crate::log_info!(
+            "Configuring nodes to pull from local registry {}",
+            registry.address()
+        );
+    }
+    let cluster = KindCluster::new(name, cni_provider)
+        .with_insecure_registries(insecure_registries)
+        .with_feature_gates(feature_gates)
+        .with_local_registry(local_registry);
 
-    // Determine kubeconfig path from CLI arg or config - REQUIRED
-    let kubeconfig_path = if let Some(kc) = kubeconfig {
-        PathBuf::from(kc)
+    let (saved_kubeconfig, merged_context) = if merge_kubeconfig {
+        cluster.create_with_kubeconfig(None)?;
+        (None, Some(cluster.merge_kubeconfig()?))
     } else {
-        settings
-            .defaults
-            .kubeconfig_path
-            .as_ref()
-            .map(PathBuf::from)
-            .ok_or_else(|| {
-                anyhow::anyhow!(
-                    "Kubeconfig path is required. Provide it via --kubeconfig flag or set 'kubeconfig_path' in config file"
-                )
-            })?
+        let kubeconfig_path = kubeconfig_path(kubeconfig, &settings)?;
+
+        // Create the cluster with kubeconfig
+        let saved_kubeconfig = cluster
+            .create_with_kubeconfig(Some(kubeconfig_path))?
+            .expect("Kubeconfig should always be saved when path is provided");
+        (Some(saved_kubeconfig), None)
     };
+    if !cluster.insecure_registries.is_empty() || cluster.local_registry.is_some() {
+        let runtime = runtime.as_ref().ok_or_else(|| {
+            anyhow::anyhow!("A container runtime is required to configure registries on the nodes")
+        })?;
+        cluster.configure_registry_hosts(runtime)?;
+    }
 
-    // Create the cluster with kubeconfig
-    let saved_kubeconfig = cluster
-        .create_with_kubeconfig(Some(kubeconfig_path))?
-        .expect("Kubeconfig should always be saved when path is provided");
+    // A merged kubeconfig is shared, so pin its context rather than relying on
+    // the current context staying on this cluster
+    let mut target = KubeTarget::new(saved_kubeconfig.as_deref());
+    if merged_context.is_some() {
+        target.context = Some(cluster.context_name());
+    }
 
     // Install Calico if selected
     if matches!(cni_provider, CniProvider::Calico) {
-        calico::install(&settings.versions.calico, Some(&saved_kubeconfig))?;
+        calico::install(&settings.versions.calico, &target)?;
     } else {
         // Wait for nodes to be ready with default CNI
         crate::log_info!("Waiting for nodes to be ready with default CNI...");
-        crate::k8s::kubectl::wait_for_condition(
-            "nodes",
-            "condition=Ready",
-            None,
-            "180s",
-            Some(&saved_kubeconfig),
-        )?;
+        crate::k8s::kubectl::wait_for_condition("nodes", "condition=Ready", None, "180s", &target)?;
     }
 
     // Label worker nodes
-    nodes::label_worker_nodes(Some(&saved_kubeconfig))?;
+    nodes::label_worker_nodes(&target, &worker_labels)?;
+
+    if let (Some(registry), Some(runtime)) = (&local_registry, &runtime) {
+        LocalRegistry::connect_to_kind_network(runtime)?;
+        crate::k8s::kubectl::apply_yaml(&registry.hosting_configmap(), &target)?;
+    }
 
     crate::log_info!("");
     crate::log_info!("==========================================");
@@ -68,15 +123,56 @@ pub fn create(name: String, cni: String, kubeconfig: Option<String>) -> Result<(
     crate::log_info!("==========================================");
     crate::log_info!("");
     crate::log_info!("Cluster name: {}", cluster.name);
-    crate::log_info!("Kubeconfig: {}", saved_kubeconfig.display());
-    crate::log_info!("");
-    crate::log_info!("To use this cluster, run:");
-    crate::log_info!("  export KUBECONFIG={}", saved_kubeconfig.display());
-    crate::log_info!("");
+    if let Some(context) = &merged_context {
+        crate::log_info!("Context: {} (merged into the default kubeconfig)", context);
+        crate::log_info!("");
+        crate::log_info!("It is the current context. To switch back to it later, run:");
+        crate::log_info!("  kubectl config use-context {}", context);
+        crate::log_info!("");
+    } else if let Some(saved_kubeconfig) = &saved_kubeconfig {
+        crate::log_info!("Kubeconfig: {}", saved_kubeconfig.display());
+        crate::log_info!("");
+        if let Some(shell) = export_shell.as_deref() {
+            println!("{}", kubeconfig_export_command(shell, saved_kubeconfig));
+        } else {
+            crate::log_info!("To use this cluster, run:");
+            crate::log_info!("  export KUBECONFIG={}", saved_kubeconfig.display());
+            crate::log_info!("");
+        }
+    }
 
     Ok(())
 }
 
+/// Kubeconfig path from the CLI arg or config - REQUIRED unless merging into the
+/// default kubeconfig
+fn kubeconfig_path(
+    kubeconfig: Option<String>,
+    settings: &crate::config::settings::Settings,
+) -> Result<PathBuf> {
+    kubeconfig
+        .or_else(|| settings.defaults.kubeconfig_path.clone())
+        .map(PathBuf::from)
+        .ok_or_else(|| {
+            anyhow!(
+                "Kubeconfig path is required. Provide it via --kubeconfig flag, set 'kubeconfig_path' in config file, or use --merge-kubeconfig"
+            )
+        })
+}
+
+/// Build a shell command that sets KUBECONFIG to the given path
+fn kubeconfig_export_command(shell: &str, path: &Path) -> String {
+    let path = path.to_string_lossy();
+    match shell {
+        "fish" => format!(
+            "set -gx KUBECONFIG '{}'",
+            path.replace('\\', "\\\\").replace('\'', "\\'")
+        ),
+        "csh" | "tcsh" => format!("setenv KUBECONFIG '{}'", path.replace('\'', "'\"'\"'")),
+        _ => format!("export KUBECONFIG='{}'", path.replace('\'', "'\\''")),
+    }
+}
+
 /// Handle cluster delete command
 pub fn delete(name: String, force: bool) -> Result<()> {
     crate::log_info!("Deleting kind cluster: {}", name);
@@ -107,8 +203,94 @@ pub fn delete(name: String, force: bool) -> Result<()> {
     Ok(())
 }
 
+/// Handle cluster recreate command: delete the cluster if it exists, then
+/// create it fresh without the "delete and recreate?" prompt of `create`
+pub fn recreate(options: CreateOptions, force: bool) -> Result<()> {
+    // Check everything create needs before deleting anything
+    crate::utils::ensure_operator_source_directory()?;
+    CniProvider::from_str(&options.cni)?;
+    let settings = crate::config::settings::Settings::load()?;
+    let kubeconfig = if options.merge_kubeconfig {
+        None
+    } else {
+        Some(kubeconfig_path(options.kubeconfig.clone(), &settings)?)
+    };
+
+    let name = &options.name;
+    let cluster = KindCluster::new(name.clone(), CniProvider::Default);
+    if cluster.exists()? {
+        if !force
+            && !crate::utils::confirm(&format!(
+                "Are you sure you want to delete and recreate cluster '{}'?",
+                name
+            ))?
+        {
+            crate::log_info!("Recreate cancelled");
+            return Ok(());
+        }
+        cluster.delete()?;
+    } else {
+        crate::log_info!("Cluster '{}' does not exist; creating it", name);
+    }
+
+    create(CreateOptions {
+        kubeconfig: kubeconfig.map(|path| path.to_string_lossy().into_owned()),
+        ..options
+    })
+}
+
+/// Output format of `cluster list`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for OutputFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            _ => Err(anyhow!(
+                "Invalid output format: {}. Must be 'text' or 'json'",
+                s
+            )),
+        }
+    }
+}
+
+/// A kind cluster as reported by `cluster list --output json`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ClusterInfo {
+    pub name: String,
+    /// Whether the current kubeconfig has the cluster's `kind-<name>` context
+    pub kubeconfig_exists: bool,
+    /// Number of nodes, or null if they couldn't be listed
+    pub nodes: Option<usize>,
+}
+
 /// Handle cluster list command
-pub fn list() -> Result<()> {
+pub fn list(format: OutputFormat) -> Result<()> {
+    if format == OutputFormat::Json {
+        let contexts = kubeconfig_contexts();
+        let clusters: Vec<ClusterInfo> = KindCluster::list_all()?
+            .into_iter()
+            .map(|name| {
+                let cluster = KindCluster::new(name.clone(), CniProvider::Default);
+                ClusterInfo {
+                    kubeconfig_exists: contexts.contains(&format!("kind-{}", name)),
+                    nodes: cluster.nodes().ok().map(|nodes| nodes.len()),
+                    name,
+                }
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&clusters)?);
+        return Ok(());
+    }
+
     crate::log_info!("Listing kind clusters...");
 
     let clusters = KindCluster::list_all()?;
@@ -125,10 +307,88 @@ pub fn list() -> Result<()> {
     Ok(())
 }
 
+/// Context names in the current kubeconfig; empty if kubectl isn't usable
+fn kubeconfig_contexts() -> Vec<String> {
+    Command::new(kube_cli().command())
+        .args(["config", "get-contexts", "-o", "name"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| {
+            String::from_utf8_lossy(&output.stdout)
+                .lines()
+                .map(|line| line.trim().to_string())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_cluster_module_exists() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_cluster_info_json() {
+        let clusters = vec![
+            ClusterInfo {
+                name: "kueue-test".to_string(),
+                kubeconfig_exists: true,
+                nodes: Some(4),
+            },
+            ClusterInfo {
+                name: "broken".to_string(),
+                kubeconfig_exists: false,
+                nodes: None,
+            },
+        ];
+
+        assert_eq!(
+            serde_json::to_value(&clusters).unwrap(),
+            serde_json::json!([
+                {"name": "kueue-test", "kubeconfig_exists": true, "nodes": 4},
+                {"name": "broken", "kubeconfig_exists": false, "nodes": null}
+            ])
+        );
+        assert_eq!("JSON".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_kubeconfig_export_command() {
+        let path = Path::new("/home/dev/.kube/config-kueue-test");
+        assert_eq!(
+            kubeconfig_export_command("bash", path),
+            "export KUBECONFIG='/home/dev/.kube/config-kueue-test'"
+        );
+        assert_eq!(
+            kubeconfig_export_command("zsh", path),
+            "export KUBECONFIG='/home/dev/.kube/config-kueue-test'"
+        );
+        assert_eq!(
+            kubeconfig_export_command("fish", path),
+            "set -gx KUBECONFIG '/home/dev/.kube/config-kueue-test'"
+        );
+        assert_eq!(
+            kubeconfig_export_command("csh", path),
+            "setenv KUBECONFIG '/home/dev/.kube/config-kueue-test'"
+        );
+    }
+
+    #[test]
+    fn test_kubeconfig_export_command_quotes() {
+        let path = Path::new("/tmp/it's/kubeconfig");
+        assert_eq!(
+            kubeconfig_export_command("sh", path),
+            "export KUBECONFIG='/tmp/it'\\''s/kubeconfig'"
+        );
+        assert_eq!(
+            kubeconfig_export_command("fish", path),
+            "set -gx KUBECONFIG '/tmp/it\\'s/kubeconfig'"
+        );
+    }
 }
@@ -0,0 +1,120 @@
+//! Install shell completion scripts
+
+use anyhow::{Context, Result, anyhow};
+use clap_complete::Shell;
+use std::path::{Path, PathBuf};
+
+/// Write a generated completion `script` to the conventional per-user location
+/// for `shell`, creating the directory if needed. Returns the installed path.
+pub fn install(shell: Shell, script: &[u8]) -> Result<PathBuf> {
+    let path = install_path(shell, |name| std::env::var(name).ok())?;
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+    }
+    std::fs::write(&path, script)
+        .with_context(|| format!("Failed to write completion script: {}", path.display()))?;
+
+    crate::log_info!("Installed {} completions to {}", shell, path.display());
+    let dir = path.parent().unwrap_or(Path::new("")).display();
+    match shell {
+        Shell::Zsh => crate::log_info!(
+            "Make sure {} is in your fpath, e.g. in ~/.zshrc: fpath=({} $fpath); autoload -Uz compinit && compinit",
+            dir,
+            dir
+        ),
+        Shell::Elvish => crate::log_info!("Load them by adding `use kueue-dev` to your rc.elv"),
+        _ => crate::log_info!("Start a new shell to use them"),
+    }
+
+    Ok(path)
+}
+
+/// Completion script location for `shell`, resolved from environment variables
+/// read through `env` (`HOME`, `XDG_DATA_HOME`, `XDG_CONFIG_HOME`)
+fn install_path(shell: Shell, env: impl Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    let home = env("HOME")
+        .filter(|home| !home.is_empty())
+        .map(PathBuf::from)
+        .ok_or_else(|| anyhow!("HOME is not set; cannot locate the completions directory"))?;
+    let xdg_dir = |var: &str, default: &str| {
+        env(var)
+            .filter(|dir| Path::new(dir).is_absolute())
+            .map(PathBuf::from)
+            .unwrap_or_else(|| home.join(default))
+    };
+
+    match shell {
+        Shell::Bash => {
+            Ok(xdg_dir("XDG_DATA_HOME", ".local/share")
+                .join("bash-completion/completions/kueue-dev"))
+        }
+        Shell::Zsh => Ok(home.join(".zsh/completions/_kueue-dev")),
+        Shell::Fish => {
+            Ok(xdg_dir("XDG_CONFIG_HOME", ".config").join("fish/completions/kueue-dev.fish"))
+        }
+        Shell::Elvish => Ok(xdg_dir("XDG_CONFIG_HOME", ".config").join("elvish/lib/kueue-dev.elv")),
+        _ => Err(anyhow!(
+            "--install is not supported for {}. Save the script with: kueue-dev completion {} > kueue-dev-completion and load it from your shell profile",
+            shell,
+            shell
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |name| {
+            vars.iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.to_string())
+        }
+    }
+
+    #[test]
+    fn test_install_path_defaults() {
+        let vars = [("HOME", "/home/dev")];
+        let path = |shell| install_path(shell, env(&vars)).unwrap();
+
+        assert_eq!(
+            path(Shell::Bash),
+            Path::new("/home/dev/.local/share/bash-completion/completions/kueue-dev")
+        );
+        assert_eq!(
+            path(Shell::Zsh),
+            Path::new("/home/dev/.zsh/completions/_kueue-dev")
+        );
+        assert_eq!(
+            path(Shell::Fish),
+            Path::new("/home/dev/.config/fish/completions/kueue-dev.fish")
+        );
+        assert_eq!(
+            path(Shell::Elvish),
+            Path::new("/home/dev/.config/elvish/lib/kueue-dev.elv")
+        );
+        assert!(install_path(Shell::PowerShell, env(&vars)).is_err());
+    }
+
+    #[test]
+    fn test_install_path_honors_xdg_dirs() {
+        let vars = [
+            ("HOME", "/home/dev"),
+            ("XDG_DATA_HOME", "/data"),
+            ("XDG_CONFIG_HOME", "relative/is/ignored"),
+        ];
+
+        assert_eq!(
+            install_path(Shell::Bash, env(&vars)).unwrap(),
+            Path::new("/data/bash-completion/completions/kueue-dev")
+        );
+        assert_eq!(
+            install_path(Shell::Fish, env(&vars)).unwrap(),
+            Path::new("/home/dev/.config/fish/completions/kueue-dev.fish")
+        );
+        assert!(install_path(Shell::Bash, env(&[])).is_err());
+    }
+}
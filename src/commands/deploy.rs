@@ -1,9 +1,12 @@
 //! Deploy command implementations
 
 use anyhow::{Context, Result};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+use crate::commands::deploy_summary::DeploySummary;
+use crate::commands::deployment_info::DeploymentInfo;
 use crate::config::images::ImageConfig;
 use crate::config::kueue::{Framework, KueueConfig};
 use crate::config::settings::Settings;
@@ -11,10 +14,68 @@ use crate::install::{
     appwrapper, calico, cert_manager, jobset, leaderworkerset, operator, prometheus,
     training_operator, upstream,
 };
+use crate::k8s::target::KubeTarget;
 use crate::k8s::{images, kind, kubectl, nodes};
 use crate::utils::ContainerRuntime;
 
+/// How the operator is deployed to a kind cluster
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeployMethod {
+    /// Bundle when operator-sdk is available, manifests otherwise
+    Auto,
+    /// OLM bundle via operator-sdk
+    Bundle,
+    /// Direct manifests (no OLM)
+    Manifest,
+}
+
+impl FromStr for DeployMethod {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "auto" => Ok(DeployMethod::Auto),
+            "bundle" => Ok(DeployMethod::Bundle),
+            "manifest" => Ok(DeployMethod::Manifest),
+            _ => Err(anyhow::anyhow!(
+                "Invalid deploy method: {}. Must be 'auto', 'bundle', or 'manifest'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for DeployMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeployMethod::Auto => write!(f, "auto"),
+            DeployMethod::Bundle => write!(f, "bundle"),
+            DeployMethod::Manifest => write!(f, "manifest"),
+        }
+    }
+}
+
+impl DeployMethod {
+    /// Decide whether to use the bundle path, returning the reason for the choice
+    fn select(self, operator_sdk_available: bool) -> Result<(bool, &'static str)> {
+        match self {
+            DeployMethod::Bundle if !operator_sdk_available => Err(anyhow::anyhow!(
+                "operator-sdk is required for bundle deployment but not found in PATH.\n\
+                 Install from: https://sdk.operatorframework.io/docs/installation/\n\
+                 Or use --deploy-method manifest (or --no-bundle) to deploy without OLM"
+            )),
+            DeployMethod::Bundle => Ok((true, "bundle deployment requested")),
+            DeployMethod::Manifest => Ok((false, "manifest deployment requested")),
+            DeployMethod::Auto if operator_sdk_available => {
+                Ok((true, "operator-sdk found in PATH"))
+            }
+            DeployMethod::Auto => Ok((false, "operator-sdk not found in PATH")),
+        }
+    }
+}
+
 /// Options for deploying to kind cluster
+#[derive(Clone)]
 pub struct DeployKindOptions {
     pub cluster_name: String,
     pub images_file: String,
@@ -23,7 +84,30 @@ pub struct DeployKindOptions {
     pub skip_kueue_cr: bool,
     pub kueue_frameworks: Option<String>,
     pub kueue_namespace: Option<String>,
-    pub use_bundle: bool,
+    /// Extra labels/annotations for the Kueue CR
+    pub kueue_cr_labels: Vec<(String, String)>,
+    pub kueue_cr_annotations: Vec<(String, String)>,
+    /// Operator replica count (manifest deployments only)
+    pub operator_replicas: Option<u32>,
+    /// Namespace to deploy the operator into (defaults to `operator.namespace` from config)
+    pub operator_namespace: Option<String>,
+    /// Deploy the operator into <operator namespace>-<suffix> (manifest deployments only)
+    pub operator_namespace_suffix: Option<String>,
+    /// Operator imagePullPolicy (manifest deployments only; default IfNotPresent)
+    pub image_pull_policy: Option<operator::ImagePullPolicy>,
+    pub deploy_method: DeployMethod,
+    /// Skip OLM installation in the bundle path (OLM is managed by the user)
+    pub skip_olm_install: bool,
+    /// Skip installing cert-manager, JobSet, LeaderWorkerSet and Prometheus Operator
+    pub skip_deps: bool,
+    /// Skip individual dependencies, reusing what is on the cluster
+    pub skip_cert_manager: bool,
+    pub skip_jobset: bool,
+    pub skip_leaderworkerset: bool,
+    /// Wait for the operator and Kueue to become ready after applying
+    pub wait: bool,
+    /// Write a JSON summary of the deployment to this path
+    pub summary_json: Option<PathBuf>,
     /// Version overrides for dependencies
     pub cert_manager_version: Option<String>,
     pub jobset_version: Option<String>,
@@ -61,24 +145,28 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
     // Load image configuration
     let images_path = PathBuf::from(&options.images_file);
 
-    // Always display images configuration (critical deployment info)
-    eprintln!();
-    eprintln!("Kueue source path: {}", source_path.display());
-    eprintln!("Using images from:  {}", images_path.display());
-    eprintln!();
-
     let image_config = ImageConfig::load(&images_path)?;
 
-    // Display images that will be used
-    eprintln!("Images to be used:");
-    eprintln!("  Bundle:       {}", image_config.bundle()?);
-    eprintln!("  Must-gather:  {}", image_config.must_gather()?);
-    eprintln!("  Operator:     {}", image_config.operator()?);
-    eprintln!("  Operand:      {}", image_config.operand()?);
-    eprintln!();
+    // Display images configuration (critical deployment info) unless --quiet
+    if !crate::utils::is_quiet() {
+        eprintln!();
+        eprintln!("Kueue source path: {}", source_path.display());
+        eprintln!("Using images from:  {}", images_path.display());
+        eprintln!();
+        eprintln!("Images to be used:");
+        eprintln!("  Bundle:       {}", image_config.bundle()?);
+        eprintln!("  Must-gather:  {}", image_config.must_gather()?);
+        eprintln!("  Operator:     {}", image_config.operator()?);
+        eprintln!("  Operand:      {}", image_config.operand()?);
+        eprintln!();
+    }
 
-    // Check if cluster exists
-    let cluster = kind::KindCluster::new(&options.cluster_name, kind::CniProvider::Calico);
+    // Check if cluster exists, with the CNI it was created with
+    let recorded_cni = kind::KindCluster::recorded_cni(&options.cluster_name);
+    let cluster = kind::KindCluster::new(
+        &options.cluster_name,
+        recorded_cni.unwrap_or(kind::CniProvider::Calico),
+    );
     if !cluster.exists()? {
         return Err(anyhow::anyhow!(
             "Cluster '{}' does not exist. Create it first with: kueue-dev cluster create --name {}",
@@ -87,37 +175,105 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
         ));
     }
 
-    // Check for operator-sdk early if using bundle deployment
-    if options.use_bundle {
-        crate::log_info!("Checking for operator-sdk...");
-        if which::which("operator-sdk").is_err() {
+    // Check for operator-sdk early and pick bundle vs manifest deployment
+    crate::log_info!("Checking for operator-sdk...");
+    let operator_sdk_available = which::which("operator-sdk").is_ok();
+    let (use_bundle, reason) = options.deploy_method.select(operator_sdk_available)?;
+    crate::log_info!(
+        "Deploy method: {} ({}, --deploy-method {})",
+        if use_bundle { "bundle" } else { "manifest" },
+        reason,
+        options.deploy_method
+    );
+
+    let manifest_only_flag = if options.operator_replicas.is_some() {
+        Some("--operator-replicas")
+    } else if options.operator_namespace_suffix.is_some() {
+        Some("--namespace-suffix/--namespace-per-run")
+    } else if options.image_pull_policy.is_some() {
+        Some("--image-pull-policy")
+    } else {
+        None
+    };
+    if let Some(flag) = manifest_only_flag.filter(|_| use_bundle) {
+        return Err(anyhow::anyhow!(
+            "{} is only supported for manifest deployments, since OLM \
+             manages the operator deployment for bundles.\n\
+             Use --deploy-method manifest (or --no-bundle)",
+            flag
+        ));
+    }
+    if options.skip_olm_install && !use_bundle {
+        if options.deploy_method == DeployMethod::Manifest {
             return Err(anyhow::anyhow!(
-                "operator-sdk is required for bundle deployment but not found in PATH.\n\
-                 Install from: https://sdk.operatorframework.io/docs/installation/\n\
-                 Or use --no-bundle to deploy without OLM"
+                "--skip-olm-install only applies to bundle deployments, which install OLM"
             ));
         }
+        crate::log_warn!(
+            "--skip-olm-install has no effect: --deploy-method {} picked a manifest deployment, which doesn't use OLM",
+            options.deploy_method
+        );
     }
 
+    let base_namespace = options
+        .operator_namespace
+        .clone()
+        .unwrap_or_else(|| settings.operator.namespace.clone());
+    let overrides = operator::OperatorOverrides {
+        replicas: options.operator_replicas,
+        namespace: match options.operator_namespace_suffix.as_deref() {
+            Some(suffix) => Some(operator::suffixed_namespace(&base_namespace, suffix)?),
+            None => operator::namespace_override(&base_namespace),
+        },
+        // Images are loaded into the kind nodes, so don't pull them again by default
+        image_pull_policy: Some(
+            options
+                .image_pull_policy
+                .unwrap_or(operator::ImagePullPolicy::IfNotPresent),
+        ),
+    };
+    let operator_namespace = overrides.namespace().to_string();
+
     // Get kubeconfig path
     let kubeconfig_path = if let Some(ref kc) = options.kubeconfig {
         PathBuf::from(kc)
     } else {
         crate::utils::operator_source_join("kube.kubeconfig")
     };
-    if !kubeconfig_path.exists() {
-        return Err(anyhow::anyhow!(
-            "Kubeconfig not found at {}. Please create cluster first.",
-            kubeconfig_path.display()
-        ));
-    }
 
-    // Canonicalize to get absolute path
-    let kubeconfig_path = kubeconfig_path.canonicalize().unwrap_or(kubeconfig_path);
+    // Validate the path (or colon-separated list of paths)
+    let kubeconfig_path = crate::utils::resolve_kubeconfig_value(kubeconfig_path.as_os_str())?;
     crate::log_info!("Using kubeconfig: {}", kubeconfig_path.display());
 
-    // Check for and uninstall existing operator installation
-    crate::install::olm::uninstall_operator_if_exists(Some(&kubeconfig_path))?;
+    // A Calico cluster has no pod network until Calico is installed
+    match recorded_cni {
+        Some(kind::CniProvider::Calico) if !calico::is_installed(Some(&kubeconfig_path)) => {
+            crate::log_warn!("Cluster was created for Calico but Calico is not installed");
+            calico::install(
+                &settings.versions.calico,
+                &KubeTarget::new(Some(&kubeconfig_path)),
+            )?;
+        }
+        Some(cni) => crate::log_info!("CNI provider: {}", cni),
+        None => crate::log_info!(
+            "CNI provider not recorded for cluster '{}'; assuming its network is ready",
+            options.cluster_name
+        ),
+    }
+
+    // Check for and uninstall existing operator installation, unless this run
+    // deploys a separate instance next to it
+    if options.operator_namespace_suffix.is_some() {
+        crate::log_info!(
+            "Deploying a separate operator instance into {}; leaving existing installations in place",
+            operator_namespace
+        );
+    } else {
+        crate::install::olm::uninstall_operator_if_exists(
+            &operator_namespace,
+            Some(&kubeconfig_path),
+        )?;
+    }
 
     // Detect container runtime
     let runtime = ContainerRuntime::detect()?;
@@ -126,20 +282,22 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
     // Delete leader election lease to avoid delays from previous deployments
     // This needs to happen before any operator deployment to ensure quick reconciliation
     crate::log_info!("Cleaning up leader election lease from previous deployments...");
-    kubectl::run_kubectl(
+    kubectl::run_kubectl_retry(
         &[
             "delete",
             "lease",
             "openshift-kueue-operator-lock",
             "-n",
-            "openshift-kueue-operator",
+            &operator_namespace,
             "--ignore-not-found",
         ],
-        Some(&kubeconfig_path),
+        &KubeTarget::new(Some(&kubeconfig_path)),
+        5,
+        std::time::Duration::from_secs(2),
     )
     .ok(); // Ignore errors if lease doesn't exist
 
-    if options.use_bundle {
+    if use_bundle {
         crate::log_info!("Deploying via OLM bundle...");
 
         // Start loading images in background while we install dependencies
@@ -149,58 +307,22 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
             image_config.clone(),
             runtime,
             true,
+            images::DEFAULT_MAX_PARALLEL_LOADS,
+            settings.behavior.parallel_operations,
         );
 
         // Install dependencies in parallel while images are loading
-        crate::log_info!("Installing dependencies in parallel...");
-
-        let kubeconfig_path_clone1 = kubeconfig_path.clone();
-        let kubeconfig_path_clone2 = kubeconfig_path.clone();
-        let kubeconfig_path_clone3 = kubeconfig_path.clone();
-        let kubeconfig_path_clone4 = kubeconfig_path.clone();
-        let kubeconfig_path_clone5 = kubeconfig_path.clone();
-
-        let cert_manager_version = settings.versions.cert_manager.clone();
-        let jobset_version = settings.versions.jobset.clone();
-        let leaderworkerset_version = settings.versions.leaderworkerset.clone();
-        let prometheus_version = settings.versions.prometheus_operator.clone();
-
-        let cert_manager_handle = std::thread::spawn(move || {
-            cert_manager::install(&cert_manager_version, Some(&kubeconfig_path_clone1))
-        });
-
-        let jobset_handle = std::thread::spawn(move || {
-            jobset::install(&jobset_version, Some(&kubeconfig_path_clone2))
-        });
-
-        let lws_handle = std::thread::spawn(move || {
-            leaderworkerset::install(&leaderworkerset_version, Some(&kubeconfig_path_clone3))
-        });
-
-        let olm_handle = std::thread::spawn(move || {
-            crate::install::olm::install_olm(Some(&kubeconfig_path_clone4))
-        });
-
-        let prometheus_handle = std::thread::spawn(move || {
-            prometheus::install(&prometheus_version, Some(&kubeconfig_path_clone5))
-        });
-
-        // Wait for all parallel tasks to complete
-        cert_manager_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("cert-manager thread panicked: {:?}", e))??;
-        jobset_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("jobset thread panicked: {:?}", e))??;
-        lws_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("leaderworkerset thread panicked: {:?}", e))??;
-        olm_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("olm thread panicked: {:?}", e))??;
-        prometheus_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("prometheus thread panicked: {:?}", e))??;
+        let olm_ready_timeout =
+            (!options.skip_olm_install).then_some(settings.behavior.olm_ready_timeout);
+        if options.skip_olm_install {
+            crate::log_info!("Skipping OLM installation (--skip-olm-install)");
+        }
+        install_dependencies(
+            &settings,
+            &kubeconfig_path,
+            DependencySkips::from_options(&options),
+            olm_ready_timeout,
+        )?;
 
         // Wait for images to finish loading
         crate::log_info!("Waiting for images to finish loading...");
@@ -215,35 +337,53 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
         // Install operator bundle
         crate::install::olm::install_bundle(
             bundle_image,
+            &operator_namespace,
             &options.cluster_name,
             Some(&kubeconfig_path),
         )?;
 
-        // Wait for operator deployment to be available before creating Kueue CR
-        crate::log_info!("Waiting for operator deployment to be available...");
-        kubectl::wait_for_condition(
-            "deployment/openshift-kueue-operator",
-            "condition=Available",
-            Some("openshift-kueue-operator"),
-            "300s",
-            Some(&kubeconfig_path),
-        )
-        .context("Operator deployment did not become available")?;
-
-        // Give the operator time to start its controllers and be ready to reconcile
-        crate::log_info!("Waiting for operator controllers to be ready...");
-        std::thread::sleep(std::time::Duration::from_secs(30));
+        if options.wait {
+            // Wait for operator deployment to be available before creating Kueue CR
+            crate::log_info!("Waiting for operator deployment to be available...");
+            kubectl::wait_for_deployment_available(
+                "openshift-kueue-operator",
+                &operator_namespace,
+                "300s",
+                &KubeTarget::new(Some(&kubeconfig_path)),
+                settings.behavior.show_progress,
+            )
+            .context("Operator deployment did not become available")?;
+
+            // Wait for the operator to start its controllers and be ready to reconcile
+            operator::wait_for_operator_ready(
+                settings.behavior.operator_ready_timeout,
+                &operator_namespace,
+                Some(&kubeconfig_path),
+            )?;
+        }
 
         // Build Kueue config if not skipping
         if !options.skip_kueue_cr {
+            // A non-default operator namespace also hosts the Kueue CR unless overridden
+            let kueue_namespace = options
+                .kueue_namespace
+                .as_deref()
+                .or(overrides.namespace.as_deref());
             let kueue_config = build_kueue_config_from_settings(
                 &settings,
                 options.kueue_frameworks.as_deref(),
-                options.kueue_namespace.as_deref(),
+                kueue_namespace,
+                &options.kueue_cr_labels,
+                &options.kueue_cr_annotations,
             )?;
 
             // Create Kueue CR
-            operator::create_kueue_cr(&kueue_config, Some(&kubeconfig_path))?;
+            operator::create_kueue_cr(
+                &kueue_config,
+                options.wait,
+                settings.behavior.show_progress,
+                Some(&kubeconfig_path),
+            )?;
         } else {
             crate::log_info!("Skipping Kueue CR creation (--skip-kueue-cr flag provided)");
         }
@@ -257,50 +397,17 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
             image_config.clone(),
             runtime,
             true,
+            images::DEFAULT_MAX_PARALLEL_LOADS,
+            settings.behavior.parallel_operations,
         );
 
         // Install dependencies in parallel while images are loading
-        crate::log_info!("Installing dependencies in parallel...");
-
-        let kubeconfig_path_clone1 = kubeconfig_path.clone();
-        let kubeconfig_path_clone2 = kubeconfig_path.clone();
-        let kubeconfig_path_clone3 = kubeconfig_path.clone();
-        let kubeconfig_path_clone4 = kubeconfig_path.clone();
-
-        let cert_manager_version = settings.versions.cert_manager.clone();
-        let jobset_version = settings.versions.jobset.clone();
-        let leaderworkerset_version = settings.versions.leaderworkerset.clone();
-        let prometheus_version = settings.versions.prometheus_operator.clone();
-
-        let cert_manager_handle = std::thread::spawn(move || {
-            cert_manager::install(&cert_manager_version, Some(&kubeconfig_path_clone1))
-        });
-
-        let jobset_handle = std::thread::spawn(move || {
-            jobset::install(&jobset_version, Some(&kubeconfig_path_clone2))
-        });
-
-        let lws_handle = std::thread::spawn(move || {
-            leaderworkerset::install(&leaderworkerset_version, Some(&kubeconfig_path_clone3))
-        });
-
-        let prometheus_handle = std::thread::spawn(move || {
-            prometheus::install(&prometheus_version, Some(&kubeconfig_path_clone4))
-        });
-
-        // Wait for all parallel tasks to complete
-        cert_manager_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("cert-manager thread panicked: {:?}", e))??;
-        jobset_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("jobset thread panicked: {:?}", e))??;
-        lws_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("leaderworkerset thread panicked: {:?}", e))??;
-        prometheus_handle
-            .join()
-            .map_err(|e| anyhow::anyhow!("prometheus thread panicked: {:?}", e))??;
+        install_dependencies(
+            &settings,
+            &kubeconfig_path,
+            DependencySkips::from_options(&options),
+            None,
+        )?;
 
         // Wait for images to finish loading
         crate::log_info!("Waiting for images to finish loading...");
@@ -316,10 +423,17 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
             crate::log_info!("Skipping Kueue CR creation (--skip-kueue-cr flag provided)");
             None
         } else {
+            // A per-run or non-default operator namespace also hosts the Kueue CR unless overridden
+            let kueue_namespace = options
+                .kueue_namespace
+                .as_deref()
+                .or(overrides.namespace.as_deref());
             Some(build_kueue_config_from_settings(
                 &settings,
                 options.kueue_frameworks.as_deref(),
-                options.kueue_namespace.as_deref(),
+                kueue_namespace,
+                &options.kueue_cr_labels,
+                &options.kueue_cr_annotations,
             )?)
         };
 
@@ -327,47 +441,84 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
         operator::install_operator_with_config(
             &image_config,
             kueue_config.as_ref(),
+            &overrides,
+            settings.behavior.operator_ready_timeout,
+            options.wait,
+            settings.behavior.show_progress,
             Some(&kubeconfig_path),
         )?;
     }
 
+    // Dependencies left as they were on the cluster have unknown versions
+    let skips = DependencySkips::from_options(&options);
+    let versions = DeploymentInfo::operator_dependency_versions(&settings.versions)
+        .into_iter()
+        .filter(|(name, _)| !skips.skips(name))
+        .collect();
+    DeploymentInfo::new(DeploySummary::images_from_config(&image_config), versions)
+        .record(&operator_namespace, Some(&kubeconfig_path));
+
     crate::log_info!("");
     crate::log_info!("==========================================");
     crate::log_info!("Deployment completed successfully!");
     crate::log_info!("==========================================");
+    if !options.wait {
+        crate::log_info!("Readiness waits skipped (--no-wait); the operator may still be starting");
+    }
     crate::log_info!("");
     crate::log_info!("Cluster name: {}", options.cluster_name);
     crate::log_info!("Kubeconfig: {}", kubeconfig_path.display());
+    crate::log_info!("Operator namespace: {}", operator_namespace);
     crate::log_info!("");
 
     // Print operator version
-    match crate::k8s::kubectl::get_operator_version(Some(&kubeconfig_path)) {
+    let operator_version = match crate::k8s::kubectl::get_operator_version(
+        &operator_namespace,
+        &KubeTarget::new(Some(&kubeconfig_path)),
+    ) {
         Ok(version) => {
             crate::log_info!("Operator version: {}", version);
+            Some(version)
         }
         Err(e) => {
             crate::log_warn!("Could not retrieve operator version: {}", e);
+            None
         }
-    }
+    };
 
     // Print kueue-controller-manager version if running
-    match crate::k8s::kubectl::get_kueue_manager_version(
-        "openshift-kueue-operator",
-        Some(&kubeconfig_path),
+    let kueue_version = match crate::k8s::kubectl::get_kueue_manager_version(
+        &operator_namespace,
+        &KubeTarget::new(Some(&kubeconfig_path)),
     ) {
         Ok(version) => {
             crate::log_info!("Kueue controller-manager version: {}", version);
+            Some(version)
         }
         Err(_) => {
             // Don't print warning if kueue-controller-manager is not running
             // as it may not be deployed yet
+            None
+        }
+    };
+
+    if let Some(path) = &options.summary_json {
+        DeploySummary {
+            cluster: Some(options.cluster_name.clone()),
+            kubeconfig: Some(kubeconfig_path.display().to_string()),
+            namespace: operator_namespace.clone(),
+            operator_version,
+            kueue_version,
+            images: DeploySummary::images_from_config(&image_config),
         }
+        .write(path)?;
     }
 
     crate::log_info!("");
     crate::log_info!("To view operator logs:");
     crate::log_info!(
-        "  kubectl logs -n openshift-kueue-operator -l name=openshift-kueue-operator -f"
+        "  kubectl logs -n {} -l name=openshift-kueue-operator -f",
+        operator_namespace
     );
     crate::log_info!("");
 
@@ -378,6 +529,195 @@ pub fn deploy_kind(options: DeployKindOptions) -> Result<()> {
     Ok(())
 }
 
+/// Operator dependencies `deploy operator kind` leaves as they are on the cluster
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct DependencySkips {
+    cert_manager: bool,
+    jobset: bool,
+    leaderworkerset: bool,
+    prometheus: bool,
+}
+
+impl DependencySkips {
+    /// Number of dependencies that can be skipped
+    const COUNT: usize = 4;
+
+    fn from_options(options: &DeployKindOptions) -> Self {
+        Self::new(
+            options.skip_deps,
+            options.skip_cert_manager,
+            options.skip_jobset,
+            options.skip_leaderworkerset,
+        )
+    }
+
+    /// `all` (`--skip-deps`) skips every dependency, the others one each
+    fn new(all: bool, cert_manager: bool, jobset: bool, leaderworkerset: bool) -> Self {
+        Self {
+            cert_manager: all || cert_manager,
+            jobset: all || jobset,
+            leaderworkerset: all || leaderworkerset,
+            prometheus: all,
+        }
+    }
+
+    /// Whether the dependency recorded as `name` in the deployment info is skipped
+    fn skips(&self, name: &str) -> bool {
+        match name {
+            "cert_manager" => self.cert_manager,
+            "jobset" => self.jobset,
+            "leaderworkerset" => self.leaderworkerset,
+            "prometheus_operator" => self.prometheus,
+            _ => false,
+        }
+    }
+
+    /// Names of the skipped dependencies, for logging
+    fn skipped(&self) -> Vec<&'static str> {
+        [
+            ("cert-manager", self.cert_manager),
+            ("JobSet", self.jobset),
+            ("LeaderWorkerSet", self.leaderworkerset),
+            ("Prometheus Operator", self.prometheus),
+        ]
+        .into_iter()
+        .filter_map(|(name, skipped)| skipped.then_some(name))
+        .collect()
+    }
+}
+
+/// Install cert-manager, JobSet, LeaderWorkerSet and the Prometheus Operator in
+/// parallel, plus OLM when `olm_ready_timeout` is given. Each dependency skips
+/// itself when already installed at the requested version; `skips` leaves
+/// dependencies untouched (never OLM).
+fn install_dependencies(
+    settings: &Settings,
+    kubeconfig: &Path,
+    skips: DependencySkips,
+    olm_ready_timeout: Option<u64>,
+) -> Result<()> {
+    let skipped = skips.skipped();
+    if skipped.len() == DependencySkips::COUNT {
+        crate::log_info!("Skipping dependency installation (--skip-deps)");
+    } else if skipped.is_empty() {
+        crate::log_info!("Installing dependencies in parallel...");
+    } else {
+        crate::log_info!(
+            "Installing dependencies in parallel, skipping {}...",
+            skipped.join(", ")
+        );
+    }
+
+    let versions = &settings.versions;
+    let kubeconfig = Some(kubeconfig);
+    std::thread::scope(|s| {
+        let mut dependencies = Vec::new();
+        if !skips.cert_manager {
+            dependencies.push((
+                "cert-manager",
+                s.spawn(|| cert_manager::install(&versions.cert_manager, kubeconfig)),
+            ));
+        }
+        if !skips.jobset {
+            dependencies.push((
+                "jobset",
+                s.spawn(|| jobset::install(&versions.jobset, kubeconfig)),
+            ));
+        }
+        if !skips.leaderworkerset {
+            dependencies.push((
+                "leaderworkerset",
+                s.spawn(|| leaderworkerset::install(&versions.leaderworkerset, kubeconfig)),
+            ));
+        }
+        if !skips.prometheus {
+            dependencies.push((
+                "prometheus",
+                s.spawn(|| prometheus::install(&versions.prometheus_operator, kubeconfig)),
+            ));
+        }
+        let olm_handle = olm_ready_timeout
+            .map(|timeout| s.spawn(move || crate::install::olm::install_olm(timeout, kubeconfig)));
+
+        // Wait for all parallel tasks to complete
+        for (name, handle) in dependencies {
+            handle
+                .join()
+                .map_err(|e| anyhow::anyhow!("{} thread panicked: {:?}", name, e))??;
+        }
+        if let Some(olm_handle) = olm_handle {
+            olm_handle
+                .join()
+                .map_err(|e| anyhow::anyhow!("olm thread panicked: {:?}", e))??;
+        }
+        Ok(())
+    })
+}
+
+/// Base delay between whole-deploy retries; doubled on each attempt
+const DEPLOY_RETRY_BASE_DELAY: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Run `deploy_kind`, retrying the whole deploy up to `retries` additional times
+/// when it fails with a transient (network/timeout) error
+pub fn deploy_kind_with_retries(options: DeployKindOptions, retries: u32) -> Result<()> {
+    let max_attempts = retries + 1;
+    let mut failures: Vec<String> = Vec::new();
+
+    for attempt in 1..=max_attempts {
+        if attempt > 1 {
+            crate::log_info!("Deploy attempt {}/{}...", attempt, max_attempts);
+        }
+
+        match deploy_kind(options.clone()) {
+            Ok(()) => {
+                if !failures.is_empty() {
+                    print_deploy_attempt_summary(&failures, true);
+                }
+                return Ok(());
+            }
+            Err(e) => {
+                failures.push(format!("{:#}", e));
+                if !should_retry_deploy(&e, attempt, max_attempts) {
+                    if max_attempts > 1 {
+                        print_deploy_attempt_summary(&failures, false);
+                    }
+                    return Err(e);
+                }
+
+                let delay = kubectl::retry_delay(DEPLOY_RETRY_BASE_DELAY, attempt);
+                crate::log_warn!(
+                    "Deploy failed with a transient error (attempt {}/{}), retrying in {}s...",
+                    attempt,
+                    max_attempts,
+                    delay.as_secs()
+                );
+                std::thread::sleep(delay);
+            }
+        }
+    }
+
+    unreachable!("the final attempt always returns")
+}
+
+/// Decide whether a failed deploy should be retried. Only transient failures
+/// (as classified for `enhance_error`) are retried.
+fn should_retry_deploy(err: &anyhow::Error, attempt: u32, max_attempts: u32) -> bool {
+    attempt < max_attempts && crate::utils::is_transient_error(&format!("{:#}", err))
+}
+
+/// Print a summary of failed deploy attempts
+fn print_deploy_attempt_summary(failures: &[String], succeeded: bool) {
+    crate::log_info!("");
+    crate::log_info!("Deploy attempt summary:");
+    for (index, failure) in failures.iter().enumerate() {
+        let first_line = failure.lines().next().unwrap_or_default();
+        crate::log_info!("  Attempt {}: failed - {}", index + 1, first_line);
+    }
+    if succeeded {
+        crate::log_info!("  Attempt {}: succeeded", failures.len() + 1);
+    }
+}
+
 /// Handle deploy kind with full cluster creation and deployment
 pub fn deploy_kind_full(
     cluster_name: String,
@@ -413,11 +753,15 @@ pub fn deploy_kind_full(
 
     // Install Calico if selected
     if matches!(cni_provider, kind::CniProvider::Calico) {
-        calico::install(&settings.versions.calico, Some(&kubeconfig_path))?;
+        calico::install(
+            &settings.versions.calico,
+            &KubeTarget::new(Some(&kubeconfig_path)),
+        )?;
     }
 
     // Label worker nodes
-    nodes::label_worker_nodes(Some(&kubeconfig_path))?;
+    let worker_labels = nodes::WorkerLabels::new(&settings.tests, Vec::new(), None)?;
+    nodes::label_worker_nodes(&KubeTarget::new(Some(&kubeconfig_path)), &worker_labels)?;
 
     // Now deploy the operator
     deploy_kind(DeployKindOptions {
@@ -428,7 +772,20 @@ pub fn deploy_kind_full(
         skip_kueue_cr: false,
         kueue_frameworks: None,
         kueue_namespace: None,
-        use_bundle: true,
+        kueue_cr_labels: Vec::new(),
+        kueue_cr_annotations: Vec::new(),
+        operator_replicas: None,
+        operator_namespace: None,
+        operator_namespace_suffix: None,
+        image_pull_policy: None,
+        deploy_method: DeployMethod::Bundle,
+        skip_olm_install: false,
+        skip_deps: false,
+        skip_cert_manager: false,
+        skip_jobset: false,
+        skip_leaderworkerset: false,
+        wait: true,
+        summary_json: None,
         cert_manager_version: None,
         jobset_version: None,
         leaderworkerset_version: None,
@@ -443,6 +800,8 @@ fn build_kueue_config_from_settings(
     settings: &Settings,
     frameworks_override: Option<&str>,
     namespace_override: Option<&str>,
+    labels: &[(String, String)],
+    annotations: &[(String, String)],
 ) -> Result<KueueConfig> {
     let namespace = namespace_override.unwrap_or(&settings.kueue.namespace);
 
@@ -482,6 +841,13 @@ fn build_kueue_config_from_settings(
         builder = builder.frameworks(frameworks);
     }
 
+    for (key, value) in labels {
+        builder = builder.label(key, value);
+    }
+    for (key, value) in annotations {
+        builder = builder.annotation(key, value);
+    }
+
     builder.build()
 }
 
@@ -489,6 +855,8 @@ fn build_kueue_config_from_settings(
 pub struct DeployUpstreamKustomizeOptions {
     /// Path to upstream kueue source (overrides config)
     pub source: Option<String>,
+    /// Fail instead of warning when the upstream source is inside the operator source
+    pub strict: bool,
     /// Kustomize overlay to use (default, dev, alpha-enabled)
     pub overlay: String,
     /// Optional image override for the controller
@@ -497,6 +865,8 @@ pub struct DeployUpstreamKustomizeOptions {
     pub build_image: bool,
     /// Custom image tag when building
     pub image_tag: Option<String>,
+    /// How to build the image (make target or Dockerfile)
+    pub build_method: upstream::ImageBuildMethod,
     /// Namespace to deploy to
     pub namespace: String,
     /// Path to kubeconfig
@@ -505,6 +875,12 @@ pub struct DeployUpstreamKustomizeOptions {
     pub cluster_name: String,
     /// Skip installing dependencies (cert-manager, jobset, leaderworkerset, appwrapper, training-operator)
     pub skip_deps: bool,
+    /// Fail instead of warning when a dependency version is untested with this kueue release
+    pub strict_versions: bool,
+    /// Wait for CRDs and kueue-controller-manager to become ready after applying
+    pub wait: bool,
+    /// Write a JSON summary of the deployment to this path
+    pub summary_json: Option<PathBuf>,
     /// Version overrides for dependencies
     pub cert_manager_version: Option<String>,
     pub jobset_version: Option<String>,
@@ -517,6 +893,8 @@ pub struct DeployUpstreamKustomizeOptions {
 pub struct DeployUpstreamHelmOptions {
     /// Path to upstream kueue source (overrides config)
     pub source: Option<String>,
+    /// Fail instead of warning when the upstream source is inside the operator source
+    pub strict: bool,
     /// Helm release name
     pub release_name: String,
     /// Namespace to deploy to
@@ -525,16 +903,28 @@ pub struct DeployUpstreamHelmOptions {
     pub values_file: Option<String>,
     /// Additional --set values
     pub set_values: Vec<String>,
+    /// Additional --set-string values
+    pub set_string_values: Vec<String>,
+    /// Additional --set-file values (KEY=path)
+    pub set_file_values: Vec<String>,
     /// Build kueue image from source
     pub build_image: bool,
     /// Custom image tag when building
     pub image_tag: Option<String>,
+    /// How to build the image (make target or Dockerfile)
+    pub build_method: upstream::ImageBuildMethod,
     /// Path to kubeconfig
     pub kubeconfig: Option<String>,
     /// Cluster name (for kind clusters)
     pub cluster_name: String,
     /// Skip installing dependencies (cert-manager, jobset, leaderworkerset, appwrapper, training-operator)
     pub skip_deps: bool,
+    /// Fail instead of warning when a dependency version is untested with this kueue release
+    pub strict_versions: bool,
+    /// Wait for CRDs and kueue-controller-manager to become ready after applying
+    pub wait: bool,
+    /// Write a JSON summary of the deployment to this path
+    pub summary_json: Option<PathBuf>,
     /// Version overrides for dependencies
     pub cert_manager_version: Option<String>,
     pub jobset_version: Option<String>,
@@ -551,6 +941,7 @@ pub fn deploy_upstream_kustomize(options: DeployUpstreamKustomizeOptions) -> Res
     let source_path = upstream::resolve_upstream_source(
         options.source.as_deref(),
         settings.defaults.upstream_source.as_deref(),
+        options.strict,
     )?;
 
     crate::log_info!("Deploying upstream kueue via kustomize");
@@ -570,6 +961,7 @@ pub fn deploy_upstream_kustomize(options: DeployUpstreamKustomizeOptions) -> Res
             &source_path,
             &options.cluster_name,
             options.image_tag.as_deref(),
+            &options.build_method,
             &runtime,
         )?;
         Some(built_image)
@@ -582,6 +974,8 @@ pub fn deploy_upstream_kustomize(options: DeployUpstreamKustomizeOptions) -> Res
         install_upstream_dependencies(
             &kubeconfig_path,
             &settings,
+            upstream::source_kueue_version(&source_path).as_deref(),
+            options.strict_versions,
             options.cert_manager_version.as_deref(),
             options.jobset_version.as_deref(),
             options.leaderworkerset_version.as_deref(),
@@ -593,29 +987,52 @@ pub fn deploy_upstream_kustomize(options: DeployUpstreamKustomizeOptions) -> Res
     }
 
     // Deploy via kustomize
+    let summary_images = image
+        .iter()
+        .map(|i| ("kueue".to_string(), i.clone()))
+        .collect();
     let kustomize_options = upstream::KustomizeOptions {
         source_path,
         overlay: options.overlay,
         image,
         namespace: options.namespace.clone(),
         kubeconfig: Some(kubeconfig_path.clone()),
+        wait: options.wait,
+        apply_timeout: std::time::Duration::from_secs(settings.behavior.apply_timeout),
+        show_progress: settings.behavior.show_progress,
     };
 
     upstream::deploy_kustomize(&kustomize_options)?;
 
     print_upstream_success(&options.cluster_name, &kubeconfig_path, &options.namespace);
 
+    if let Some(path) = &options.summary_json {
+        write_upstream_summary(
+            path,
+            &options.cluster_name,
+            &kubeconfig_path,
+            &options.namespace,
+            summary_images,
+        )?;
+    }
+
     Ok(())
 }
 
 /// Deploy upstream kueue via helm
 pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
+    // Catch malformed values before building images or installing dependencies
+    upstream::validate_set_values("--set", &options.set_values)?;
+    upstream::validate_set_values("--set-string", &options.set_string_values)?;
+    upstream::validate_set_values("--set-file", &options.set_file_values)?;
+
     let settings = Settings::load()?;
 
     // Resolve upstream source path
     let source_path = upstream::resolve_upstream_source(
         options.source.as_deref(),
         settings.defaults.upstream_source.as_deref(),
+        options.strict,
     )?;
 
     crate::log_info!("Deploying upstream kueue via helm");
@@ -629,6 +1046,7 @@ pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
 
     // Build image if requested and add to set_values
     let mut set_values = options.set_values;
+    let mut summary_images = BTreeMap::new();
     if options.build_image {
         let runtime = ContainerRuntime::detect()?;
         crate::log_info!("Building and loading kueue image...");
@@ -636,6 +1054,7 @@ pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
             &source_path,
             &options.cluster_name,
             options.image_tag.as_deref(),
+            &options.build_method,
             &runtime,
         )?;
         // Add image override to helm values
@@ -656,6 +1075,7 @@ pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
         }
         // Set imagePullPolicy to Never for locally loaded images
         set_values.push("controllerManager.manager.image.pullPolicy=Never".to_string());
+        summary_images.insert("kueue".to_string(), built_image);
     }
 
     // Install dependencies if not skipped
@@ -663,6 +1083,8 @@ pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
         install_upstream_dependencies(
             &kubeconfig_path,
             &settings,
+            upstream::source_kueue_version(&source_path).as_deref(),
+            options.strict_versions,
             options.cert_manager_version.as_deref(),
             options.jobset_version.as_deref(),
             options.leaderworkerset_version.as_deref(),
@@ -680,24 +1102,60 @@ pub fn deploy_upstream_helm(options: DeployUpstreamHelmOptions) -> Result<()> {
         namespace: options.namespace.clone(),
         values_file: options.values_file.map(PathBuf::from),
         set_values,
+        set_string_values: options.set_string_values,
+        set_file_values: options.set_file_values,
         kubeconfig: Some(kubeconfig_path.clone()),
+        wait: options.wait,
+        show_progress: settings.behavior.show_progress,
     };
 
     upstream::deploy_helm(&helm_options)?;
 
     print_upstream_success(&options.cluster_name, &kubeconfig_path, &options.namespace);
 
+    if let Some(path) = &options.summary_json {
+        write_upstream_summary(
+            path,
+            &options.cluster_name,
+            &kubeconfig_path,
+            &options.namespace,
+            summary_images,
+        )?;
+    }
+
     Ok(())
 }
 
+/// Write the `--summary-json` record for an upstream deploy
+fn write_upstream_summary(
+    path: &std::path::Path,
+    cluster_name: &str,
+    kubeconfig: &std::path::Path,
+    namespace: &str,
+    images: BTreeMap<String, String>,
+) -> Result<()> {
+    DeploySummary {
+        cluster: Some(cluster_name.to_string()),
+        kubeconfig: Some(kubeconfig.display().to_string()),
+        namespace: namespace.to_string(),
+        operator_version: None,
+        kueue_version: kubectl::get_kueue_manager_version(
+            namespace,
+            &KubeTarget::new(Some(kubeconfig)),
+        )
+        .ok(),
+        images,
+    }
+    .write(path)
+}
+
 /// Resolve kubeconfig path from options or cluster name
-fn resolve_kubeconfig(kubeconfig: &Option<String>, cluster_name: &str) -> Result<PathBuf> {
+pub(crate) fn resolve_kubeconfig(
+    kubeconfig: &Option<String>,
+    cluster_name: &str,
+) -> Result<PathBuf> {
     if let Some(kc) = kubeconfig {
-        let path = PathBuf::from(kc);
-        if path.exists() {
-            return Ok(path.canonicalize().unwrap_or(path));
-        }
-        return Err(anyhow::anyhow!("Kubeconfig not found: {}", kc));
+        return crate::utils::resolve_kubeconfig_value(std::ffi::OsStr::new(kc));
     }
 
     // Try default locations
@@ -729,6 +1187,8 @@ fn resolve_kubeconfig(kubeconfig: &Option<String>, cluster_name: &str) -> Result
 fn install_upstream_dependencies(
     kubeconfig: &std::path::Path,
     settings: &Settings,
+    kueue_version: Option<&str>,
+    strict_versions: bool,
     cert_manager_version: Option<&str>,
     jobset_version: Option<&str>,
     leaderworkerset_version: Option<&str>,
@@ -759,6 +1219,34 @@ fn install_upstream_dependencies(
         .map(String::from)
         .unwrap_or_else(|| settings.versions.training_operator.clone());
 
+    // Only versions changed from the built-in defaults are checked
+    let defaults = crate::config::settings::Versions::default();
+    let overridden: Vec<(&str, &str)> = [
+        ("cert_manager", &cert_manager_ver, &defaults.cert_manager),
+        ("jobset", &jobset_ver, &defaults.jobset),
+        (
+            "leaderworkerset",
+            &leaderworkerset_ver,
+            &defaults.leaderworkerset,
+        ),
+        ("appwrapper", &appwrapper_ver, &defaults.appwrapper),
+        (
+            "training_operator",
+            &training_operator_ver,
+            &defaults.training_operator,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, version, default)| version != default)
+    .map(|(dependency, version, _)| (dependency, version.as_str()))
+    .collect();
+    crate::config::compat::validate(
+        &settings.compatibility_matrix(),
+        kueue_version,
+        &overridden,
+        strict_versions,
+    )?;
+
     let cert_manager_handle = std::thread::spawn(move || {
         cert_manager::install(&cert_manager_ver, Some(&kubeconfig_clone1))
     });
@@ -820,8 +1308,76 @@ fn print_upstream_success(cluster_name: &str, kubeconfig: &std::path::Path, name
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dependency_skips() {
+        let none = DependencySkips::new(false, false, false, false);
+        assert_eq!(none, DependencySkips::default());
+        assert!(none.skipped().is_empty());
+
+        let all = DependencySkips::new(true, false, false, false);
+        assert_eq!(all.skipped().len(), DependencySkips::COUNT);
+        assert!(all.skips("prometheus_operator"));
+
+        // Partial skips leave the other dependencies to their installers
+        let partial = DependencySkips::new(false, true, false, true);
+        assert_eq!(partial.skipped(), vec!["cert-manager", "LeaderWorkerSet"]);
+        assert!(partial.skips("cert_manager"));
+        assert!(!partial.skips("jobset"));
+        assert!(partial.skips("leaderworkerset"));
+        assert!(!partial.skips("prometheus_operator"));
+
+        // Recorded versions drop exactly the skipped dependencies
+        let versions: Vec<String> =
+            DeploymentInfo::operator_dependency_versions(&Default::default())
+                .into_keys()
+                .filter(|name| !partial.skips(name))
+                .collect();
+        assert_eq!(versions, vec!["jobset", "prometheus_operator"]);
+    }
+
     #[test]
     fn test_deploy_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_deploy_method_from_str() {
+        assert_eq!(DeployMethod::from_str("auto").unwrap(), DeployMethod::Auto);
+        assert_eq!(
+            DeployMethod::from_str("Bundle").unwrap(),
+            DeployMethod::Bundle
+        );
+        assert_eq!(
+            DeployMethod::from_str("manifest").unwrap(),
+            DeployMethod::Manifest
+        );
+        assert!(DeployMethod::from_str("olm").is_err());
+    }
+
+    #[test]
+    fn test_deploy_method_select() {
+        assert!(DeployMethod::Auto.select(true).unwrap().0);
+        assert!(!DeployMethod::Auto.select(false).unwrap().0);
+        assert!(DeployMethod::Bundle.select(true).unwrap().0);
+        assert!(DeployMethod::Bundle.select(false).is_err());
+        assert!(!DeployMethod::Manifest.select(true).unwrap().0);
+        assert!(!DeployMethod::Manifest.select(false).unwrap().0);
+    }
+
+    #[test]
+    fn test_should_retry_deploy_transient() {
+        let err = anyhow::anyhow!("dial tcp 127.0.0.1:6443: connect: connection refused")
+            .context("Failed to install cert-manager");
+        assert!(should_retry_deploy(&err, 1, 3));
+        assert!(should_retry_deploy(&err, 2, 3));
+        assert!(!should_retry_deploy(&err, 3, 3));
+    }
+
+    #[test]
+    fn test_should_retry_deploy_non_transient() {
+        let err = anyhow::anyhow!("Failed to load image config: related_images.json");
+        assert!(!should_retry_deploy(&err, 1, 3));
+    }
 }
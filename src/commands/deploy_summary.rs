@@ -0,0 +1,84 @@
+//! Machine-readable record of what a deploy put on the cluster
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::config::images::ImageConfig;
+
+/// Summary written by `--summary-json` at the end of a successful deploy
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct DeploySummary {
+    /// Kind cluster name, or the kubectl context for OpenShift
+    pub cluster: Option<String>,
+    pub kubeconfig: Option<String>,
+    pub namespace: String,
+    /// Operator version from its startup logs (not set for upstream deploys)
+    pub operator_version: Option<String>,
+    /// kueue-controller-manager version from its startup logs
+    pub kueue_version: Option<String>,
+    /// Deployed images by name
+    pub images: BTreeMap<String, String>,
+}
+
+impl DeploySummary {
+    /// Images of `config` keyed by their related-images name
+    pub fn images_from_config(config: &ImageConfig) -> BTreeMap<String, String> {
+        config
+            .list()
+            .into_iter()
+            .map(|(name, image)| (name.to_string(), image.to_string()))
+            .collect()
+    }
+
+    /// Write the summary as pretty-printed JSON
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("Failed to write deploy summary: {}", path.display()))?;
+
+        crate::log_info!("Wrote deploy summary to {}", path.display());
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_summary() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out/summary.json");
+
+        let summary = DeploySummary {
+            cluster: Some("kueue-test".to_string()),
+            kubeconfig: Some("/tmp/kube.kubeconfig".to_string()),
+            namespace: "openshift-kueue-operator".to_string(),
+            operator_version: Some("v1.1.0".to_string()),
+            kueue_version: None,
+            images: BTreeMap::from([(
+                "operator".to_string(),
+                "quay.io/me/kueue-operator:dev".to_string(),
+            )]),
+        };
+        summary.write(&path).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["cluster"], "kueue-test");
+        assert_eq!(written["namespace"], "openshift-kueue-operator");
+        assert_eq!(written["operator_version"], "v1.1.0");
+        assert!(written["kueue_version"].is_null());
+        assert_eq!(
+            written["images"]["operator"],
+            "quay.io/me/kueue-operator:dev"
+        );
+    }
+}
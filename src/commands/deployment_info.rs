@@ -0,0 +1,209 @@
+//! Deployment metadata recorded in the cluster as a ConfigMap
+
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::config::settings::Versions;
+use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+
+/// ConfigMap written to the operator namespace by each deploy
+pub const CONFIGMAP_NAME: &str = "kueue-dev-deployment-info";
+
+const CLI_VERSION_KEY: &str = "cli-version";
+const DEPLOYED_AT_KEY: &str = "deployed-at";
+const IMAGE_PREFIX: &str = "image.";
+const VERSION_PREFIX: &str = "version.";
+
+/// What a deploy put on the cluster, and with which kueue-dev
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeploymentInfo {
+    pub cli_version: String,
+    /// RFC 3339 UTC time of the deploy
+    pub deployed_at: String,
+    /// Deployed images by name
+    pub images: BTreeMap<String, String>,
+    /// Dependency versions by name
+    pub versions: BTreeMap<String, String>,
+}
+
+impl DeploymentInfo {
+    /// Info for a deploy happening now
+    pub fn new(images: BTreeMap<String, String>, versions: BTreeMap<String, String>) -> Self {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        Self {
+            cli_version: env!("CARGO_PKG_VERSION").to_string(),
+            deployed_at: rfc3339_utc(now),
+            images,
+            versions,
+        }
+    }
+
+    /// Versions of the dependencies the operator deploys install
+    pub fn operator_dependency_versions(versions: &Versions) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("cert_manager".to_string(), versions.cert_manager.clone()),
+            ("jobset".to_string(), versions.jobset.clone()),
+            (
+                "leaderworkerset".to_string(),
+                versions.leaderworkerset.clone(),
+            ),
+            (
+                "prometheus_operator".to_string(),
+                versions.prometheus_operator.clone(),
+            ),
+        ])
+    }
+
+    /// The ConfigMap manifest recording this info in `namespace`
+    pub fn to_configmap(&self, namespace: &str) -> Result<String> {
+        let mut data = BTreeMap::from([
+            (CLI_VERSION_KEY.to_string(), self.cli_version.clone()),
+            (DEPLOYED_AT_KEY.to_string(), self.deployed_at.clone()),
+        ]);
+        data.extend(
+            self.images
+                .iter()
+                .map(|(name, image)| (format!("{}{}", IMAGE_PREFIX, name), image.clone())),
+        );
+        data.extend(
+            self.versions
+                .iter()
+                .map(|(name, version)| (format!("{}{}", VERSION_PREFIX, name), version.clone())),
+        );
+
+        let configmap = serde_json::json!({
+            "apiVersion": "v1",
+            "kind": "ConfigMap",
+            "metadata": {
+                "name": CONFIGMAP_NAME,
+                "namespace": namespace,
+                "labels": { "app.kubernetes.io/managed-by": "kueue-dev" },
+            },
+            "data": data,
+        });
+        Ok(serde_yaml::to_string(&configmap)?)
+    }
+
+    /// Parse the `data` of the ConfigMap
+    fn from_data(data: &BTreeMap<String, String>) -> Self {
+        let prefixed = |prefix: &str| {
+            data.iter()
+                .filter_map(|(key, value)| {
+                    key.strip_prefix(prefix)
+                        .map(|name| (name.to_string(), value.clone()))
+                })
+                .collect()
+        };
+        Self {
+            cli_version: data.get(CLI_VERSION_KEY).cloned().unwrap_or_default(),
+            deployed_at: data.get(DEPLOYED_AT_KEY).cloned().unwrap_or_default(),
+            images: prefixed(IMAGE_PREFIX),
+            versions: prefixed(VERSION_PREFIX),
+        }
+    }
+
+    /// Apply the ConfigMap. Best effort: failures are logged, not returned.
+    pub fn record(&self, namespace: &str, kubeconfig: Option<&Path>) {
+        let result = self
+            .to_configmap(namespace)
+            .and_then(|yaml| kubectl::apply_yaml(&yaml, &KubeTarget::new(kubeconfig)));
+        match result {
+            Ok(()) => crate::log_info!(
+                "Recorded deployment info in configmap/{} ({})",
+                CONFIGMAP_NAME,
+                namespace
+            ),
+            Err(e) => crate::log_warn!(
+                "Could not record deployment info in configmap/{}: {:#}",
+                CONFIGMAP_NAME,
+                e
+            ),
+        }
+    }
+
+    /// Read the info recorded in `namespace`
+    pub fn read(namespace: &str, target: &KubeTarget) -> Result<Self> {
+        let data = kubectl::get_with_jsonpath(
+            &format!("configmap/{}", CONFIGMAP_NAME),
+            Some(namespace),
+            "{.data}",
+            target,
+        )?;
+        let data: BTreeMap<String, String> =
+            serde_json::from_str(&data).context("Unexpected deployment info ConfigMap data")?;
+        Ok(Self::from_data(&data))
+    }
+}
+
+/// Format seconds since the Unix epoch as `YYYY-MM-DDTHH:MM:SSZ`
+fn rfc3339_utc(secs: u64) -> String {
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_configmap_round_trip() {
+        let info = DeploymentInfo {
+            cli_version: "0.9.0".to_string(),
+            deployed_at: "2026-01-02T03:04:05Z".to_string(),
+            images: BTreeMap::from([(
+                "operator".to_string(),
+                "quay.io/me/kueue-operator:dev".to_string(),
+            )]),
+            versions: BTreeMap::from([("jobset".to_string(), "v0.10.1".to_string())]),
+        };
+
+        let yaml = info.to_configmap("openshift-kueue-operator").unwrap();
+        let configmap: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(configmap["metadata"]["name"], CONFIGMAP_NAME);
+        assert_eq!(
+            configmap["metadata"]["namespace"],
+            "openshift-kueue-operator"
+        );
+        assert_eq!(
+            configmap["data"]["image.operator"],
+            "quay.io/me/kueue-operator:dev"
+        );
+
+        let data: BTreeMap<String, String> =
+            serde_yaml::from_value(configmap["data"].clone()).unwrap();
+        assert_eq!(DeploymentInfo::from_data(&data), info);
+    }
+
+    #[test]
+    fn test_rfc3339_utc() {
+        assert_eq!(rfc3339_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339_utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339_utc(1_790_000_000), "2026-09-21T14:13:20Z");
+    }
+}
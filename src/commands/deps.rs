@@ -0,0 +1,112 @@
+//! Install individual dependencies for debugging
+
+use anyhow::Result;
+
+use crate::config::settings::{Settings, Versions};
+use crate::install::{
+    appwrapper, calico, cert_manager, jobset, leaderworkerset, prometheus, training_operator,
+};
+
+/// Dependency names accepted by `deps install`
+const VALID_DEPENDENCIES: &[&str] = &[
+    "cert-manager",
+    "jobset",
+    "leaderworkerset",
+    "appwrapper",
+    "training-operator",
+    "prometheus",
+    "calico",
+];
+
+/// Look up the configured version for a dependency
+fn configured_version<'a>(name: &str, versions: &'a Versions) -> Option<&'a str> {
+    let version = match name {
+        "cert-manager" => &versions.cert_manager,
+        "jobset" => &versions.jobset,
+        "leaderworkerset" => &versions.leaderworkerset,
+        "appwrapper" => &versions.appwrapper,
+        "training-operator" => &versions.training_operator,
+        "prometheus" => &versions.prometheus_operator,
+        "calico" => &versions.calico,
+        _ => return None,
+    };
+    Some(version.as_str())
+}
+
+/// Install a single dependency using the configured or overridden version
+pub fn install(
+    name: &str,
+    version: Option<String>,
+    kubeconfig: Option<String>,
+    cluster_name: &str,
+) -> Result<()> {
+    let settings = Settings::load()?;
+
+    let configured = configured_version(name, &settings.versions).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid dependency '{}'. Valid dependencies are: {}",
+            name,
+            VALID_DEPENDENCIES.join(", ")
+        )
+    })?;
+    let version = version.unwrap_or_else(|| configured.to_string());
+
+    let kubeconfig_path = super::deploy::resolve_kubeconfig(&kubeconfig, cluster_name)?;
+    crate::log_info!("Using kubeconfig: {}", kubeconfig_path.display());
+
+    let kc = Some(kubeconfig_path.as_path());
+    match name {
+        "cert-manager" => cert_manager::install(&version, kc),
+        "jobset" => jobset::install(&version, kc),
+        "leaderworkerset" => leaderworkerset::install(&version, kc),
+        "appwrapper" => appwrapper::install(&version, kc),
+        "training-operator" => training_operator::install(&version, kc),
+        "prometheus" => prometheus::install(&version, kc),
+        "calico" => calico::install(&version, &crate::k8s::target::KubeTarget::new(kc)),
+        _ => unreachable!("dependency name validated above"),
+    }
+}
+
+/// List dependencies and their configured versions
+pub fn list() -> Result<()> {
+    let settings = Settings::load()?;
+
+    println!("{:<20} VERSION", "DEPENDENCY");
+    for name in VALID_DEPENDENCIES {
+        let version = configured_version(name, &settings.versions).unwrap_or("unknown");
+        println!("{:<20} {}", name, version);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_all_dependencies_have_versions() {
+        let versions = Versions::default();
+        for name in VALID_DEPENDENCIES {
+            assert!(
+                configured_version(name, &versions).is_some(),
+                "missing version for {}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_configured_version_mapping() {
+        let versions = Versions::default();
+        assert_eq!(
+            configured_version("prometheus", &versions),
+            Some(versions.prometheus_operator.as_str())
+        );
+        assert_eq!(
+            configured_version("training-operator", &versions),
+            Some(versions.training_operator.as_str())
+        );
+        assert_eq!(configured_version("olm", &versions), None);
+    }
+}
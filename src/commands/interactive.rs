@@ -1,50 +1,247 @@
 //! Interactive menu for debugging and cluster management
+//!
+//! The menu actions are also exposed as direct subcommands (`logs`,
+//! `port-forward`, `info`) through [`MenuContext::detect`].
 
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use std::io::{self, Write};
 use std::path::Path;
 use std::process::Command;
+use std::str::FromStr;
 
+use crate::config::settings::{InteractiveSettings, Settings};
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+
+/// Namespace of an upstream (non-operator) kueue install
+const UPSTREAM_KUEUE_NAMESPACE: &str = "kueue-system";
+
+/// Label on Prometheus instance pods, both on OpenShift and from prometheus-operator
+const PROMETHEUS_SELECTOR: &str = "app.kubernetes.io/name=prometheus";
+
+/// Where the menu actions look for Prometheus and kueue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MenuTargets {
+    pub monitoring_namespace: String,
+    pub prometheus_service: String,
+    pub prometheus_operator_namespace: String,
+    /// Namespace of the kueue-operator deployment, if the operator is installed
+    pub operator_namespace: Option<String>,
+    /// Namespace of the kueue-controller-manager deployment
+    pub kueue_namespace: String,
+}
+
+/// What was found on the cluster when the menu started
+#[derive(Debug, Clone, Copy, Default)]
+struct ClusterLayout {
+    openshift_monitoring: bool,
+    operator_installed: bool,
+}
 
-/// Show interactive menu for cluster operations
-pub fn show_menu(kubeconfig: Option<&Path>) -> Result<()> {
+impl ClusterLayout {
+    fn detect(operator_namespace: &str, kubeconfig: Option<&Path>) -> Self {
+        let target = KubeTarget::new(kubeconfig);
+        let exists = |namespace: &str| {
+            kubectl::run_kubectl_output(&["get", "namespace", namespace], &target).is_ok()
+        };
+        Self {
+            openshift_monitoring: exists("openshift-monitoring"),
+            operator_installed: exists(operator_namespace),
+        }
+    }
+}
+
+impl MenuTargets {
+    /// Configured values win; the rest follow the detected cluster layout.
+    /// `namespace` replaces the configured operator namespace.
+    fn resolve(settings: &Settings, layout: ClusterLayout, namespace: Option<&str>) -> Self {
+        let InteractiveSettings {
+            monitoring_namespace,
+            prometheus_service,
+            prometheus_operator_namespace,
+        } = settings.interactive.clone();
+
+        // OpenShift's cluster monitoring vs. the instance `deps install prometheus` creates
+        let (default_namespace, default_service) = if layout.openshift_monitoring {
+            ("openshift-monitoring", "prometheus")
+        } else {
+            ("default", "prometheus-operated")
+        };
+
+        let (operator_namespace, kueue_namespace) = match (layout.operator_installed, namespace) {
+            (true, namespace) => {
+                let namespace = namespace
+                    .unwrap_or(&settings.operator.namespace)
+                    .to_string();
+                (Some(namespace.clone()), namespace)
+            }
+            (false, namespace) => (
+                None,
+                namespace.unwrap_or(UPSTREAM_KUEUE_NAMESPACE).to_string(),
+            ),
+        };
+
+        Self {
+            monitoring_namespace: monitoring_namespace
+                .unwrap_or_else(|| default_namespace.to_string()),
+            prometheus_service: prometheus_service.unwrap_or_else(|| default_service.to_string()),
+            prometheus_operator_namespace: prometheus_operator_namespace
+                .unwrap_or_else(|| "default".to_string()),
+            operator_namespace,
+            kueue_namespace,
+        }
+    }
+}
+
+/// State shared by all menu actions
+pub struct MenuContext<'a> {
+    pub kubeconfig: Option<&'a Path>,
+    pub targets: MenuTargets,
+}
+
+impl<'a> MenuContext<'a> {
+    /// Resolve the action targets from settings and the namespaces present on the cluster.
+    /// `namespace` overrides the configured operator namespace.
+    pub fn detect(kubeconfig: Option<&'a Path>, namespace: Option<&str>) -> Result<Self> {
+        let settings = Settings::load()?;
+        let layout = ClusterLayout::detect(
+            namespace.unwrap_or(&settings.operator.namespace),
+            kubeconfig,
+        );
+        Ok(Self {
+            kubeconfig,
+            targets: MenuTargets::resolve(&settings, layout, namespace),
+        })
+    }
+
+    /// `kubectl` command using the menu's kubeconfig
+    fn kubectl(&self) -> Command {
+        KubeTarget::new(self.kubeconfig).kubectl()
+    }
+}
+
+/// An entry in the interactive menu
+pub struct MenuAction {
+    pub label: &'static str,
+    pub handler: fn(&MenuContext) -> Result<()>,
+}
+
+/// Menu entries in display order. "Exit" is appended after the last one.
+fn menu_actions() -> Vec<MenuAction> {
+    vec![
+        MenuAction {
+            label: "Port-forward to Prometheus UI (http://localhost:9090)",
+            handler: port_forward_prometheus,
+        },
+        MenuAction {
+            label: "View Prometheus Operator logs",
+            handler: view_prometheus_operator_logs,
+        },
+        MenuAction {
+            label: "View Prometheus instance logs",
+            handler: view_prometheus_logs,
+        },
+        MenuAction {
+            label: "View Kueue Operator logs",
+            handler: view_kueue_logs,
+        },
+        MenuAction {
+            label: "Tail kueue-controller-manager logs",
+            handler: view_controller_manager_logs,
+        },
+        MenuAction {
+            label: "Show cluster information",
+            handler: show_cluster_info,
+        },
+        MenuAction {
+            label: "Describe failing pods",
+            handler: describe_failing_pods,
+        },
+        MenuAction {
+            label: "Run cleanup",
+            handler: run_cleanup,
+        },
+        MenuAction {
+            label: "kubectl shell (interactive)",
+            handler: kubectl_shell,
+        },
+    ]
+}
+
+/// What a menu selection refers to
+#[derive(Debug, PartialEq, Eq)]
+enum Selection {
+    Action(usize),
+    Exit,
+}
+
+/// Parse a 1-based menu choice; `action_count + 1` is Exit
+fn parse_selection(input: &str, action_count: usize) -> Option<Selection> {
+    match input.trim().parse::<usize>().ok()? {
+        0 => None,
+        n if n <= action_count => Some(Selection::Action(n - 1)),
+        n if n == action_count + 1 => Some(Selection::Exit),
+        _ => None,
+    }
+}
+
+/// Show interactive menu for cluster operations.
+/// Without `namespace`, asks once for the kueue namespace the actions use.
+pub fn show_menu(kubeconfig: Option<&Path>, namespace: Option<&str>) -> Result<()> {
     crate::log_info!("");
     crate::log_info!("==========================================");
     crate::log_info!("Interactive Menu");
     crate::log_info!("==========================================");
     crate::log_info!("");
 
+    let namespace = match namespace {
+        Some(namespace) => Some(namespace.to_string()),
+        None => {
+            let configured = Settings::load()?.operator.namespace;
+            let entered = crate::utils::input_with_default("Kueue namespace", &configured)?;
+            // Keep the configured namespace's upstream fallback unless it was changed
+            (entered != configured).then_some(entered)
+        }
+    };
+    let context = MenuContext::detect(kubeconfig, namespace.as_deref())?;
+    match &context.targets.operator_namespace {
+        Some(namespace) => crate::log_info!("Detected kueue-operator in {}", namespace),
+        None => crate::log_info!(
+            "kueue-operator not found; using upstream kueue in {}",
+            context.targets.kueue_namespace
+        ),
+    }
+
+    let actions = menu_actions();
+    let exit_choice = actions.len() + 1;
+
     loop {
         println!();
         println!("Available actions:");
-        println!("  1) Port-forward to Prometheus UI (http://localhost:9090)");
-        println!("  2) View Prometheus Operator logs");
-        println!("  3) View Prometheus instance logs");
-        println!("  4) View Kueue Operator logs");
-        println!("  5) Show cluster information");
-        println!("  6) kubectl shell (interactive)");
-        println!("  7) Exit");
+        for (i, action) in actions.iter().enumerate() {
+            println!("  {}) {}", i + 1, action.label);
+        }
+        println!("  {}) Exit", exit_choice);
         println!();
-        print!("Select an action [1-7]: ");
+        print!("Select an action [1-{}]: ", exit_choice);
         io::stdout().flush()?;
 
         let mut input = String::new();
         io::stdin().read_line(&mut input)?;
 
-        match input.trim() {
-            "1" => port_forward_prometheus(kubeconfig)?,
-            "2" => view_prometheus_operator_logs(kubeconfig)?,
-            "3" => view_prometheus_logs(kubeconfig)?,
-            "4" => view_kueue_logs(kubeconfig)?,
-            "5" => show_cluster_info(kubeconfig)?,
-            "6" => kubectl_shell(kubeconfig)?,
-            "7" => {
+        match parse_selection(&input, actions.len()) {
+            Some(Selection::Action(index)) => {
+                let action = &actions[index];
+                if let Err(e) = (action.handler)(&context) {
+                    crate::log_error!("{} failed: {:#}", action.label, e);
+                }
+            }
+            Some(Selection::Exit) => {
                 crate::log_info!("Exiting...");
                 break;
             }
-            _ => {
-                crate::log_error!("Invalid selection. Please choose 1-7.");
+            None => {
+                crate::log_error!("Invalid selection. Please choose 1-{}.", exit_choice);
             }
         }
     }
@@ -52,22 +249,71 @@ pub fn show_menu(kubeconfig: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-/// Port-forward to Prometheus UI
-fn port_forward_prometheus(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Starting port-forward to Prometheus UI...");
-    crate::log_info!("Access Prometheus at: http://localhost:9090");
-    crate::log_info!("Press Ctrl+C to stop port-forwarding and return to menu");
+/// Component whose logs `kueue-dev logs` follows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogTarget {
+    Operator,
+    ControllerManager,
+    Prometheus,
+    PrometheusOperator,
+}
+
+impl FromStr for LogTarget {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "operator" => Ok(LogTarget::Operator),
+            "controller-manager" => Ok(LogTarget::ControllerManager),
+            "prometheus" => Ok(LogTarget::Prometheus),
+            "prometheus-operator" => Ok(LogTarget::PrometheusOperator),
+            _ => Err(anyhow!(
+                "Invalid log target: {}. Must be 'operator', 'controller-manager', 'prometheus' or 'prometheus-operator'",
+                s
+            )),
+        }
+    }
+}
 
-    let mut cmd = Command::new("kubectl");
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
+impl std::fmt::Display for LogTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogTarget::Operator => write!(f, "operator"),
+            LogTarget::ControllerManager => write!(f, "controller-manager"),
+            LogTarget::Prometheus => write!(f, "prometheus"),
+            LogTarget::PrometheusOperator => write!(f, "prometheus-operator"),
+        }
+    }
+}
+
+/// Follow the logs of `target` until Ctrl+C
+pub fn logs(ctx: &MenuContext, target: LogTarget) -> Result<()> {
+    match target {
+        LogTarget::Operator => view_kueue_logs(ctx),
+        LogTarget::ControllerManager => view_controller_manager_logs(ctx),
+        LogTarget::Prometheus => view_prometheus_logs(ctx),
+        LogTarget::PrometheusOperator => view_prometheus_operator_logs(ctx),
     }
+}
+
+/// Port-forward to Prometheus UI
+pub fn port_forward_prometheus(ctx: &MenuContext) -> Result<()> {
+    let targets = &ctx.targets;
+    crate::log_info!(
+        "Starting port-forward to svc/{} in {}...",
+        targets.prometheus_service,
+        targets.monitoring_namespace
+    );
+    crate::log_info!("Access Prometheus at: http://localhost:9090");
+    crate::log_info!("Press Ctrl+C to stop port-forwarding");
 
+    let service = format!("svc/{}", targets.prometheus_service);
+    let mut cmd = ctx.kubectl();
     cmd.args([
         "port-forward",
         "-n",
-        "openshift-monitoring",
-        "svc/prometheus",
+        &targets.monitoring_namespace,
+        &service,
         "9090:9090",
     ]);
 
@@ -76,149 +322,205 @@ fn port_forward_prometheus(kubeconfig: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-/// View Prometheus Operator logs
-fn view_prometheus_operator_logs(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Showing Prometheus Operator logs...");
-    crate::log_info!("Press Ctrl+C to stop and return to menu");
-
-    let mut cmd = Command::new("kubectl");
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
+/// Stream logs until Ctrl+C
+fn follow_logs(ctx: &MenuContext, namespace: &str, target: &[&str]) -> Result<()> {
+    crate::log_info!("Press Ctrl+C to stop");
 
-    cmd.args([
-        "logs",
-        "-n",
-        "default",
-        "-l",
-        "app.kubernetes.io/name=prometheus-operator",
-        "-f",
-        "--tail=100",
-    ]);
+    let mut cmd = ctx.kubectl();
+    cmd.args(["logs", "-n", namespace])
+        .args(target)
+        .args(["-f", "--tail=100"]);
 
     let _ = cmd.status(); // Ignore error from Ctrl+C
 
     Ok(())
 }
 
+/// View Prometheus Operator logs
+fn view_prometheus_operator_logs(ctx: &MenuContext) -> Result<()> {
+    crate::log_info!("Showing Prometheus Operator logs...");
+    follow_logs(
+        ctx,
+        &ctx.targets.prometheus_operator_namespace,
+        &["-l", "app.kubernetes.io/name=prometheus-operator"],
+    )
+}
+
 /// View Prometheus instance logs
-fn view_prometheus_logs(kubeconfig: Option<&Path>) -> Result<()> {
+fn view_prometheus_logs(ctx: &MenuContext) -> Result<()> {
     crate::log_info!("Showing Prometheus instance logs...");
-    crate::log_info!("Press Ctrl+C to stop and return to menu");
-
-    let mut cmd = Command::new("kubectl");
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
+    follow_logs(
+        ctx,
+        &ctx.targets.monitoring_namespace,
+        &["-l", PROMETHEUS_SELECTOR],
+    )
+}
 
-    cmd.args([
-        "logs",
-        "-n",
-        "openshift-monitoring",
-        "-l",
-        "app.kubernetes.io/name=prometheus",
-        "-f",
-        "--tail=100",
-    ]);
+/// View Kueue Operator logs
+fn view_kueue_logs(ctx: &MenuContext) -> Result<()> {
+    let Some(namespace) = &ctx.targets.operator_namespace else {
+        crate::log_warn!(
+            "kueue-operator is not installed; showing kueue-controller-manager logs instead"
+        );
+        return view_controller_manager_logs(ctx);
+    };
 
-    let _ = cmd.status(); // Ignore error from Ctrl+C
+    crate::log_info!("Showing Kueue Operator logs...");
+    follow_logs(ctx, namespace, &["-l", "name=openshift-kueue-operator"])
+}
 
-    Ok(())
+/// Tail the kueue-controller-manager (operand) logs
+fn view_controller_manager_logs(ctx: &MenuContext) -> Result<()> {
+    let namespace = &ctx.targets.kueue_namespace;
+    crate::log_info!("Showing kueue-controller-manager logs in {}...", namespace);
+    follow_logs(ctx, namespace, &["deployment/kueue-controller-manager"])
 }
 
-/// View Kueue Operator logs
-fn view_kueue_logs(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Showing Kueue Operator logs...");
-    crate::log_info!("Press Ctrl+C to stop and return to menu");
+/// Describe every pod that is not running or completed cleanly
+fn describe_failing_pods(ctx: &MenuContext) -> Result<()> {
+    let target = KubeTarget::new(ctx.kubeconfig);
+    let output =
+        kubectl::run_kubectl_output(&["get", "pods", "--all-namespaces", "-o", "json"], &target)?;
+    let pods = failing_pods(&output);
 
-    let mut cmd = Command::new("kubectl");
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
+    if pods.is_empty() {
+        crate::log_info!("No failing pods found");
+        return Ok(());
     }
 
-    cmd.args([
-        "logs",
-        "-n",
-        "openshift-kueue-operator",
-        "-l",
-        "name=openshift-kueue-operator",
-        "-f",
-        "--tail=100",
-    ]);
-
-    let _ = cmd.status(); // Ignore error from Ctrl+C
+    for (namespace, name) in &pods {
+        crate::log_info!("Pod {}/{}:", namespace, name);
+        kubectl::run_kubectl(&["describe", "pod", name, "-n", namespace], &target).ok();
+        println!();
+    }
 
     Ok(())
 }
 
+/// `(namespace, name)` of pods that are not Succeeded and are either not
+/// Running or have a container stuck waiting (e.g. CrashLoopBackOff)
+fn failing_pods(json: &str) -> Vec<(String, String)> {
+    let Ok(list) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+    let Some(items) = list["items"].as_array() else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .filter(|pod| {
+            let phase = pod["status"]["phase"].as_str().unwrap_or("Unknown");
+            let waiting = pod["status"]["containerStatuses"]
+                .as_array()
+                .is_some_and(|statuses| statuses.iter().any(|c| c["state"]["waiting"].is_object()));
+            phase != "Succeeded" && (phase != "Running" || waiting)
+        })
+        .map(|pod| {
+            let metadata = &pod["metadata"];
+            (
+                metadata["namespace"]
+                    .as_str()
+                    .unwrap_or("default")
+                    .to_string(),
+                metadata["name"].as_str().unwrap_or("unknown").to_string(),
+            )
+        })
+        .collect()
+}
+
+/// Clean up test resources, asking for confirmation first
+fn run_cleanup(ctx: &MenuContext) -> Result<()> {
+    use crate::commands::cleanup::{CleanupScope, cleanup};
+    cleanup(ctx.kubeconfig, CleanupScope::Tests, false, &[], &[], false)
+}
+
 /// Show cluster information
-fn show_cluster_info(kubeconfig: Option<&Path>) -> Result<()> {
+pub fn show_cluster_info(ctx: &MenuContext) -> Result<()> {
+    let target = KubeTarget::new(ctx.kubeconfig);
+    let targets = &ctx.targets;
     crate::log_info!("Cluster Information:");
     println!();
 
     // Show Prometheus Operator deployment
-    crate::log_info!("Prometheus Operator Deployment (default namespace):");
+    crate::log_info!(
+        "Prometheus Operator Deployment ({} namespace):",
+        targets.prometheus_operator_namespace
+    );
     kubectl::run_kubectl(
-        &["get", "deployment", "-n", "default", "prometheus-operator"],
-        kubeconfig,
+        &[
+            "get",
+            "deployment",
+            "-n",
+            &targets.prometheus_operator_namespace,
+            "prometheus-operator",
+        ],
+        &target,
     )
     .ok();
     println!();
 
     // Show Prometheus pods
-    crate::log_info!("Prometheus Pods (openshift-monitoring namespace):");
+    crate::log_info!(
+        "Prometheus Pods ({} namespace):",
+        targets.monitoring_namespace
+    );
     kubectl::run_kubectl(
         &[
             "get",
             "pods",
             "-n",
-            "openshift-monitoring",
+            &targets.monitoring_namespace,
             "-l",
-            "app.kubernetes.io/name=prometheus",
+            PROMETHEUS_SELECTOR,
         ],
-        kubeconfig,
+        &target,
     )
     .ok();
     println!();
 
     // Show Prometheus service
     crate::log_info!("Prometheus Service:");
-    kubectl::run_kubectl(
-        &["get", "svc", "-n", "openshift-monitoring", "prometheus"],
-        kubeconfig,
-    )
-    .ok();
-    println!();
-
-    // Show Kueue Operator deployment
-    crate::log_info!("Kueue Operator Deployment:");
     kubectl::run_kubectl(
         &[
             "get",
-            "deployment",
+            "svc",
             "-n",
-            "openshift-kueue-operator",
-            "openshift-kueue-operator",
+            &targets.monitoring_namespace,
+            &targets.prometheus_service,
         ],
-        kubeconfig,
+        &target,
     )
     .ok();
     println!();
 
-    // Show Kueue Operator pods
-    crate::log_info!("Kueue Operator Pods:");
-    kubectl::run_kubectl(
-        &["get", "pods", "-n", "openshift-kueue-operator"],
-        kubeconfig,
-    )
-    .ok();
+    // Show Kueue Operator deployment
+    if let Some(namespace) = &targets.operator_namespace {
+        crate::log_info!("Kueue Operator Deployment:");
+        kubectl::run_kubectl(
+            &[
+                "get",
+                "deployment",
+                "-n",
+                namespace,
+                "openshift-kueue-operator",
+            ],
+            &target,
+        )
+        .ok();
+        println!();
+    }
+
+    // Show kueue pods (operator and operand share the namespace in operator installs)
+    crate::log_info!("Kueue Pods ({} namespace):", targets.kueue_namespace);
+    kubectl::run_kubectl(&["get", "pods", "-n", &targets.kueue_namespace], &target).ok();
     println!();
 
     Ok(())
 }
 
 /// Interactive kubectl shell
-fn kubectl_shell(kubeconfig: Option<&Path>) -> Result<()> {
+fn kubectl_shell(ctx: &MenuContext) -> Result<()> {
     crate::log_info!("Starting kubectl shell...");
     crate::log_info!("Type 'exit' to return to menu");
     println!();
@@ -243,11 +545,7 @@ fn kubectl_shell(kubeconfig: Option<&Path>) -> Result<()> {
             continue;
         }
 
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
-
+        let mut cmd = ctx.kubectl();
         cmd.args(&args);
         let _ = cmd.status(); // Ignore errors
     }
@@ -257,8 +555,131 @@ fn kubectl_shell(kubeconfig: Option<&Path>) -> Result<()> {
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_interactive_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("1\n", 3), Some(Selection::Action(0)));
+        assert_eq!(parse_selection(" 3 ", 3), Some(Selection::Action(2)));
+        assert_eq!(parse_selection("4", 3), Some(Selection::Exit));
+        assert_eq!(parse_selection("0", 3), None);
+        assert_eq!(parse_selection("5", 3), None);
+        assert_eq!(parse_selection("x", 3), None);
+    }
+
+    #[test]
+    fn test_menu_targets_follow_cluster_layout() {
+        let settings = Settings::default();
+
+        let openshift = MenuTargets::resolve(
+            &settings,
+            ClusterLayout {
+                openshift_monitoring: true,
+                operator_installed: true,
+            },
+            None,
+        );
+        assert_eq!(openshift.monitoring_namespace, "openshift-monitoring");
+        assert_eq!(openshift.prometheus_service, "prometheus");
+        assert_eq!(
+            openshift.operator_namespace.as_deref(),
+            Some("openshift-kueue-operator")
+        );
+        assert_eq!(openshift.kueue_namespace, "openshift-kueue-operator");
+
+        let upstream = MenuTargets::resolve(&settings, ClusterLayout::default(), None);
+        assert_eq!(upstream.monitoring_namespace, "default");
+        assert_eq!(upstream.prometheus_service, "prometheus-operated");
+        assert_eq!(upstream.operator_namespace, None);
+        assert_eq!(upstream.kueue_namespace, "kueue-system");
+    }
+
+    #[test]
+    fn test_menu_targets_use_operator_namespace() {
+        let mut settings = Settings::default();
+        settings.operator.namespace = "my-operator".to_string();
+        settings.kueue.namespace = "my-kueue".to_string();
+        let installed = ClusterLayout {
+            openshift_monitoring: false,
+            operator_installed: true,
+        };
+
+        let targets = MenuTargets::resolve(&settings, installed, None);
+        assert_eq!(targets.operator_namespace.as_deref(), Some("my-operator"));
+        assert_eq!(targets.kueue_namespace, "my-operator");
+    }
+
+    #[test]
+    fn test_menu_targets_namespace_override() {
+        let settings = Settings::default();
+        let installed = ClusterLayout {
+            openshift_monitoring: false,
+            operator_installed: true,
+        };
+
+        let operator = MenuTargets::resolve(&settings, installed, Some("my-operator"));
+        assert_eq!(operator.operator_namespace.as_deref(), Some("my-operator"));
+        assert_eq!(operator.kueue_namespace, "my-operator");
+
+        let upstream = MenuTargets::resolve(&settings, ClusterLayout::default(), Some("my-kueue"));
+        assert_eq!(upstream.operator_namespace, None);
+        assert_eq!(upstream.kueue_namespace, "my-kueue");
+    }
+
+    #[test]
+    fn test_menu_targets_prefer_settings() {
+        let mut settings = Settings::default();
+        settings.interactive.monitoring_namespace = Some("monitoring".to_string());
+        settings.interactive.prometheus_service = Some("prometheus-k8s".to_string());
+        settings.interactive.prometheus_operator_namespace = Some("monitoring".to_string());
+
+        let targets = MenuTargets::resolve(&settings, ClusterLayout::default(), None);
+        assert_eq!(targets.monitoring_namespace, "monitoring");
+        assert_eq!(targets.prometheus_service, "prometheus-k8s");
+        assert_eq!(targets.prometheus_operator_namespace, "monitoring");
+    }
+
+    #[test]
+    fn test_log_target_from_str() {
+        assert_eq!(
+            "operator".parse::<LogTarget>().unwrap(),
+            LogTarget::Operator
+        );
+        assert_eq!(
+            "Controller-Manager".parse::<LogTarget>().unwrap(),
+            LogTarget::ControllerManager
+        );
+        assert_eq!(
+            "prometheus-operator".parse::<LogTarget>().unwrap(),
+            LogTarget::PrometheusOperator
+        );
+        assert!("kueue".parse::<LogTarget>().is_err());
+        assert_eq!(LogTarget::Prometheus.to_string(), "prometheus");
+    }
+
+    #[test]
+    fn test_failing_pods() {
+        let json = r#"{"items":[
+            {"metadata":{"namespace":"a","name":"ok"},"status":{"phase":"Running",
+                "containerStatuses":[{"state":{"running":{}}}]}},
+            {"metadata":{"namespace":"a","name":"done"},"status":{"phase":"Succeeded"}},
+            {"metadata":{"namespace":"b","name":"crash"},"status":{"phase":"Running",
+                "containerStatuses":[{"state":{"waiting":{"reason":"CrashLoopBackOff"}}}]}},
+            {"metadata":{"namespace":"c","name":"pending"},"status":{"phase":"Pending"}}
+        ]}"#;
+
+        assert_eq!(
+            failing_pods(json),
+            vec![
+                ("b".to_string(), "crash".to_string()),
+                ("c".to_string(), "pending".to_string()),
+            ]
+        );
+        assert!(failing_pods("not json").is_empty());
+    }
 }
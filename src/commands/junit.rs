@@ -0,0 +1,178 @@
+//! Consolidated JUnit report covering deploy phases and ginkgo specs
+
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// One deploy phase, reported as a JUnit testcase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhaseResult {
+    pub name: String,
+    pub duration: Duration,
+    /// Error message when the phase failed
+    pub failure: Option<String>,
+}
+
+/// Deploy phases recorded as they run, plus the `<testsuite>` elements of
+/// ginkgo's own JUnit report
+#[derive(Debug, Clone, Default)]
+pub struct JUnitReport {
+    pub phases: Vec<PhaseResult>,
+    ginkgo_suites: Option<String>,
+}
+
+impl JUnitReport {
+    /// Run `step` as the phase `name`, recording its outcome and duration
+    pub fn phase<T>(&mut self, name: &str, step: impl FnOnce() -> Result<T>) -> Result<T> {
+        let started = Instant::now();
+        let result = step();
+        self.phases.push(PhaseResult {
+            name: name.to_string(),
+            duration: started.elapsed(),
+            failure: result.as_ref().err().map(|e| format!("{:#}", e)),
+        });
+        result
+    }
+
+    /// Merge in the suites of ginkgo's `--junit-report`, replacing any merged before.
+    /// A missing or unreadable report is logged and leaves only the deploy phases.
+    pub fn merge_ginkgo_report(&mut self, path: &Path) {
+        self.ginkgo_suites = match std::fs::read_to_string(path) {
+            Ok(xml) => testsuite_elements(&xml).map(str::to_string),
+            Err(e) => {
+                crate::log_warn!("No ginkgo JUnit report at {}: {}", path.display(), e);
+                None
+            }
+        };
+    }
+
+    /// Render the report as a JUnit XML document
+    pub fn to_xml(&self) -> String {
+        let failures = self.phases.iter().filter(|p| p.failure.is_some()).count();
+        let time: Duration = self.phases.iter().map(|p| p.duration).sum();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<testsuites name=\"kueue-dev\">\n");
+        xml.push_str(&format!(
+            "  <testsuite name=\"Deploy\" tests=\"{}\" failures=\"{}\" errors=\"0\" time=\"{:.3}\">\n",
+            self.phases.len(),
+            failures,
+            time.as_secs_f64()
+        ));
+        for phase in &self.phases {
+            let attrs = format!(
+                "name=\"{}\" classname=\"deploy\" time=\"{:.3}\"",
+                escape(&phase.name),
+                phase.duration.as_secs_f64()
+            );
+            match &phase.failure {
+                Some(message) => xml.push_str(&format!(
+                    "    <testcase {}>\n      <failure message=\"{}\" type=\"failed\">{}</failure>\n    </testcase>\n",
+                    attrs,
+                    escape(message.lines().next().unwrap_or_default()),
+                    escape(message)
+                )),
+                None => xml.push_str(&format!("    <testcase {}></testcase>\n", attrs)),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+
+        if let Some(suites) = &self.ginkgo_suites {
+            xml.push_str("  ");
+            xml.push_str(suites);
+            xml.push('\n');
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    /// Write the report, creating the parent directory if needed
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        std::fs::write(path, self.to_xml())
+            .with_context(|| format!("Failed to write JUnit report: {}", path.display()))
+    }
+}
+
+/// The `<testsuite>` elements of a JUnit document, from the first opening tag
+/// through the last closing tag
+fn testsuite_elements(xml: &str) -> Option<&str> {
+    let start = ["<testsuite ", "<testsuite>"]
+        .iter()
+        .filter_map(|tag| xml.find(tag))
+        .min()?;
+    let end = xml.rfind("</testsuite>")? + "</testsuite>".len();
+    (start < end).then(|| &xml[start..end])
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+
+    const GINKGO_JUNIT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites tests="1" disabled="0" errors="0" failures="0" time="2.5">
+  <testsuite name="E2E Suite" package="/src/test/e2e" tests="1" failures="0" time="2.5">
+    <testcase name="[It] Kueue should admit the job" classname="E2E Suite" status="passed" time="1.2"></testcase>
+  </testsuite>
+</testsuites>"#;
+
+    #[test]
+    fn test_phase_records_outcome() {
+        let mut report = JUnitReport::default();
+        assert_eq!(report.phase("Install cert-manager", || Ok(1)).unwrap(), 1);
+        assert!(
+            report
+                .phase("Install operator", || -> Result<()> {
+                    Err(anyhow!("timed out <waiting>").context("Operator not available"))
+                })
+                .is_err()
+        );
+
+        assert_eq!(report.phases[0].failure, None);
+        assert_eq!(
+            report.phases[1].failure.as_deref(),
+            Some("Operator not available: timed out <waiting>")
+        );
+
+        let xml = report.to_xml();
+        assert!(xml.contains(r#"<testsuite name="Deploy" tests="2" failures="1""#));
+        assert!(xml.contains(r#"<testcase name="Install cert-manager" classname="deploy""#));
+        assert!(xml.contains(
+            r#"<failure message="Operator not available: timed out &lt;waiting&gt;" type="failed">"#
+        ));
+    }
+
+    #[test]
+    fn test_merge_ginkgo_suites() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("junit.xml");
+        std::fs::write(&path, GINKGO_JUNIT).unwrap();
+
+        let mut report = JUnitReport::default();
+        report.phase("Install CRDs", || Ok(())).unwrap();
+        report.merge_ginkgo_report(&path);
+
+        let xml = report.to_xml();
+        assert_eq!(xml.matches("<testsuites").count(), 1);
+        assert!(xml.contains(r#"<testsuite name="E2E Suite""#));
+        assert!(xml.contains("[It] Kueue should admit the job"));
+        assert!(xml.trim_end().ends_with("</testsuite>\n</testsuites>"));
+
+        report.merge_ginkgo_report(&dir.path().join("missing.xml"));
+        assert!(!report.to_xml().contains("E2E Suite"));
+
+        assert_eq!(testsuite_elements("<testsuites></testsuites>"), None);
+    }
+}
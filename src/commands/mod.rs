@@ -3,7 +3,16 @@
 pub mod build;
 pub mod cleanup;
 pub mod cluster;
+pub mod completion;
 pub mod deploy;
+pub mod deploy_summary;
+pub mod deployment_info;
+pub mod deps;
 pub mod interactive;
+pub mod junit;
+pub mod netpol;
 pub mod openshift;
+pub mod prune;
+pub mod registry;
 pub mod test;
+pub mod test_summary;
@@ -0,0 +1,314 @@
+//! Back up and restore NetworkPolicies
+
+use anyhow::{Context, Result, bail};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+
+/// Server-populated metadata that must not be set when re-creating an object
+const SERVER_METADATA_FIELDS: &[&str] = &[
+    "uid",
+    "resourceVersion",
+    "creationTimestamp",
+    "generation",
+    "managedFields",
+    "selfLink",
+];
+
+/// Save every NetworkPolicy outside `excluded_namespaces` to
+/// `<dir>/<namespace>_<name>.yaml`, matching what [`delete`] removes.
+/// `dir` must be empty or not exist yet, so a later [`restore`] only applies
+/// this backup. Returns the number of policies saved.
+pub fn backup(
+    dir: &Path,
+    kubeconfig: Option<&Path>,
+    excluded_namespaces: &[String],
+) -> Result<usize> {
+    let target = KubeTarget::new(kubeconfig);
+    crate::log_info!("Backing up NetworkPolicies to {}...", dir.display());
+
+    ensure_empty_dir(dir)?;
+
+    let output = kubectl::run_kubectl_output(
+        &["get", "networkpolicies", "--all-namespaces", "-o", "json"],
+        &target,
+    )?;
+    let list: Value =
+        serde_json::from_str(&output).context("Failed to parse NetworkPolicy list")?;
+
+    let items = list["items"].as_array().cloned().unwrap_or_default();
+    let mut count = 0;
+    for item in select_backup(items, excluded_namespaces) {
+        let policy = sanitize_policy(item);
+        let file = dir.join(backup_file_name(&policy)?);
+        let yaml = kubectl::render_yaml(&policy.to_string(), &target)?;
+        std::fs::write(&file, yaml)
+            .with_context(|| format!("Failed to write {}", file.display()))?;
+        crate::log_info!("Saved {}", file.display());
+        count += 1;
+    }
+
+    crate::log_info!("Backed up {} NetworkPolicies", count);
+    Ok(count)
+}
+
+/// Create `dir`, refusing one that already has files in it
+fn ensure_empty_dir(dir: &Path) -> Result<()> {
+    std::fs::create_dir_all(dir)
+        .with_context(|| format!("Failed to create backup directory: {}", dir.display()))?;
+    let mut entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read backup directory: {}", dir.display()))?;
+    if entries.next().is_some() {
+        bail!(
+            "Backup directory {} is not empty; choose a new directory or remove its contents",
+            dir.display()
+        );
+    }
+    Ok(())
+}
+
+/// Re-apply NetworkPolicies saved by [`backup`]. Returns the number applied.
+pub fn restore(dir: &Path, kubeconfig: Option<&Path>) -> Result<usize> {
+    crate::log_info!("Restoring NetworkPolicies from {}...", dir.display());
+
+    let files = backup_files(dir)?;
+    for file in &files {
+        let file_arg = file.to_string_lossy();
+        kubectl::run_kubectl(&["apply", "-f", &file_arg], &KubeTarget::new(kubeconfig))
+            .with_context(|| format!("Failed to restore {}", file.display()))?;
+    }
+
+    crate::log_info!("Restored {} NetworkPolicies", files.len());
+    Ok(files.len())
+}
+
+/// Delete the NetworkPolicies outside `excluded_namespaces`.
+/// Returns the number of policies deleted.
+pub fn delete(kubeconfig: Option<&Path>, excluded_namespaces: &[String]) -> Result<usize> {
+    let target = KubeTarget::new(kubeconfig);
+    let output = match kubectl::run_kubectl_output(
+        &[
+            "get",
+            "networkpolicies",
+            "--all-namespaces",
+            "-o",
+            r#"jsonpath={range .items[*]}{.metadata.namespace}{"\t"}{.metadata.name}{"\n"}{end}"#,
+        ],
+        &target,
+    ) {
+        Ok(output) => output,
+        Err(e)
+            if e.to_string()
+                .contains("the server doesn't have a resource type") =>
+        {
+            String::new()
+        }
+        Err(e) => return Err(e),
+    };
+
+    let policies = select_deletable(&output, excluded_namespaces);
+    for (namespace, name) in &policies {
+        kubectl::run_kubectl(&["delete", "networkpolicy", name, "-n", namespace], &target)
+            .with_context(|| format!("Failed to delete NetworkPolicy {}/{}", namespace, name))?;
+        crate::log_info!("Deleted NetworkPolicy {}/{}", namespace, name);
+    }
+
+    Ok(policies.len())
+}
+
+/// Parse `<namespace>\t<name>` lines, dropping policies in `excluded_namespaces`
+fn select_deletable(output: &str, excluded_namespaces: &[String]) -> Vec<(String, String)> {
+    output
+        .lines()
+        .filter_map(|line| line.trim().split_once('\t'))
+        .filter(|(namespace, _)| !excluded_namespaces.iter().any(|ex| ex == namespace))
+        .map(|(namespace, name)| (namespace.to_string(), name.to_string()))
+        .collect()
+}
+
+/// Policies from a NetworkPolicy list outside `excluded_namespaces`
+fn select_backup(items: Vec<Value>, excluded_namespaces: &[String]) -> Vec<Value> {
+    items
+        .into_iter()
+        .filter(|item| {
+            let namespace = policy_namespace(item);
+            !excluded_namespaces.iter().any(|ex| ex == namespace)
+        })
+        .collect()
+}
+
+/// Drop server-populated fields so the policy can be re-created after deletion
+fn sanitize_policy(mut policy: Value) -> Value {
+    if let Some(obj) = policy.as_object_mut() {
+        obj.remove("status");
+        obj.entry("apiVersion")
+            .or_insert_with(|| Value::from("networking.k8s.io/v1"));
+        obj.entry("kind")
+            .or_insert_with(|| Value::from("NetworkPolicy"));
+    }
+
+    if let Some(metadata) = policy["metadata"].as_object_mut() {
+        for field in SERVER_METADATA_FIELDS {
+            metadata.remove(*field);
+        }
+
+        if let Some(annotations) = metadata
+            .get_mut("annotations")
+            .and_then(Value::as_object_mut)
+        {
+            annotations.remove("kubectl.kubernetes.io/last-applied-configuration");
+            if annotations.is_empty() {
+                metadata.remove("annotations");
+            }
+        }
+    }
+
+    policy
+}
+
+/// `<namespace>_<name>.yaml`; `_` cannot appear in Kubernetes names
+fn backup_file_name(policy: &Value) -> Result<String> {
+    let metadata = &policy["metadata"];
+    let name = metadata["name"]
+        .as_str()
+        .context("NetworkPolicy without metadata.name")?;
+    Ok(format!("{}_{}.yaml", policy_namespace(policy), name))
+}
+
+fn policy_namespace(policy: &Value) -> &str {
+    policy["metadata"]["namespace"]
+        .as_str()
+        .unwrap_or("default")
+}
+
+/// Backup files in `dir`, sorted for a stable restore order
+fn backup_files(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read backup directory: {}", dir.display()))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "yaml"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sanitize_policy() {
+        let policy = json!({
+            "apiVersion": "networking.k8s.io/v1",
+            "kind": "NetworkPolicy",
+            "metadata": {
+                "name": "allow-webhook",
+                "namespace": "openshift-kueue-operator",
+                "uid": "1234",
+                "resourceVersion": "42",
+                "creationTimestamp": "2025-01-01T00:00:00Z",
+                "generation": 1,
+                "managedFields": [],
+                "labels": {"app": "kueue"},
+                "annotations": {
+                    "kubectl.kubernetes.io/last-applied-configuration": "{}"
+                }
+            },
+            "spec": {"podSelector": {}},
+            "status": {}
+        });
+
+        let sanitized = sanitize_policy(policy);
+        assert_eq!(
+            sanitized,
+            json!({
+                "apiVersion": "networking.k8s.io/v1",
+                "kind": "NetworkPolicy",
+                "metadata": {
+                    "name": "allow-webhook",
+                    "namespace": "openshift-kueue-operator",
+                    "labels": {"app": "kueue"}
+                },
+                "spec": {"podSelector": {}}
+            })
+        );
+    }
+
+    #[test]
+    fn test_select_deletable_skips_excluded_namespaces() {
+        let output = "kube-system\tallow-dns\n\
+                      e2e-abc\tdeny-all\n\
+                      openshift-kueue-operator\tallow-webhook\n\
+                      team-a\tdeny-all\n";
+        let excluded = vec![
+            "kube-system".to_string(),
+            "openshift-kueue-operator".to_string(),
+        ];
+
+        assert_eq!(
+            select_deletable(output, &excluded),
+            vec![
+                ("e2e-abc".to_string(), "deny-all".to_string()),
+                ("team-a".to_string(), "deny-all".to_string()),
+            ]
+        );
+        assert_eq!(select_deletable(output, &[]).len(), 4);
+        assert!(select_deletable("", &excluded).is_empty());
+    }
+
+    #[test]
+    fn test_select_backup_skips_excluded_namespaces() {
+        let items = vec![
+            json!({"metadata": {"name": "allow-dns", "namespace": "kube-system"}}),
+            json!({"metadata": {"name": "deny-all", "namespace": "team-a"}}),
+            json!({"metadata": {"name": "allow-webhook", "namespace": "openshift-kueue-operator"}}),
+        ];
+        let excluded = vec![
+            "kube-system".to_string(),
+            "openshift-kueue-operator".to_string(),
+        ];
+
+        let selected = select_backup(items.clone(), &excluded);
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0]["metadata"]["namespace"], "team-a");
+        assert_eq!(select_backup(items, &[]).len(), 3);
+    }
+
+    #[test]
+    fn test_backup_file_name() {
+        let policy = json!({"metadata": {"name": "deny-all", "namespace": "team-a"}});
+        assert_eq!(backup_file_name(&policy).unwrap(), "team-a_deny-all.yaml");
+        assert!(backup_file_name(&json!({"metadata": {}})).is_err());
+    }
+
+    #[test]
+    fn test_ensure_empty_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let backup_dir = dir.path().join("netpol");
+        assert!(ensure_empty_dir(&backup_dir).is_ok());
+        assert!(backup_dir.is_dir());
+        assert!(ensure_empty_dir(&backup_dir).is_ok());
+
+        std::fs::write(backup_dir.join("old_policy.yaml"), "").unwrap();
+        let err = ensure_empty_dir(&backup_dir).unwrap_err();
+        assert!(err.to_string().contains("is not empty"));
+    }
+
+    #[test]
+    fn test_backup_files_only_yaml_sorted() {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["b_p.yaml", "a_p.yaml", "notes.txt"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+
+        let files = backup_files(dir.path()).unwrap();
+        let names: Vec<_> = files
+            .iter()
+            .map(|f| f.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        assert_eq!(names, vec!["a_p.yaml", "b_p.yaml"]);
+    }
+}
@@ -3,19 +3,22 @@
 use anyhow::{Context, Result};
 use std::path::PathBuf;
 
+use crate::commands::deploy_summary::DeploySummary;
+use crate::commands::deployment_info::DeploymentInfo;
 use crate::config::images::ImageConfig;
+use crate::config::settings::Settings;
 use crate::install::{cert_manager, jobset, leaderworkerset, operator};
-
-const CERT_MANAGER_VERSION: &str = "v1.13.3";
-const JOBSET_VERSION: &str = "v0.10.1";
-const LEADERWORKERSET_VERSION: &str = "v0.7.0";
+use crate::k8s::target::KubeTarget;
+use crate::k8s::{kubectl, version};
+use crate::utils::preflight::CheckResult;
 
 /// Verify OpenShift cluster connection
 pub fn verify_connection() -> Result<()> {
     crate::log_info!("Verifying OpenShift cluster connection...");
 
     // Check if logged in
-    let output = std::process::Command::new("oc")
+    let output = KubeTarget::new(None)
+        .command("oc")
         .args(["whoami"])
         .output()
         .context("Failed to run 'oc whoami'. Is oc installed and are you logged in?")?;
@@ -28,7 +31,8 @@ pub fn verify_connection() -> Result<()> {
 
     let current_user = String::from_utf8(output.stdout)?.trim().to_string();
 
-    let output = std::process::Command::new("oc")
+    let output = KubeTarget::new(None)
+        .command("oc")
         .args(["whoami", "--show-server"])
         .output()?;
 
@@ -38,7 +42,8 @@ pub fn verify_connection() -> Result<()> {
     crate::log_info!("Cluster URL: {}", cluster_url);
 
     // Check for cluster-admin permissions
-    let output = std::process::Command::new("oc")
+    let output = KubeTarget::new(None)
+        .command("oc")
         .args(["auth", "can-i", "*", "*", "--all-namespaces"])
         .output()?;
 
@@ -54,52 +59,153 @@ pub fn verify_connection() -> Result<()> {
         }
     }
 
+    check_openshift_version()?;
+
     crate::log_info!("Cluster connection verified");
     Ok(())
 }
 
+/// Warn (and ask to continue) when the cluster is older than `versions.min_openshift`
+fn check_openshift_version() -> Result<()> {
+    let settings = Settings::load()?;
+    let min_version = &settings.versions.min_openshift;
+
+    let output = KubeTarget::new(None)
+        .command("oc")
+        .args([
+            "get",
+            "clusterversion",
+            "-o",
+            "jsonpath={.items[0].status.desired.version}",
+        ])
+        .output()?;
+    let cluster_version = String::from_utf8_lossy(&output.stdout).trim().to_string();
+
+    let result = if output.status.success() && !cluster_version.is_empty() {
+        openshift_version_check(&cluster_version, min_version)
+    } else {
+        CheckResult::Warn("Could not determine OpenShift version".to_string())
+    };
+    result.display();
+
+    if let CheckResult::Warn(_) = result
+        && !crate::utils::confirm("Continue anyway?")?
+    {
+        crate::log_info!("Exiting...");
+        std::process::exit(0);
+    }
+
+    Ok(())
+}
+
+/// Compare the cluster's OpenShift version against the supported minimum
+fn openshift_version_check(cluster_version: &str, min_version: &str) -> CheckResult {
+    match version::version_at_least(cluster_version, min_version) {
+        Some(true) => CheckResult::Pass(format!("OpenShift version: {}", cluster_version)),
+        Some(false) => CheckResult::Warn(format!(
+            "OpenShift {} is older than the minimum supported version {}",
+            cluster_version, min_version
+        )),
+        None => CheckResult::Warn(format!(
+            "Could not compare OpenShift version '{}' with minimum '{}'",
+            cluster_version, min_version
+        )),
+    }
+}
+
+/// Options for deploying to an OpenShift cluster
+pub struct DeployOpenshiftOptions {
+    pub images_file: String,
+    pub skip_tests: bool,
+    pub wait: bool,
+    pub image_pull_policy: Option<operator::ImagePullPolicy>,
+    /// Overrides `operator.namespace` from the config file
+    pub operator_namespace: Option<String>,
+    pub summary_json: Option<PathBuf>,
+    pub cert_manager_version: Option<String>,
+    pub jobset_version: Option<String>,
+    pub leaderworkerset_version: Option<String>,
+}
+
 /// Deploy to OpenShift cluster
-pub fn deploy_openshift(images_file: String, skip_tests: bool) -> Result<()> {
+pub fn deploy_openshift(options: DeployOpenshiftOptions) -> Result<()> {
     // Ensure we're in the operator source directory
     let source_path = crate::utils::ensure_operator_source_directory()?;
 
+    // Load settings for versions and other config
+    let mut settings = Settings::load()?;
+
+    // Apply namespace and version overrides from CLI
+    if let Some(ref namespace) = options.operator_namespace {
+        settings.operator.namespace = namespace.clone();
+    }
+    if let Some(ref v) = options.cert_manager_version {
+        settings.versions.cert_manager = v.clone();
+    }
+    if let Some(ref v) = options.jobset_version {
+        settings.versions.jobset = v.clone();
+    }
+    if let Some(ref v) = options.leaderworkerset_version {
+        settings.versions.leaderworkerset = v.clone();
+    }
+
     crate::log_info!("Starting kueue-operator deployment on OpenShift cluster...");
 
     // Verify connection
     verify_connection()?;
 
     // Load image configuration
-    let images_path = PathBuf::from(images_file);
-
-    // Always display images configuration (critical deployment info)
-    eprintln!();
-    eprintln!("Kueue source path: {}", source_path.display());
-    eprintln!("Using images from:  {}", images_path.display());
-    eprintln!();
+    let images_path = PathBuf::from(&options.images_file);
 
     let image_config = ImageConfig::load(&images_path)?;
 
-    // Display images
-    eprintln!("Images to be used:");
-    eprintln!("  Operator:     {}", image_config.operator()?);
-    eprintln!("  Operand:      {}", image_config.operand()?);
-    eprintln!("  Must-gather:  {}", image_config.must_gather()?);
-    eprintln!();
+    // Display images configuration (critical deployment info) unless --quiet
+    if !crate::utils::is_quiet() {
+        eprintln!();
+        eprintln!("Kueue source path: {}", source_path.display());
+        eprintln!("Using images from:  {}", images_path.display());
+        eprintln!();
+        eprintln!("Images to be used:");
+        eprintln!("  Operator:     {}", image_config.operator()?);
+        eprintln!("  Operand:      {}", image_config.operand()?);
+        eprintln!("  Must-gather:  {}", image_config.must_gather()?);
+        eprintln!();
+    }
 
     // Install cert-manager
-    cert_manager::install(CERT_MANAGER_VERSION, None)?;
+    cert_manager::install(&settings.versions.cert_manager, None)?;
 
     // Install JobSet
-    jobset::install(JOBSET_VERSION, None)?;
+    jobset::install(&settings.versions.jobset, None)?;
 
     // Install LeaderWorkerSet
-    leaderworkerset::install(LEADERWORKERSET_VERSION, None)?;
+    leaderworkerset::install(&settings.versions.leaderworkerset, None)?;
 
     // Install CRDs
     operator::install_crds(None)?;
 
     // Install operator
-    operator::install_operator(&image_config, None)?;
+    let operator_namespace = settings.operator.namespace.as_str();
+    operator::install_operator(
+        &image_config,
+        operator_namespace,
+        settings.behavior.operator_ready_timeout,
+        options.image_pull_policy,
+        options.wait,
+        settings.behavior.show_progress,
+        None,
+    )?;
+
+    let versions = [
+        ("cert_manager", &settings.versions.cert_manager),
+        ("jobset", &settings.versions.jobset),
+        ("leaderworkerset", &settings.versions.leaderworkerset),
+    ]
+    .into_iter()
+    .map(|(name, version)| (name.to_string(), version.to_string()))
+    .collect();
+    DeploymentInfo::new(DeploySummary::images_from_config(&image_config), versions)
+        .record(operator_namespace, None);
 
     crate::log_info!("");
     crate::log_info!("==========================================");
@@ -110,10 +216,13 @@ pub fn deploy_openshift(images_file: String, skip_tests: bool) -> Result<()> {
     crate::log_info!("Current user: {}", get_current_user()?);
     crate::log_info!("");
     crate::log_info!("To view operator logs:");
-    crate::log_info!("  oc logs -n openshift-kueue-operator -l name=openshift-kueue-operator -f");
+    crate::log_info!(
+        "  oc logs -n {} -l name=openshift-kueue-operator -f",
+        operator_namespace
+    );
     crate::log_info!("");
 
-    if skip_tests {
+    if options.skip_tests {
         crate::log_info!("Skipping e2e tests (--skip-tests flag provided)");
     } else {
         crate::log_info!("To run tests:");
@@ -122,17 +231,43 @@ pub fn deploy_openshift(images_file: String, skip_tests: bool) -> Result<()> {
 
     crate::log_info!("");
     crate::log_info!("To cleanup:");
-    crate::log_info!("  kubectl delete namespace openshift-kueue-operator");
+    crate::log_info!("  kubectl delete namespace {}", operator_namespace);
     crate::log_info!("  kubectl delete -f deploy/crd/");
     crate::log_info!("");
 
+    if let Some(path) = &options.summary_json {
+        DeploySummary {
+            cluster: get_current_context().ok(),
+            kubeconfig: std::env::var("KUBECONFIG").ok(),
+            namespace: operator_namespace.to_string(),
+            operator_version: kubectl::get_operator_version(
+                operator_namespace,
+                &KubeTarget::new(None),
+            )
+            .ok(),
+            kueue_version: kubectl::get_kueue_manager_version(
+                operator_namespace,
+                &KubeTarget::new(None),
+            )
+            .ok(),
+            images: DeploySummary::images_from_config(&image_config),
+        }
+        .write(path)?;
+    }
+
     Ok(())
 }
 
 /// Get current kubectl/oc context
 fn get_current_context() -> Result<String> {
-    let output = std::process::Command::new("oc")
-        .args(["config", "current-context"])
+    let target = KubeTarget::new(None);
+    if let Some(context) = &target.context {
+        return Ok(context.clone());
+    }
+
+    let output = target
+        .command("oc")
+        .args(kubectl::CURRENT_CONTEXT_ARGS)
         .output()?;
 
     Ok(String::from_utf8(output.stdout)?.trim().to_string())
@@ -140,15 +275,34 @@ fn get_current_context() -> Result<String> {
 
 /// Get current user
 fn get_current_user() -> Result<String> {
-    let output = std::process::Command::new("oc").args(["whoami"]).output()?;
+    let output = KubeTarget::new(None)
+        .command("oc")
+        .args(["whoami"])
+        .output()?;
 
     Ok(String::from_utf8(output.stdout)?.trim().to_string())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_openshift_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_openshift_version_check() {
+        assert!(matches!(
+            openshift_version_check("4.19.3", "4.18"),
+            CheckResult::Pass(_)
+        ));
+        assert!(matches!(
+            openshift_version_check("4.18.0-rc.2", "4.18"),
+            CheckResult::Pass(_)
+        ));
+        assert!(openshift_version_check("4.16.12", "4.18").is_warning());
+        assert!(openshift_version_check("unknown", "4.18").is_warning());
+    }
 }
@@ -0,0 +1,282 @@
+//! Remove locally-built kueue-dev images from the container runtime
+
+use anyhow::{Context, Result, bail};
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::images::{self, ImageConfig};
+use crate::config::settings::Settings;
+use crate::k8s::kind::KindCluster;
+use crate::utils::{ContainerRuntime, LocalImage};
+
+/// Image name prefix used to recognize kueue-dev images when no images file is available
+const IMAGE_NAME_PREFIX: &str = "kueue";
+
+/// Options for pruning images
+#[derive(Debug, Clone, Default)]
+pub struct PruneOptions {
+    /// Images file whose repositories identify kueue-dev images (defaults to config file setting)
+    pub images_file: Option<String>,
+    /// Only remove images created longer ago than this
+    pub older_than: Option<Duration>,
+    /// List what would be removed without removing anything
+    pub dry_run: bool,
+}
+
+/// Remove kueue-dev images that no kind cluster is using
+pub fn prune(options: PruneOptions) -> Result<()> {
+    let PruneOptions {
+        images_file,
+        older_than,
+        dry_run,
+    } = options;
+
+    let images_file = match images_file {
+        Some(path) => path,
+        None => Settings::load()?.defaults.images_file,
+    };
+    let repositories = match ImageConfig::load(Path::new(&images_file)) {
+        Ok(config) => {
+            crate::log_info!("Matching repositories from {}", images_file);
+            config
+                .list()
+                .into_iter()
+                .map(|(_, image)| images::repository(image).to_string())
+                .collect()
+        }
+        Err(e) => {
+            crate::log_warn!(
+                "Could not load images file ({:#}); matching images named {}*",
+                e,
+                IMAGE_NAME_PREFIX
+            );
+            HashSet::new()
+        }
+    };
+
+    let runtime = ContainerRuntime::detect()?;
+    let local_images = runtime.list_local_images()?;
+
+    let in_use = images_in_use(KindCluster::list_all(), |cluster| {
+        runtime.kind_cluster_images(cluster)
+    })?;
+
+    let cutoff = older_than.map(|age| now().saturating_sub(age.as_secs()));
+    let prunable = select_prunable(&local_images, &repositories, &in_use, cutoff);
+
+    if prunable.is_empty() {
+        crate::log_info!("No kueue-dev images to prune");
+        return Ok(());
+    }
+
+    let mut removed = 0;
+    let mut failed = 0;
+    for image in &prunable {
+        let reference = image.reference();
+        if dry_run {
+            crate::log_info!("Would remove {}", reference);
+            continue;
+        }
+        match runtime.remove_image(&reference) {
+            Ok(()) => {
+                crate::log_info!("Removed {}", reference);
+                removed += 1;
+            }
+            Err(e) => {
+                crate::log_warn!("{:#}", e);
+                failed += 1;
+            }
+        }
+    }
+
+    if dry_run {
+        crate::log_info!("{} image(s) would be removed (dry run)", prunable.len());
+    } else {
+        crate::log_info!("Removed {} image(s)", removed);
+        if failed > 0 {
+            bail!("Failed to remove {} image(s)", failed);
+        }
+    }
+
+    Ok(())
+}
+
+/// Normalized references of every image loaded into the given kind clusters.
+/// Fails when the clusters cannot be listed, since pruning without knowing
+/// which images are in use could remove images a cluster still needs.
+fn images_in_use(
+    clusters: Result<Vec<String>>,
+    cluster_images: impl Fn(&str) -> Result<Vec<String>>,
+) -> Result<HashSet<String>> {
+    let clusters = clusters.context(
+        "Cannot determine which images kind clusters are using; aborting prune. \
+         Check that kind is installed and working",
+    )?;
+
+    let mut in_use = HashSet::new();
+    for cluster in clusters {
+        let images = cluster_images(&cluster)
+            .with_context(|| format!("Failed to list images in kind cluster {}", cluster))?;
+        in_use.extend(images.iter().map(|image| normalize(image)));
+    }
+    Ok(in_use)
+}
+
+/// Images belonging to kueue-dev that are not in use and older than `cutoff`.
+/// With no `repositories`, images whose name starts with [`IMAGE_NAME_PREFIX`] match.
+/// Images with an unknown creation time are kept when a cutoff is given.
+fn select_prunable<'a>(
+    images: &'a [LocalImage],
+    repositories: &HashSet<String>,
+    in_use: &HashSet<String>,
+    cutoff: Option<u64>,
+) -> Vec<&'a LocalImage> {
+    let repositories: HashSet<String> = repositories.iter().map(|r| normalize(r)).collect();
+
+    images
+        .iter()
+        .filter(|image| {
+            let repository = normalize(&image.repository);
+            if repositories.is_empty() {
+                let name = repository.rsplit('/').next().unwrap_or_default();
+                name.starts_with(IMAGE_NAME_PREFIX)
+            } else {
+                repositories.contains(&repository)
+            }
+        })
+        .filter(|image| !in_use.contains(&normalize(&image.reference())))
+        .filter(|image| match cutoff {
+            Some(cutoff) => image.created.is_some_and(|created| created < cutoff),
+            None => true,
+        })
+        .collect()
+}
+
+/// Strip the implicit registry prefixes that docker, podman and containerd add,
+/// so the same image compares equal across them
+fn normalize(reference: &str) -> String {
+    ["docker.io/library/", "docker.io/", "localhost/"]
+        .iter()
+        .find_map(|prefix| reference.strip_prefix(prefix))
+        .unwrap_or(reference)
+        .to_string()
+}
+
+/// Parse an age such as `30m`, `12h`, `7d` or `2w`
+pub fn parse_age(value: &str) -> Result<Duration> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: u64 = number
+        .parse()
+        .with_context(|| format!("Invalid age '{}': expected e.g. 12h, 7d", value))?;
+
+    let seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => bail!("Invalid age '{}': unit must be one of s, m, h, d, w", value),
+    };
+
+    let seconds = number
+        .checked_mul(seconds)
+        .ok_or_else(|| anyhow::anyhow!("Invalid age '{}': too large", value))?;
+    Ok(Duration::from_secs(seconds))
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(repository: &str, tag: &str, created: Option<u64>) -> LocalImage {
+        LocalImage {
+            repository: repository.to_string(),
+            tag: tag.to_string(),
+            id: String::new(),
+            created,
+        }
+    }
+
+    #[test]
+    fn test_parse_age() {
+        assert_eq!(parse_age("90s").unwrap(), Duration::from_secs(90));
+        assert_eq!(parse_age("12h").unwrap(), Duration::from_secs(12 * 3600));
+        assert_eq!(parse_age("7d").unwrap(), Duration::from_secs(7 * 86400));
+        assert_eq!(parse_age("2w").unwrap(), Duration::from_secs(14 * 86400));
+        assert!(parse_age("7").is_err());
+        assert!(parse_age("d").is_err());
+        assert!(parse_age("3y").is_err());
+        let err = parse_age("99999999999999999w").unwrap_err();
+        assert!(err.to_string().contains("Invalid age"));
+    }
+
+    #[test]
+    fn test_select_prunable() {
+        let images = vec![
+            image("quay.io/me/kueue-operator", "dev", Some(100)),
+            image("quay.io/me/kueue-operator", "old", Some(10)),
+            image("localhost/quay.io/me/kueue", "dev", None),
+            image("quay.io/me/kueue", "in-use", Some(10)),
+            image("registry.k8s.io/pause", "3.9", Some(10)),
+        ];
+        let repositories: HashSet<String> = ["quay.io/me/kueue-operator", "quay.io/me/kueue"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let in_use: HashSet<String> = [normalize("docker.io/quay.io/me/kueue:in-use")]
+            .into_iter()
+            .collect();
+
+        let refs = |selected: Vec<&LocalImage>| -> Vec<String> {
+            selected.iter().map(|i| i.reference()).collect()
+        };
+
+        assert_eq!(
+            refs(select_prunable(&images, &repositories, &in_use, None)),
+            vec![
+                "quay.io/me/kueue-operator:dev",
+                "quay.io/me/kueue-operator:old",
+                "localhost/quay.io/me/kueue:dev",
+            ]
+        );
+        assert_eq!(
+            refs(select_prunable(&images, &repositories, &in_use, Some(50))),
+            vec!["quay.io/me/kueue-operator:old"]
+        );
+        // Without an images file, fall back to the name prefix
+        assert_eq!(
+            select_prunable(&images, &HashSet::new(), &HashSet::new(), None).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_images_in_use() {
+        let in_use = images_in_use(Ok(vec!["dev".to_string()]), |cluster| {
+            Ok(vec![format!("docker.io/quay.io/me/kueue:{}", cluster)])
+        })
+        .unwrap();
+        assert!(in_use.contains(&normalize("quay.io/me/kueue:dev")));
+
+        // A failed cluster listing aborts instead of treating every image as unused
+        let err = images_in_use(Err(anyhow::anyhow!("kind not found")), |_| {
+            panic!("no clusters should be inspected")
+        })
+        .unwrap_err();
+        assert!(format!("{:#}", err).contains("aborting prune"));
+
+        assert!(images_in_use(Ok(vec!["dev".to_string()]), |_| bail!("boom")).is_err());
+    }
+}
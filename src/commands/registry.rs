@@ -0,0 +1,55 @@
+//! Local registry command implementations
+
+use anyhow::{Result, bail};
+
+use crate::k8s::kind::KindCluster;
+use crate::k8s::registry::{CONTAINER_NAME, LocalRegistry};
+use crate::utils::ContainerRuntime;
+
+/// Handle registry start command
+pub fn start(port: u16) -> Result<()> {
+    let runtime = ContainerRuntime::detect()?;
+
+    if let Some(existing) = LocalRegistry::active(&runtime)
+        && existing.port != port
+    {
+        bail!(
+            "Local registry '{}' is already running on {}. Stop it first with: kueue-dev registry stop",
+            CONTAINER_NAME,
+            existing.address()
+        );
+    }
+
+    let registry = LocalRegistry::new(port);
+    registry.start(&runtime)?;
+    if !LocalRegistry::connect_to_kind_network(&runtime)? {
+        crate::log_info!(
+            "No kind network yet; the registry is connected on the next `cluster create`"
+        );
+    }
+
+    let unconfigured: Vec<String> = KindCluster::list_all()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| KindCluster::recorded_registry_port(name) != Some(port))
+        .collect();
+    if !unconfigured.is_empty() {
+        crate::log_warn!(
+            "These clusters were created without the registry and still need `kind load`; recreate them to pull from it: {}",
+            unconfigured.join(", ")
+        );
+    }
+
+    crate::log_info!("Local registry available at {}", registry.address());
+    crate::log_info!(
+        "Clusters created from now on pull {}/... images without `kind load`",
+        registry.address()
+    );
+    Ok(())
+}
+
+/// Handle registry stop command
+pub fn stop() -> Result<()> {
+    let runtime = ContainerRuntime::detect()?;
+    LocalRegistry::stop(&runtime)
+}
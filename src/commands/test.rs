@@ -3,25 +3,86 @@
 use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::str::FromStr;
 
+use crate::commands::junit::JUnitReport;
+use crate::commands::netpol;
+use crate::commands::test_summary::{TestRunResult, TestSummary};
 use crate::config::images::ImageConfig;
 use crate::config::kueue::{Framework, KueueConfig};
 use crate::config::settings::Settings;
 use crate::install::{calico, cert_manager, jobset, leaderworkerset, operator};
+use crate::k8s::target::KubeTarget;
 use crate::k8s::{images, kind, kubectl, nodes};
 use crate::utils::ContainerRuntime;
 
 /// Options for running tests on kind cluster
 pub struct TestKindOptions {
     pub cluster_name: String,
+    /// Deploy onto the cluster if it already exists, skipping cluster creation,
+    /// CNI install and node labeling
+    pub reuse: bool,
+    /// Calico version instead of `versions.calico`
+    pub calico_version: Option<String>,
     pub focus: Option<String>,
     pub label_filter: Option<String>,
     pub images_file: String,
     pub skip_kueue_cr: bool,
     pub kueue_frameworks: Option<String>,
     pub kueue_namespace: Option<String>,
+    /// Extra labels/annotations for the Kueue CR
+    pub kueue_cr_labels: Vec<(String, String)>,
+    pub kueue_cr_annotations: Vec<(String, String)>,
+    pub ginkgo: GinkgoOptions,
 }
 
+/// Ginkgo invocation options shared by the test commands
+#[derive(Debug, Clone, Default)]
+pub struct GinkgoOptions {
+    /// Prebuilt ginkgo binary to use instead of installing one into bin/
+    pub ginkgo_from: Option<PathBuf>,
+    pub skip_overrides: SkipOverrides,
+    /// Ginkgo package path (operator tests only; default ./test/e2e/...)
+    pub test_path: Option<String>,
+    /// Directory to write junit.xml and e2e.json reports into
+    pub report_dir: Option<PathBuf>,
+    /// Consolidated JUnit report of the deploy phases and specs (`test operator` only)
+    pub junit_out: Option<PathBuf>,
+    /// Labels for the worker nodes in addition to `instance-type`
+    pub worker_labels: nodes::WorkerLabels,
+}
+
+/// Per-run adjustments to the skip patterns derived from settings
+#[derive(Debug, Clone, Default)]
+pub struct SkipOverrides {
+    /// Patterns to skip in addition to the configured ones
+    pub add: Vec<String>,
+    /// Configured patterns to stop skipping
+    pub remove: Vec<String>,
+}
+
+impl SkipOverrides {
+    /// Apply the overrides to a base skip list, preserving order and avoiding duplicates
+    pub fn apply(&self, base: Vec<String>) -> Vec<String> {
+        let mut patterns: Vec<String> = base
+            .into_iter()
+            .filter(|pattern| !self.remove.contains(pattern))
+            .collect();
+        for pattern in &self.add {
+            if !patterns.contains(pattern) && !self.remove.contains(pattern) {
+                patterns.push(pattern.clone());
+            }
+        }
+        patterns
+    }
+}
+
+/// Default ginkgo package path for the operator e2e suite
+const DEFAULT_TEST_PATH: &str = "./test/e2e/...";
+
+/// Ginkgo version installed into the project bin/ directory
+const GINKGO_VERSION: &str = "2.1.4";
+
 /// Generate test skip pattern regex from a list of patterns
 pub fn generate_skip_pattern(patterns: &[String]) -> String {
     if patterns.is_empty() {
@@ -31,11 +92,69 @@ pub fn generate_skip_pattern(patterns: &[String]) -> String {
     format!("({})", pattern_strings.join("|"))
 }
 
+/// Describe the effective ginkgo test selection as a single line
+fn describe_test_selection(
+    focus: Option<&str>,
+    label_filter: Option<&str>,
+    skip_pattern: &str,
+) -> String {
+    let or_none = |value: Option<&str>| match value {
+        Some(v) if !v.is_empty() => v.to_string(),
+        _ => "(none)".to_string(),
+    };
+    format!(
+        "Test selection: focus={} skip={} label-filter={}",
+        or_none(focus),
+        or_none(Some(skip_pattern)),
+        or_none(label_filter)
+    )
+}
+
+/// Return the skip patterns that match the focus pattern itself. Any spec
+/// selected by such a focus also matches the skip pattern, so ginkgo would
+/// skip everything the user asked to focus on.
+fn skip_patterns_covering_focus<'a>(focus: &str, skip_patterns: &'a [String]) -> Vec<&'a str> {
+    skip_patterns
+        .iter()
+        .map(|s| s.as_str())
+        .filter(|pattern| match regex::Regex::new(pattern) {
+            Ok(re) => re.is_match(focus),
+            Err(_) => focus.contains(pattern),
+        })
+        .collect()
+}
+
+/// Log the effective test selection and warn if the focus would be skipped
+fn log_test_selection(
+    focus: Option<&str>,
+    label_filter: Option<&str>,
+    skip_patterns: &[String],
+    skip_pattern: &str,
+) {
+    crate::log_info!(
+        "{}",
+        describe_test_selection(focus, label_filter, skip_pattern)
+    );
+
+    if let Some(focus) = focus {
+        let covering = skip_patterns_covering_focus(focus, skip_patterns);
+        if !covering.is_empty() {
+            crate::log_warn!(
+                "Focus '{}' matches skip pattern(s) {}; the focused tests may all be skipped",
+                focus,
+                covering.join(", ")
+            );
+            crate::log_warn!("Adjust the skip patterns under [tests] in your kueue-dev config");
+        }
+    }
+}
+
 /// Run e2e tests on existing cluster
 pub fn run_tests(
     focus: Option<String>,
     label_filter: Option<String>,
     kubeconfig: Option<PathBuf>,
+    ginkgo: &GinkgoOptions,
 ) -> Result<()> {
     // Determine kubeconfig
     let kc = if let Some(path) = kubeconfig {
@@ -44,35 +163,42 @@ pub fn run_tests(
         crate::utils::operator_source_join("kube.kubeconfig")
     };
 
-    if !kc.exists() {
-        return Err(anyhow::anyhow!(
-            "Kubeconfig not found at {}. Please create cluster first.",
-            kc.display()
-        ));
-    }
-
-    // Canonicalize to get absolute path
-    let kc = kc.canonicalize().unwrap_or(kc);
+    // Validate the path (or colon-separated list of paths)
+    let kc = crate::utils::resolve_kubeconfig_value(kc.as_os_str())?;
     crate::log_info!("Using kubeconfig: {}", kc.display());
 
     // Install or check for ginkgo
-    let ginkgo_bin = ensure_ginkgo()?;
+    let ginkgo_bin = ensure_ginkgo(ginkgo.ginkgo_from.as_deref())?;
 
     // Load settings to get skip patterns
     let settings = Settings::load()?;
-    let skip_patterns = &settings.tests.operator_skip_patterns;
+    let skip_patterns = &ginkgo
+        .skip_overrides
+        .apply(settings.tests.effective_operator_skip_patterns());
 
     // Run tests
-    execute_ginkgo_tests(&ginkgo_bin, focus, label_filter, skip_patterns, Some(&kc))?;
+    execute_ginkgo_tests(
+        &ginkgo_bin,
+        focus,
+        label_filter,
+        skip_patterns,
+        ginkgo,
+        Some(&kc),
+    )?;
 
     Ok(())
 }
 
-/// Run tests with retry loop
-pub fn run_tests_with_retry(
+/// Run the tests `repeat` times without prompting, collecting each run's
+/// JSON report. With `summary_json`, writes per-spec pass/fail counts and
+/// flaky specs across all iterations to that path.
+pub fn run_tests_repeated(
     focus: Option<String>,
     label_filter: Option<String>,
     kubeconfig: Option<PathBuf>,
+    ginkgo: &GinkgoOptions,
+    repeat: u32,
+    summary_json: Option<&Path>,
 ) -> Result<()> {
     // Determine kubeconfig
     let kc = if let Some(path) = kubeconfig {
@@ -81,22 +207,148 @@ pub fn run_tests_with_retry(
         crate::utils::operator_source_join("kube.kubeconfig")
     };
 
-    if !kc.exists() {
+    // Validate the path (or colon-separated list of paths)
+    let kc = crate::utils::resolve_kubeconfig_value(kc.as_os_str())?;
+    crate::log_info!("Using kubeconfig: {}", kc.display());
+
+    // Install or check for ginkgo
+    let ginkgo_bin = ensure_ginkgo(ginkgo.ginkgo_from.as_deref())?;
+
+    // Load settings to get skip patterns
+    let settings = Settings::load()?;
+    let skip_patterns = &ginkgo
+        .skip_overrides
+        .apply(settings.tests.effective_operator_skip_patterns());
+
+    // Each iteration needs its own JSON report; keep them under --report-dir
+    // when given, otherwise in a temporary directory
+    let scratch_dir = tempfile::tempdir().context("Failed to create report directory")?;
+    let reports_root = ginkgo
+        .report_dir
+        .clone()
+        .unwrap_or_else(|| scratch_dir.path().to_path_buf());
+
+    let mut summary = TestSummary::default();
+    for iteration in 1..=repeat {
+        crate::log_info!("");
+        crate::log_info!("==========================================");
+        crate::log_info!("Running E2E tests (iteration {}/{})", iteration, repeat);
+        crate::log_info!("==========================================");
+        crate::log_info!("");
+
+        let iteration_dir = reports_root.join(format!("iteration-{}", iteration));
+        let iteration_ginkgo = GinkgoOptions {
+            report_dir: Some(iteration_dir.clone()),
+            ..ginkgo.clone()
+        };
+
+        let started = std::time::Instant::now();
+        let outcome = execute_ginkgo_tests(
+            &ginkgo_bin,
+            focus.clone(),
+            label_filter.clone(),
+            skip_patterns,
+            &iteration_ginkgo,
+            Some(&kc),
+        );
+        if let Err(e) = &outcome {
+            crate::log_warn!("Iteration {} failed: {}", iteration, e);
+        }
+
+        let run = match TestRunResult::from_report_file(&iteration_dir.join("e2e.json")) {
+            Ok(run) => Some(run),
+            Err(e) => {
+                crate::log_warn!("No spec results for iteration {}: {:#}", iteration, e);
+                None
+            }
+        };
+        summary.record(run.as_ref(), outcome.is_ok(), started.elapsed());
+    }
+
+    crate::log_info!("");
+    crate::log_info!(
+        "{}/{} iterations passed",
+        summary.iterations - summary.failed_iterations,
+        summary.iterations
+    );
+    for spec in &summary.flaky {
+        let counts = &summary.specs[spec];
+        crate::log_warn!(
+            "Flaky: {} (passed {}, failed {})",
+            spec,
+            counts.passed,
+            counts.failed
+        );
+    }
+
+    if let Some(path) = summary_json {
+        summary.write(path)?;
+        crate::log_info!("Wrote test summary to {}", path.display());
+    }
+
+    if summary.failed_iterations > 0 {
         return Err(anyhow::anyhow!(
-            "Kubeconfig not found at {}. Please create cluster first.",
-            kc.display()
+            "E2E tests failed in {} of {} iterations",
+            summary.failed_iterations,
+            summary.iterations
         ));
     }
 
-    // Canonicalize to get absolute path
-    let kc = kc.canonicalize().unwrap_or(kc);
+    Ok(())
+}
+
+/// Run tests with retry loop
+pub fn run_tests_with_retry(
+    focus: Option<String>,
+    label_filter: Option<String>,
+    kubeconfig: Option<PathBuf>,
+    ginkgo: &GinkgoOptions,
+) -> Result<()> {
+    run_tests_with_retry_report(
+        focus,
+        label_filter,
+        kubeconfig,
+        ginkgo,
+        &mut JUnitReport::default(),
+    )
+}
+
+/// Run tests with retry loop. With `--junit-out`, `report` is written after
+/// every attempt, merged with that attempt's ginkgo JUnit report.
+fn run_tests_with_retry_report(
+    focus: Option<String>,
+    label_filter: Option<String>,
+    kubeconfig: Option<PathBuf>,
+    ginkgo: &GinkgoOptions,
+    report: &mut JUnitReport,
+) -> Result<()> {
+    // The consolidated report needs ginkgo's JUnit output; keep it in a
+    // temporary directory unless --report-dir is set
+    let scratch_dir = tempfile::tempdir().context("Failed to create report directory")?;
+    let mut ginkgo = ginkgo.clone();
+    if ginkgo.junit_out.is_some() && ginkgo.report_dir.is_none() {
+        ginkgo.report_dir = Some(scratch_dir.path().to_path_buf());
+    }
+    let ginkgo = &ginkgo;
+
+    // Determine kubeconfig
+    let kc = if let Some(path) = kubeconfig {
+        path
+    } else {
+        crate::utils::operator_source_join("kube.kubeconfig")
+    };
+
+    // Validate the path (or colon-separated list of paths)
+    let kc = crate::utils::resolve_kubeconfig_value(kc.as_os_str())?;
 
     // Install or check for ginkgo
-    let ginkgo_bin = ensure_ginkgo()?;
+    let ginkgo_bin = ensure_ginkgo(ginkgo.ginkgo_from.as_deref())?;
 
     // Load settings to get skip patterns
     let settings = Settings::load()?;
-    let skip_patterns = &settings.tests.operator_skip_patterns;
+    let skip_patterns = &ginkgo
+        .skip_overrides
+        .apply(settings.tests.effective_operator_skip_patterns());
 
     crate::log_info!("");
     crate::log_info!("==========================================");
@@ -106,13 +358,22 @@ pub fn run_tests_with_retry(
 
     // Retry loop
     loop {
-        match execute_ginkgo_tests(
+        let outcome = execute_ginkgo_tests(
             &ginkgo_bin,
             focus.clone(),
             label_filter.clone(),
             skip_patterns,
+            ginkgo,
             Some(&kc),
-        ) {
+        );
+
+        if let (Some(junit_out), Some(report_dir)) = (&ginkgo.junit_out, &ginkgo.report_dir) {
+            report.merge_ginkgo_report(&report_dir.join("junit.xml"));
+            report.write(junit_out)?;
+            crate::log_info!("Wrote JUnit report to {}", junit_out.display());
+        }
+
+        match outcome {
             Ok(_) => {
                 crate::log_info!("");
                 crate::log_info!("==========================================");
@@ -136,15 +397,31 @@ pub fn run_tests_with_retry(
     Ok(())
 }
 
-/// Create kind cluster and run tests
+/// Create kind cluster and run tests. With `--junit-out`, each deploy phase
+/// is recorded as a testcase, and the report is written even if a phase fails.
 pub fn run_tests_kind(options: TestKindOptions) -> Result<()> {
+    let junit_out = options.ginkgo.junit_out.clone();
+    let mut report = JUnitReport::default();
+
+    let result = deploy_and_test_kind(options, &mut report);
+    if let Some(path) = junit_out.filter(|_| result.is_err()) {
+        // Successful runs and test failures write the report from the retry loop
+        report.write(&path)?;
+        crate::log_info!("Wrote JUnit report to {}", path.display());
+    }
+
+    result
+}
+
+fn deploy_and_test_kind(options: TestKindOptions, report: &mut JUnitReport) -> Result<()> {
     // Ensure we're in the operator source directory
     crate::utils::ensure_operator_source_directory()?;
 
-    crate::log_info!("Creating kind cluster and running e2e tests...");
-
     // Load settings
-    let settings = Settings::load()?;
+    let mut settings = Settings::load()?;
+    if let Some(ref v) = options.calico_version {
+        settings.versions.calico = v.clone();
+    }
 
     // Parse CNI provider (always use Calico for tests)
     let cni_provider = kind::CniProvider::Calico;
@@ -159,22 +436,56 @@ pub fn run_tests_kind(options: TestKindOptions) -> Result<()> {
         .map(PathBuf::from)
         .or_else(|| Some(crate::utils::operator_source_join("kube.kubeconfig")));
 
-    // Create the cluster
-    let kubeconfig_path_opt = cluster.create_with_kubeconfig(kubeconfig_to_save)?;
+    let reuse = options.reuse && cluster.exists()?;
+    if options.reuse && !reuse {
+        crate::log_info!(
+            "Cluster '{}' does not exist; creating it",
+            options.cluster_name
+        );
+    }
 
-    // We need a kubeconfig for tests, so error if not saved
-    let kubeconfig_path = kubeconfig_path_opt.ok_or_else(|| {
-        anyhow::anyhow!("Kubeconfig was not saved. This should not happen in test run")
-    })?;
+    let kubeconfig_path = if reuse {
+        crate::log_info!(
+            "Reusing kind cluster '{}' and running e2e tests...",
+            options.cluster_name
+        );
+        report.phase("Export kubeconfig", || {
+            cluster.export_kubeconfig_with_custom(kubeconfig_to_save)
+        })?
+    } else {
+        crate::log_info!("Creating kind cluster and running e2e tests...");
+
+        // Create the cluster
+        let kubeconfig_path_opt = report.phase("Create kind cluster", || {
+            cluster.create_with_kubeconfig(kubeconfig_to_save)
+        })?;
+
+        // We need a kubeconfig for tests, so error if not saved
+        let kubeconfig_path = kubeconfig_path_opt.ok_or_else(|| {
+            anyhow::anyhow!("Kubeconfig was not saved. This should not happen in test run")
+        })?;
+
+        // Install Calico
+        report.phase("Install Calico", || {
+            calico::install(
+                &settings.versions.calico,
+                &KubeTarget::new(Some(&kubeconfig_path)),
+            )
+        })?;
+
+        // Label worker nodes
+        report.phase("Label worker nodes", || {
+            nodes::label_worker_nodes(
+                &KubeTarget::new(Some(&kubeconfig_path)),
+                &options.ginkgo.worker_labels,
+            )
+        })?;
+
+        kubeconfig_path
+    };
 
     crate::log_info!("Kubeconfig: {}", kubeconfig_path.display());
 
-    // Install Calico
-    calico::install(&settings.versions.calico, Some(&kubeconfig_path))?;
-
-    // Label worker nodes
-    nodes::label_worker_nodes(Some(&kubeconfig_path))?;
-
     // Load image configuration
     let images_path = if options.images_file.starts_with('/') {
         PathBuf::from(&options.images_file)
@@ -189,19 +500,36 @@ pub fn run_tests_kind(options: TestKindOptions) -> Result<()> {
     crate::log_info!("Using container runtime: {}", runtime);
 
     // Load images into kind cluster
-    images::load_images_to_kind(&options.cluster_name, &image_config, &runtime, true)?;
+    report.phase("Load images", || {
+        images::load_images_to_kind(
+            &options.cluster_name,
+            &image_config,
+            &runtime,
+            true,
+            images::DEFAULT_MAX_PARALLEL_LOADS,
+            settings.behavior.parallel_operations,
+        )
+    })?;
 
     // Install cert-manager
-    cert_manager::install(&settings.versions.cert_manager, Some(&kubeconfig_path))?;
+    report.phase("Install cert-manager", || {
+        cert_manager::install(&settings.versions.cert_manager, Some(&kubeconfig_path))
+    })?;
 
     // Install JobSet
-    jobset::install(&settings.versions.jobset, Some(&kubeconfig_path))?;
+    report.phase("Install JobSet", || {
+        jobset::install(&settings.versions.jobset, Some(&kubeconfig_path))
+    })?;
 
     // Install LeaderWorkerSet
-    leaderworkerset::install(&settings.versions.leaderworkerset, Some(&kubeconfig_path))?;
+    report.phase("Install LeaderWorkerSet", || {
+        leaderworkerset::install(&settings.versions.leaderworkerset, Some(&kubeconfig_path))
+    })?;
 
     // Install CRDs
-    operator::install_crds(Some(&kubeconfig_path))?;
+    report.phase("Install CRDs", || {
+        operator::install_crds(Some(&kubeconfig_path))
+    })?;
 
     // Build Kueue config if not skipping
     let kueue_config = if options.skip_kueue_cr {
@@ -212,15 +540,40 @@ pub fn run_tests_kind(options: TestKindOptions) -> Result<()> {
             &settings,
             options.kueue_frameworks.as_deref(),
             options.kueue_namespace.as_deref(),
+            &options.kueue_cr_labels,
+            &options.kueue_cr_annotations,
         )?)
     };
 
     // Install operator with optional Kueue CR
-    operator::install_operator_with_config(
-        &image_config,
-        kueue_config.as_ref(),
-        Some(&kubeconfig_path),
-    )?;
+    let overrides = operator::OperatorOverrides {
+        image_pull_policy: Some(operator::ImagePullPolicy::IfNotPresent),
+        ..Default::default()
+    };
+    report.phase("Install operator", || {
+        operator::install_operator_with_config(
+            &image_config,
+            kueue_config.as_ref(),
+            &overrides,
+            settings.behavior.operator_ready_timeout,
+            true,
+            settings.behavior.show_progress,
+            Some(&kubeconfig_path),
+        )
+    })?;
+
+    // A reused cluster may still run the operator from an earlier deploy; the
+    // apply above leaves its pods alone when the manifests are unchanged
+    if reuse {
+        report.phase("Restart operator", || {
+            kubectl::rollout_restart(
+                "openshift-kueue-operator",
+                overrides.namespace(),
+                "300s",
+                &KubeTarget::new(Some(&kubeconfig_path)),
+            )
+        })?;
+    }
 
     crate::log_info!("");
     crate::log_info!("==========================================");
@@ -229,13 +582,44 @@ pub fn run_tests_kind(options: TestKindOptions) -> Result<()> {
     crate::log_info!("");
 
     // Run tests with retry
-    run_tests_with_retry(options.focus, options.label_filter, Some(kubeconfig_path))?;
+    run_tests_with_retry_report(
+        options.focus,
+        options.label_filter,
+        Some(kubeconfig_path),
+        &options.ginkgo,
+        report,
+    )?;
 
     Ok(())
 }
 
-/// Ensure ginkgo binary is available
-fn ensure_ginkgo() -> Result<PathBuf> {
+/// Ensure ginkgo binary is available, preferring a shared prebuilt binary
+/// (--ginkgo-from / GINKGO_BIN) over the project-local bin/ginkgo, which is
+/// installed if missing
+fn ensure_ginkgo(ginkgo_from: Option<&Path>) -> Result<PathBuf> {
+    if let Some(shared) = ginkgo_from {
+        match ginkgo_version(shared) {
+            Some(version) if version == GINKGO_VERSION => {
+                crate::log_info!("Using shared ginkgo at {}", shared.display());
+                return Ok(shared.to_path_buf());
+            }
+            Some(version) => {
+                crate::log_warn!(
+                    "Shared ginkgo at {} is version {}, expected {}; using project-local ginkgo",
+                    shared.display(),
+                    version,
+                    GINKGO_VERSION
+                );
+            }
+            None => {
+                crate::log_warn!(
+                    "Shared ginkgo at {} could not be executed; using project-local ginkgo",
+                    shared.display()
+                );
+            }
+        }
+    }
+
     let bin_dir = crate::utils::operator_source_join("bin");
     let ginkgo_bin = bin_dir.join("ginkgo");
 
@@ -254,7 +638,7 @@ fn ensure_ginkgo() -> Result<PathBuf> {
         .args([
             "install",
             "-mod=mod",
-            "github.com/onsi/ginkgo/v2/ginkgo@v2.1.4",
+            &format!("github.com/onsi/ginkgo/v2/ginkgo@v{}", GINKGO_VERSION),
         ])
         .env("GOBIN", &bin_dir)
         .env("GO111MODULE", "on")
@@ -275,44 +659,131 @@ fn ensure_ginkgo() -> Result<PathBuf> {
     Ok(ginkgo_bin)
 }
 
-/// Execute ginkgo tests
-fn execute_ginkgo_tests(
-    ginkgo_bin: &Path,
-    focus: Option<String>,
-    label_filter: Option<String>,
-    skip_patterns: &[String],
-    kubeconfig: Option<&Path>,
-) -> Result<()> {
-    crate::log_info!("Running e2e tests...");
+/// Run `ginkgo version` and return the reported version, if it executes
+fn ginkgo_version(ginkgo_bin: &Path) -> Option<String> {
+    let output = Command::new(ginkgo_bin).arg("version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_ginkgo_version(&String::from_utf8_lossy(&output.stdout)).map(str::to_string)
+}
 
-    // Use provided label filter or default to !disruptive
-    let label_filter_str = label_filter.as_deref().unwrap_or("!disruptive");
-    let label_filter_arg = format!("--label-filter={}", label_filter_str);
+/// Parse the version from `ginkgo version` output ("Ginkgo Version 2.1.4")
+fn parse_ginkgo_version(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Ginkgo Version "))
+        .map(|version| version.trim().trim_start_matches('v'))
+}
 
-    let mut args = vec![label_filter_arg.as_str(), "-v"];
+/// Assemble ginkgo arguments for the operator e2e suite and log the selection.
+/// Shared by the real run and `test list` so the two cannot drift apart.
+fn operator_ginkgo_args(
+    focus: Option<&str>,
+    label_filter: Option<&str>,
+    skip_patterns: &[String],
+    report_flags: &[String],
+    test_path: Option<&str>,
+) -> Vec<String> {
+    // Use provided label filter or default to !disruptive
+    let label_filter = label_filter.unwrap_or("!disruptive");
+    let mut args = vec![format!("--label-filter={}", label_filter), "-v".to_string()];
 
     // Generate skip pattern
     let skip_pattern = generate_skip_pattern(skip_patterns);
     if !skip_pattern.is_empty() {
-        args.push("--skip");
-        args.push(&skip_pattern);
+        args.push("--skip".to_string());
+        args.push(skip_pattern.clone());
     }
 
+    log_test_selection(focus, Some(label_filter), skip_patterns, &skip_pattern);
+
     // Add focus pattern if provided
-    let focus_arg;
-    if let Some(ref pattern) = focus {
-        crate::log_info!("Running tests with focus: {}", pattern);
-        args.push("--focus");
-        focus_arg = pattern.clone();
-        args.push(&focus_arg);
+    if let Some(pattern) = focus {
+        args.push("--focus".to_string());
+        args.push(pattern.to_string());
     }
 
-    // Test directory
-    args.push("./test/e2e/...");
+    args.extend_from_slice(report_flags);
 
-    // Run ginkgo
+    // Test package path, relative to the operator source directory
+    let test_path = test_path.unwrap_or(DEFAULT_TEST_PATH);
+    if test_path != DEFAULT_TEST_PATH {
+        crate::log_info!("Using test path: {}", test_path);
+    }
+    args.push(test_path.to_string());
+
+    args
+}
+
+/// List the operator e2e specs that would run, without executing them
+pub fn list_tests(
+    focus: Option<String>,
+    label_filter: Option<String>,
+    ginkgo: &GinkgoOptions,
+) -> Result<()> {
+    // Load settings before changing directories
+    let settings = Settings::load()?;
+    let skip_patterns = ginkgo
+        .skip_overrides
+        .apply(settings.tests.effective_operator_skip_patterns());
+
+    // Ensure we're in the operator source directory
+    crate::utils::ensure_operator_source_directory()?;
+
+    let ginkgo_bin = ensure_ginkgo(ginkgo.ginkgo_from.as_deref())?;
+
+    let mut args = vec!["--dry-run".to_string()];
+    args.extend(operator_ginkgo_args(
+        focus.as_deref(),
+        label_filter.as_deref(),
+        &skip_patterns,
+        &[],
+        ginkgo.test_path.as_deref(),
+    ));
+
+    let status = Command::new(&ginkgo_bin)
+        .args(&args)
+        .status()
+        .context("Failed to run ginkgo --dry-run")?;
+
+    if !status.success() {
+        return Err(anyhow::anyhow!("ginkgo --dry-run failed"));
+    }
+
+    Ok(())
+}
+
+/// Execute ginkgo tests
+fn execute_ginkgo_tests(
+    ginkgo_bin: &Path,
+    focus: Option<String>,
+    label_filter: Option<String>,
+    skip_patterns: &[String],
+    ginkgo: &GinkgoOptions,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    crate::log_info!("Running e2e tests...");
+
+    // Reports are opt-in for operator tests
+    let report_flags = match ginkgo.report_dir {
+        Some(ref dir) => report_args(dir)?,
+        None => Vec::new(),
+    };
+
+    let args = operator_ginkgo_args(
+        focus.as_deref(),
+        label_filter.as_deref(),
+        skip_patterns,
+        &report_flags,
+        ginkgo.test_path.as_deref(),
+    );
+
+    // Run ginkgo from the operator source (--source / KUEUE_OPERATOR_SOURCE / config)
+    // so relative test paths resolve there rather than in the caller's directory
     let mut cmd = Command::new(ginkgo_bin);
-    cmd.args(&args);
+    cmd.args(&args)
+        .current_dir(crate::utils::operator_source_join(""));
 
     if let Some(kc) = kubeconfig {
         cmd.env("KUBECONFIG", kc);
@@ -333,6 +804,8 @@ fn build_kueue_config_from_settings(
     settings: &Settings,
     frameworks_override: Option<&str>,
     namespace_override: Option<&str>,
+    labels: &[(String, String)],
+    annotations: &[(String, String)],
 ) -> Result<KueueConfig> {
     let namespace = namespace_override.unwrap_or(&settings.kueue.namespace);
 
@@ -372,6 +845,13 @@ fn build_kueue_config_from_settings(
         builder = builder.frameworks(frameworks);
     }
 
+    for (key, value) in labels {
+        builder = builder.label(key, value);
+    }
+    for (key, value) in annotations {
+        builder = builder.annotation(key, value);
+    }
+
     builder.build()
 }
 
@@ -445,7 +925,8 @@ fn apply_git_patches(upstream_dir: &Path) -> Result<()> {
 fn allow_privileged_access(kubeconfig: Option<&PathBuf>) -> Result<()> {
     crate::log_info!("Configuring OpenShift SCC for privileged access...");
 
-    let mut cmd = Command::new("oc");
+    let target = KubeTarget::new(kubeconfig.map(PathBuf::as_path));
+    let mut cmd = target.command("oc");
     cmd.args([
         "adm",
         "policy",
@@ -455,17 +936,13 @@ fn allow_privileged_access(kubeconfig: Option<&PathBuf>) -> Result<()> {
         "system:serviceaccounts",
     ]);
 
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
-
     let status = cmd.status().context("Failed to add privileged SCC")?;
 
     if !status.success() {
         return Err(anyhow::anyhow!("Failed to add privileged SCC"));
     }
 
-    let mut cmd = Command::new("oc");
+    let mut cmd = target.command("oc");
     cmd.args([
         "adm",
         "policy",
@@ -475,10 +952,6 @@ fn allow_privileged_access(kubeconfig: Option<&PathBuf>) -> Result<()> {
         "system:serviceaccounts",
     ]);
 
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
-
     let status = cmd.status().context("Failed to add anyuid SCC")?;
 
     if !status.success() {
@@ -489,6 +962,29 @@ fn allow_privileged_access(kubeconfig: Option<&PathBuf>) -> Result<()> {
     Ok(())
 }
 
+/// Create the report directory if needed and build ginkgo's report flags.
+/// Paths are made absolute since ginkgo runs from the source checkout.
+fn report_args(report_dir: &Path) -> Result<Vec<String>> {
+    std::fs::create_dir_all(report_dir).with_context(|| {
+        format!(
+            "Failed to create report directory: {}",
+            report_dir.display()
+        )
+    })?;
+    let dir = std::path::absolute(report_dir).with_context(|| {
+        format!(
+            "Failed to resolve report directory: {}",
+            report_dir.display()
+        )
+    })?;
+    crate::log_info!("Writing test reports to {}", dir.display());
+
+    Ok(vec![
+        format!("--junit-report={}", dir.join("junit.xml").display()),
+        format!("--json-report={}", dir.join("e2e.json").display()),
+    ])
+}
+
 /// Execute upstream ginkgo tests
 #[allow(clippy::too_many_arguments)]
 fn execute_upstream_ginkgo_tests(
@@ -499,12 +995,20 @@ fn execute_upstream_ginkgo_tests(
     skip_patterns: &[String],
     target: &str,
     kubeconfig: Option<&PathBuf>,
-    operator_installed: bool,
+    kueue_namespace: &str,
+    report_dir: Option<&Path>,
 ) -> Result<()> {
     crate::log_info!("Running upstream e2e tests...");
 
     let skip_pattern = generate_skip_pattern(skip_patterns);
 
+    log_test_selection(
+        focus.as_deref(),
+        label_filter.as_deref(),
+        skip_patterns,
+        &skip_pattern,
+    );
+
     let mut args = Vec::new();
     if !skip_pattern.is_empty() {
         args.push("--skip");
@@ -517,7 +1021,6 @@ fn execute_upstream_ginkgo_tests(
     // Add focus if provided
     let focus_arg;
     if let Some(ref pattern) = focus {
-        crate::log_info!("Running tests with focus: {}", pattern);
         args.push("--focus");
         focus_arg = pattern.clone();
         args.push(&focus_arg);
@@ -526,14 +1029,19 @@ fn execute_upstream_ginkgo_tests(
     // Add label filter if provided
     let label_filter_arg;
     if let Some(ref filter) = label_filter {
-        crate::log_info!("Running tests with label filter: {}", filter);
         label_filter_arg = format!("--label-filter={}", filter);
         args.push(&label_filter_arg);
     }
 
-    // Add output format
-    args.push("--junit-report=junit.xml");
-    args.push("--json-report=e2e.json");
+    // Add output format (relative to the upstream source unless --report-dir is set)
+    let report_flags = match report_dir {
+        Some(dir) => report_args(dir)?,
+        None => vec![
+            "--junit-report=junit.xml".to_string(),
+            "--json-report=e2e.json".to_string(),
+        ],
+    };
+    args.extend(report_flags.iter().map(String::as_str));
 
     // Add test path
     let test_path = format!("./test/e2e/{}/...", target);
@@ -546,11 +1054,7 @@ fn execute_upstream_ginkgo_tests(
         .current_dir(upstream_src_dir)
         .env("E2E_KIND_VERSION", ""); // Empty for OCP tests
 
-    // Only set KUEUE_NAMESPACE for operator deployments
-    // Upstream kueue uses kueue-system by default which is what the tests expect
-    if operator_installed {
-        cmd.env("KUEUE_NAMESPACE", "openshift-kueue-operator");
-    }
+    cmd.env("KUEUE_NAMESPACE", kueue_namespace);
 
     if let Some(kc) = kubeconfig {
         cmd.env("KUBECONFIG", kc);
@@ -571,52 +1075,59 @@ fn execute_upstream_ginkgo_tests(
     Ok(())
 }
 
-/// Check if running on a Kind cluster
-fn is_kind_cluster(kubeconfig: Option<&Path>) -> Result<bool> {
-    // Get the current context and check if it's a kind cluster
-    let output = kubectl::run_kubectl_output(&["config", "current-context"], kubeconfig)?;
-    let context = output.trim();
-    Ok(context.starts_with("kind-"))
-}
-
-/// Check if the kueue operator is installed (vs upstream kueue deployment)
-fn is_operator_installed(kubeconfig: Option<&Path>) -> bool {
+/// Check if the kueue operator is installed in `namespace` (vs upstream kueue deployment)
+fn is_operator_installed(namespace: &str, kubeconfig: Option<&Path>) -> bool {
     // Check if the operator namespace exists
     let ns_check = kubectl::run_kubectl_output(
-        &[
-            "get",
-            "namespace",
-            "openshift-kueue-operator",
-            "--ignore-not-found",
-        ],
-        kubeconfig,
+        &["get", "namespace", namespace, "--ignore-not-found"],
+        &KubeTarget::new(kubeconfig),
     );
 
     matches!(ns_check, Ok(output) if !output.trim().is_empty())
 }
 
 /// Scale down operator deployment to 0 replicas (if it exists)
-fn scale_down_operator(kubeconfig: Option<&Path>) -> Result<()> {
-    if !is_operator_installed(kubeconfig) {
+/// Returns the original replica count when the operator was scaled down.
+fn scale_down_operator(namespace: &str, kubeconfig: Option<&Path>) -> Result<Option<u32>> {
+    let target = KubeTarget::new(kubeconfig);
+    if !is_operator_installed(namespace, kubeconfig) {
         crate::log_info!(
-            "Operator namespace 'openshift-kueue-operator' not found - skipping scale down"
+            "Operator namespace '{}' not found - skipping scale down",
+            namespace
         );
         crate::log_info!(
             "(This is expected when using 'deploy upstream' instead of 'deploy operator')"
         );
-        return Ok(());
+        return Ok(None);
     }
 
-    crate::log_info!("Scaling down operator deployment to 0 replicas...");
+    let replicas_output = kubectl::run_kubectl_output(
+        &[
+            "get",
+            "deployment/openshift-kueue-operator",
+            "-n",
+            namespace,
+            "-o",
+            "jsonpath={.spec.replicas}",
+        ],
+        &target,
+    )
+    .context("Failed to get operator replica count")?;
+    let original_replicas = parse_replicas(&replicas_output);
+
+    crate::log_info!(
+        "Scaling down operator deployment to 0 replicas (was {})...",
+        original_replicas
+    );
     kubectl::run_kubectl(
         &[
             "scale",
             "deployment/openshift-kueue-operator",
             "--replicas=0",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
         ],
-        kubeconfig,
+        &target,
     )
     .context("Failed to scale down operator deployment")?;
 
@@ -630,51 +1141,225 @@ fn scale_down_operator(kubeconfig: Option<&Path>) -> Result<()> {
             "-l",
             "name=openshift-kueue-operator",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
             "--timeout=60s",
         ],
-        kubeconfig,
+        &target,
     )
     .context("Failed to wait for operator pods to terminate")?;
 
     crate::log_info!("Operator deployment scaled down successfully");
+    Ok(Some(original_replicas))
+}
+
+/// Parse `.spec.replicas`; an unset value means the Kubernetes default of 1
+fn parse_replicas(output: &str) -> u32 {
+    output.trim().parse().unwrap_or(1)
+}
+
+/// Scale the operator deployment back to its original replica count
+fn restore_operator_scale(replicas: u32, namespace: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    crate::log_info!(
+        "Restoring operator deployment to {} replica(s)...",
+        replicas
+    );
+    let replicas_arg = format!("--replicas={}", replicas);
+    kubectl::run_kubectl(
+        &[
+            "scale",
+            "deployment/openshift-kueue-operator",
+            &replicas_arg,
+            "-n",
+            namespace,
+        ],
+        &KubeTarget::new(kubeconfig),
+    )
+    .context("Failed to restore operator deployment replicas")?;
     Ok(())
 }
 
-/// Delete all NetworkPolicies in the cluster
-fn delete_network_policies(kubeconfig: Option<&Path>) -> Result<()> {
-    crate::log_info!("Deleting NetworkPolicies...");
+/// Scales the operator back to its original replica count when dropped,
+/// so it is restored even if the upstream tests fail part-way
+struct OperatorScaleGuard {
+    replicas: u32,
+    namespace: String,
+    kubeconfig: Option<PathBuf>,
+}
 
-    // Try to delete NetworkPolicies, but don't fail if there are none
-    match kubectl::run_kubectl(
-        &["delete", "networkpolicies", "--all", "--all-namespaces"],
-        kubeconfig,
-    ) {
-        Ok(_) => {
-            crate::log_info!("NetworkPolicies deleted successfully");
-            Ok(())
+impl Drop for OperatorScaleGuard {
+    fn drop(&mut self) {
+        if let Err(e) =
+            restore_operator_scale(self.replicas, &self.namespace, self.kubeconfig.as_deref())
+        {
+            crate::log_error!("{:#}", e);
+            crate::log_error!(
+                "{}",
+                operator_restore_command(self.replicas, &self.namespace)
+            );
         }
-        Err(e) => {
-            // Check if the error is because there are no NetworkPolicies
-            let err_msg = e.to_string();
-            if err_msg.contains("No resources found")
-                || err_msg.contains("the server doesn't have a resource type \"networkpolicies\"")
-            {
-                crate::log_info!("No NetworkPolicies found to delete");
-                Ok(())
-            } else {
-                Err(e)
+    }
+}
+
+/// Command that re-enables the operator after it was scaled down
+fn operator_restore_command(replicas: u32, namespace: &str) -> String {
+    format!(
+        "To re-enable the operator, run: kubectl scale deployment/openshift-kueue-operator --replicas={} -n {}",
+        replicas, namespace
+    )
+}
+
+/// Re-applies backed-up NetworkPolicies when dropped, so the cluster's
+/// policies survive a failed upstream test run
+struct NetworkPolicyRestoreGuard {
+    backup_dir: PathBuf,
+    kubeconfig: Option<PathBuf>,
+}
+
+impl Drop for NetworkPolicyRestoreGuard {
+    fn drop(&mut self) {
+        match netpol::restore(&self.backup_dir, self.kubeconfig.as_deref()) {
+            Ok(_) => {
+                let _ = std::fs::remove_dir_all(&self.backup_dir);
+            }
+            Err(e) => {
+                crate::log_error!("{:#}", e);
+                crate::log_error!(
+                    "To restore the NetworkPolicies, run: kueue-dev netpol restore {}",
+                    self.backup_dir.display()
+                );
             }
         }
     }
 }
 
-/// Run upstream kueue tests
+/// Directory NetworkPolicies are backed up to before `test upstream` deletes them
+fn network_policy_backup_dir() -> PathBuf {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+    std::env::temp_dir().join(format!("kueue-dev-netpol-{}", timestamp))
+}
+
+/// Delete NetworkPolicies, leaving those in `excluded_namespaces` untouched
+fn delete_network_policies(
+    kubeconfig: Option<&Path>,
+    excluded_namespaces: &[String],
+) -> Result<()> {
+    crate::log_info!(
+        "Deleting NetworkPolicies (excluding namespaces: {})...",
+        excluded_namespaces.join(", ")
+    );
+
+    match netpol::delete(kubeconfig, excluded_namespaces)? {
+        0 => crate::log_info!("No NetworkPolicies found to delete"),
+        count => crate::log_info!("Deleted {} NetworkPolicies", count),
+    }
+    Ok(())
+}
+
+/// Namespace of an upstream (helm/kustomize) kueue install, which the upstream suite expects
+const UPSTREAM_KUEUE_NAMESPACE: &str = "kueue-system";
+
+/// How kueue was installed on the cluster `test upstream` runs against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestFlavor {
+    /// kueue-operator manages kueue in `kueue.namespace`
+    Operator,
+    /// Plain upstream install in kueue-system
+    Upstream,
+}
+
+impl TestFlavor {
+    /// Namespace of kueue-controller-manager, passed to the suite as `KUEUE_NAMESPACE`
+    pub fn kueue_namespace(self, settings: &Settings) -> String {
+        match self {
+            TestFlavor::Operator => settings.kueue.namespace.clone(),
+            TestFlavor::Upstream => UPSTREAM_KUEUE_NAMESPACE.to_string(),
+        }
+    }
+}
+
+impl FromStr for TestFlavor {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "operator" => Ok(TestFlavor::Operator),
+            "upstream" => Ok(TestFlavor::Upstream),
+            _ => Err(anyhow::anyhow!(
+                "Invalid flavor: {}. Must be 'operator' or 'upstream'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for TestFlavor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TestFlavor::Operator => write!(f, "operator"),
+            TestFlavor::Upstream => write!(f, "upstream"),
+        }
+    }
+}
+
+/// Cluster setup steps `test upstream` performs on kind clusters
+#[derive(Debug, Clone, Copy)]
+pub struct UpstreamKindSetup {
+    /// Scale the operator to 0 so it does not reconcile over the upstream manifests
+    pub scale_down_operator: bool,
+    /// Scale the operator back up once the tests finish
+    pub restore_operator: bool,
+    /// Delete NetworkPolicies outside `tests.netpol_excluded_namespaces`,
+    /// which block the upstream suite's traffic.
+    /// They are backed up first and re-applied once the tests finish.
+    pub delete_network_policies: bool,
+}
+
+impl Default for UpstreamKindSetup {
+    fn default() -> Self {
+        Self {
+            scale_down_operator: true,
+            restore_operator: true,
+            delete_network_policies: true,
+        }
+    }
+}
+
+/// Ensure `test/e2e/<target>` exists, listing the available targets if it doesn't.
+/// Ginkgo only reports "no test files" for a missing package path.
+fn validate_test_target(upstream_src_dir: &Path, target: &str) -> Result<()> {
+    let e2e_dir = upstream_src_dir.join("test").join("e2e");
+    if e2e_dir.join(target).is_dir() {
+        return Ok(());
+    }
+
+    let mut targets: Vec<String> = std::fs::read_dir(&e2e_dir)
+        .with_context(|| format!("Failed to read {}", e2e_dir.display()))?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.path().is_dir())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .collect();
+    targets.sort();
+
+    Err(anyhow::anyhow!(
+        "Test target '{}' not found in {}. Valid targets: {}",
+        target,
+        e2e_dir.display(),
+        targets.join(", ")
+    ))
+}
+
+/// Run upstream kueue tests. `flavor` is detected from the cluster when not given.
 pub fn test_upstream(
     focus: Option<String>,
     label_filter: Option<String>,
     kubeconfig: Option<PathBuf>,
     target: String,
+    flavor: Option<TestFlavor>,
+    ginkgo: &GinkgoOptions,
+    kind_setup: UpstreamKindSetup,
 ) -> Result<()> {
     crate::log_info!("Running upstream kueue tests...");
 
@@ -696,11 +1381,31 @@ pub fn test_upstream(
         ));
     }
 
+    validate_test_target(&upstream_src_dir, &target)?;
+
+    // Load settings for NetworkPolicy exclusions, skip patterns and the operator namespace
+    let settings = Settings::load()?;
+    let operator_namespace = &settings.operator.namespace;
+
     // Check if the operator is installed (vs upstream kueue deployment)
-    let operator_installed = is_operator_installed(kubeconfig.as_deref());
+    let flavor = match flavor {
+        Some(flavor) => {
+            crate::log_info!("Testing against {} install (--flavor)", flavor);
+            flavor
+        }
+        None if is_operator_installed(operator_namespace, kubeconfig.as_deref()) => {
+            crate::log_info!("Detected operator deployment");
+            TestFlavor::Operator
+        }
+        None => {
+            crate::log_info!("Detected upstream kueue deployment");
+            TestFlavor::Upstream
+        }
+    };
+    let kueue_namespace = flavor.kueue_namespace(&settings);
 
-    if operator_installed {
-        crate::log_info!("Detected operator deployment - applying operator-specific setup");
+    if flavor == TestFlavor::Operator {
+        crate::log_info!("Applying operator-specific setup");
 
         // Apply patches (only needed for operator deployment)
         apply_git_patches(&upstream_dir)?;
@@ -708,32 +1413,73 @@ pub fn test_upstream(
         // Allow privileged access via OpenShift SCCs (only for operator deployment)
         allow_privileged_access(kubeconfig.as_ref())?;
     } else {
-        crate::log_info!("Detected upstream kueue deployment - skipping operator-specific setup");
+        crate::log_info!("Skipping operator-specific setup");
     }
 
     // Check if running on Kind and perform necessary setup
-    if is_kind_cluster(kubeconfig.as_deref())? {
+    let mut _restore_guard = None;
+    let mut _netpol_guard = None;
+    if kind::is_kind_context(kubeconfig.as_deref())? {
         crate::log_info!("Detected Kind cluster - performing setup for upstream tests");
 
         // Scale down the operator deployment (if present)
-        scale_down_operator(kubeconfig.as_deref())?;
+        if flavor == TestFlavor::Upstream {
+            crate::log_info!("Upstream install - no operator to scale down");
+        } else if kind_setup.scale_down_operator {
+            if let Some(replicas) = scale_down_operator(operator_namespace, kubeconfig.as_deref())?
+            {
+                if kind_setup.restore_operator {
+                    _restore_guard = Some(OperatorScaleGuard {
+                        replicas,
+                        namespace: operator_namespace.clone(),
+                        kubeconfig: kubeconfig.clone(),
+                    });
+                } else {
+                    crate::log_warn!("Operator will be left scaled down (--no-restore-operator)");
+                    crate::log_info!("{}", operator_restore_command(replicas, operator_namespace));
+                }
+            }
+        } else {
+            crate::log_info!("Leaving operator running (--no-scale-down-operator)");
+        }
 
         // Delete NetworkPolicies
-        delete_network_policies(kubeconfig.as_deref())?;
+        if kind_setup.delete_network_policies {
+            let backup_dir = network_policy_backup_dir();
+            let excluded_namespaces = &settings.tests.netpol_excluded_namespaces;
+            if netpol::backup(&backup_dir, kubeconfig.as_deref(), excluded_namespaces)? > 0 {
+                crate::log_info!(
+                    "NetworkPolicies will be restored from {} after the tests",
+                    backup_dir.display()
+                );
+                _netpol_guard = Some(NetworkPolicyRestoreGuard {
+                    backup_dir,
+                    kubeconfig: kubeconfig.clone(),
+                });
+            } else {
+                let _ = std::fs::remove_dir_all(&backup_dir);
+            }
+            delete_network_policies(kubeconfig.as_deref(), excluded_namespaces)?;
+        } else {
+            crate::log_info!("Keeping NetworkPolicies (--keep-network-policies)");
+        }
     }
 
     // Label worker nodes
     crate::log_info!("Labeling worker nodes for e2e tests...");
-    nodes::label_worker_nodes(kubeconfig.as_deref())?;
+    nodes::label_worker_nodes(
+        &KubeTarget::new(kubeconfig.as_deref()),
+        &ginkgo.worker_labels,
+    )?;
 
     // Ensure ginkgo is available
-    let ginkgo_bin = ensure_ginkgo()?;
+    let ginkgo_bin = ensure_ginkgo(ginkgo.ginkgo_from.as_deref())?;
 
-    // Load settings to get skip patterns
-    let settings = Settings::load()?;
-    let skip_patterns = &settings.tests.upstream_skip_patterns;
+    let skip_patterns = &ginkgo
+        .skip_overrides
+        .apply(settings.tests.effective_upstream_skip_patterns());
 
-    // Run tests
+    // Run tests; the operator is restored when the guard drops
     execute_upstream_ginkgo_tests(
         &ginkgo_bin,
         &upstream_src_dir,
@@ -742,16 +1488,47 @@ pub fn test_upstream(
         skip_patterns,
         &target,
         kubeconfig.as_ref(),
-        operator_installed,
-    )?;
-
-    Ok(())
+        &kueue_namespace,
+        ginkgo.report_dir.as_deref(),
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_flavor() {
+        let settings = Settings::default();
+        let operator: TestFlavor = "operator".parse().unwrap();
+        let upstream: TestFlavor = "Upstream".parse().unwrap();
+
+        assert_eq!(
+            operator.kueue_namespace(&settings),
+            "openshift-kueue-operator"
+        );
+        assert_eq!(upstream.kueue_namespace(&settings), "kueue-system");
+        assert!("helm".parse::<TestFlavor>().is_err());
+    }
+
+    #[test]
+    fn test_validate_test_target() {
+        let src = tempfile::tempdir().unwrap();
+        let e2e = src.path().join("test").join("e2e");
+        for dir in ["singlecluster", "multikueue", "certmanager"] {
+            std::fs::create_dir_all(e2e.join(dir)).unwrap();
+        }
+        std::fs::write(e2e.join("README.md"), "").unwrap();
+
+        assert!(validate_test_target(src.path(), "multikueue").is_ok());
+
+        let err = validate_test_target(src.path(), "singelcluster")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'singelcluster' not found"));
+        assert!(err.ends_with("Valid targets: certmanager, multikueue, singlecluster"));
+    }
+
     #[test]
     fn test_generate_skip_pattern() {
         let patterns = vec![
@@ -767,6 +1544,22 @@ mod tests {
         assert!(pattern.ends_with(')'));
     }
 
+    #[test]
+    fn test_parse_replicas() {
+        assert_eq!(parse_replicas("2"), 2);
+        assert_eq!(parse_replicas("0\n"), 0);
+        // Unset spec.replicas defaults to 1
+        assert_eq!(parse_replicas(""), 1);
+    }
+
+    #[test]
+    fn test_operator_restore_command() {
+        assert!(
+            operator_restore_command(2, "my-operator")
+                .ends_with("deployment/openshift-kueue-operator --replicas=2 -n my-operator")
+        );
+    }
+
     #[test]
     fn test_generate_skip_pattern_empty() {
         let patterns = vec![];
@@ -774,6 +1567,146 @@ mod tests {
         assert_eq!(pattern, "");
     }
 
+    #[test]
+    fn test_describe_test_selection() {
+        assert_eq!(
+            describe_test_selection(Some("Pod"), Some("!disruptive"), "(JobSet|JAX)"),
+            "Test selection: focus=Pod skip=(JobSet|JAX) label-filter=!disruptive"
+        );
+        assert_eq!(
+            describe_test_selection(None, None, ""),
+            "Test selection: focus=(none) skip=(none) label-filter=(none)"
+        );
+    }
+
+    #[test]
+    fn test_skip_patterns_covering_focus() {
+        let skips = vec![
+            "JobSet".to_string(),
+            "Kueue visibility server".to_string(),
+            "Metrics".to_string(),
+        ];
+        assert_eq!(
+            skip_patterns_covering_focus("JobSet", &skips),
+            vec!["JobSet"]
+        );
+        assert_eq!(
+            skip_patterns_covering_focus("when JobSet is created", &skips),
+            vec!["JobSet"]
+        );
+        assert!(skip_patterns_covering_focus("Pod groups", &skips).is_empty());
+        assert!(skip_patterns_covering_focus("Kueue", &skips).is_empty());
+    }
+
+    #[test]
+    fn test_skip_patterns_covering_focus_invalid_regex() {
+        let skips = vec!["Job(".to_string()];
+        assert_eq!(
+            skip_patterns_covering_focus("Job(set)", &skips),
+            vec!["Job("]
+        );
+        assert!(skip_patterns_covering_focus("Pod", &skips).is_empty());
+    }
+
+    #[test]
+    fn test_skip_overrides_add_and_remove() {
+        let base = vec!["AppWrapper".to_string(), "JobSet".to_string()];
+        let overrides = SkipOverrides {
+            add: vec!["Flaky".to_string(), "AppWrapper".to_string()],
+            remove: vec!["JobSet".to_string()],
+        };
+        assert_eq!(overrides.apply(base), vec!["AppWrapper", "Flaky"]);
+    }
+
+    #[test]
+    fn test_skip_overrides_remove_wins_over_add() {
+        let overrides = SkipOverrides {
+            add: vec!["JobSet".to_string()],
+            remove: vec!["JobSet".to_string(), "Missing".to_string()],
+        };
+        assert_eq!(
+            overrides.apply(vec!["JobSet".to_string()]),
+            Vec::<String>::new()
+        );
+        assert_eq!(
+            SkipOverrides::default().apply(vec!["JobSet".to_string()]),
+            vec!["JobSet"]
+        );
+    }
+
+    #[test]
+    fn test_operator_ginkgo_args_defaults() {
+        let args = operator_ginkgo_args(None, None, &[], &[], None);
+        assert_eq!(
+            args,
+            vec!["--label-filter=!disruptive", "-v", "./test/e2e/..."]
+        );
+    }
+
+    #[test]
+    fn test_operator_ginkgo_args_full_selection() {
+        let skips = vec!["JobSet".to_string(), "JAX".to_string()];
+        let args = operator_ginkgo_args(Some("Pod"), Some("network-policy"), &skips, &[], None);
+        assert_eq!(
+            args,
+            vec![
+                "--label-filter=network-policy",
+                "-v",
+                "--skip",
+                "(JobSet|JAX)",
+                "--focus",
+                "Pod",
+                "./test/e2e/...",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_operator_ginkgo_args_custom_test_path() {
+        let args = operator_ginkgo_args(None, None, &[], &[], Some("./test/e2e/singlecluster/..."));
+        assert_eq!(args.last().unwrap(), "./test/e2e/singlecluster/...");
+    }
+
+    #[test]
+    fn test_report_args_creates_missing_dir() {
+        let base = tempfile::tempdir().unwrap();
+        let dir = base.path().join("reports/nested");
+        let flags = report_args(&dir).unwrap();
+
+        assert!(dir.is_dir());
+        assert_eq!(
+            flags,
+            vec![
+                format!("--junit-report={}", dir.join("junit.xml").display()),
+                format!("--json-report={}", dir.join("e2e.json").display()),
+            ]
+        );
+
+        // Report flags go before the test path
+        let args = operator_ginkgo_args(None, None, &[], &flags, None);
+        assert_eq!(&args[2..4], flags.as_slice());
+        assert_eq!(args.last().unwrap(), "./test/e2e/...");
+    }
+
+    #[test]
+    fn test_parse_ginkgo_version() {
+        assert_eq!(
+            parse_ginkgo_version("Ginkgo Version 2.1.4\n"),
+            Some("2.1.4")
+        );
+        assert_eq!(
+            parse_ginkgo_version("Ginkgo Version v2.23.0"),
+            Some("2.23.0")
+        );
+        assert_eq!(parse_ginkgo_version("command not found"), None);
+        assert_eq!(parse_ginkgo_version(""), None);
+    }
+
+    #[test]
+    fn test_ensure_ginkgo_rejects_missing_shared_binary() {
+        assert_eq!(ginkgo_version(Path::new("/nonexistent/ginkgo")), None);
+    }
+
     #[test]
     fn test_test_module() {
         // Basic compile test
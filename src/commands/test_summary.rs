@@ -0,0 +1,224 @@
+//! Aggregate ginkgo JSON reports across repeated test runs
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::Duration;
+
+/// Outcome of a single spec in one run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecResult {
+    /// Full spec text: container hierarchy followed by the leaf node text
+    pub name: String,
+    pub passed: bool,
+}
+
+/// Specs executed by one ginkgo run, parsed from its `--json-report`
+#[derive(Debug, Clone, Default)]
+pub struct TestRunResult {
+    pub specs: Vec<SpecResult>,
+    pub duration: Duration,
+}
+
+impl TestRunResult {
+    /// Parse a ginkgo JSON report file
+    pub fn from_report_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read ginkgo report: {}", path.display()))?;
+        Self::from_report(&content)
+            .with_context(|| format!("Failed to parse ginkgo report: {}", path.display()))
+    }
+
+    /// Parse the contents of a ginkgo JSON report (an array of suite reports).
+    /// Only `It` specs that ran are recorded; skipped and pending specs are ignored.
+    pub fn from_report(content: &str) -> Result<Self> {
+        let suites: Vec<Value> = serde_json::from_str(content)?;
+
+        let mut result = Self::default();
+        for suite in &suites {
+            result.duration += nanos(&suite["RunTime"]);
+
+            let reports = suite["SpecReports"].as_array().map(Vec::as_slice);
+            for spec in reports.unwrap_or_default() {
+                if spec["LeafNodeType"].as_str() != Some("It") {
+                    continue;
+                }
+
+                let state = spec["State"].as_str().unwrap_or_default();
+                if matches!(state, "skipped" | "pending") {
+                    continue;
+                }
+
+                result.specs.push(SpecResult {
+                    name: spec_name(spec),
+                    passed: state == "passed",
+                });
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Ginkgo reports durations as integer nanoseconds
+fn nanos(value: &Value) -> Duration {
+    Duration::from_nanos(value.as_u64().unwrap_or_default())
+}
+
+fn spec_name(spec: &Value) -> String {
+    let containers = spec["ContainerHierarchyTexts"]
+        .as_array()
+        .map(Vec::as_slice);
+    containers
+        .unwrap_or_default()
+        .iter()
+        .chain(std::iter::once(&spec["LeafNodeText"]))
+        .filter_map(Value::as_str)
+        .filter(|text| !text.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Pass/fail counts for one spec across iterations
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SpecCounts {
+    pub passed: u32,
+    pub failed: u32,
+}
+
+/// Accumulated results of all iterations, written by `--summary-json`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TestSummary {
+    pub iterations: u32,
+    pub failed_iterations: u32,
+    pub total_duration_seconds: f64,
+    /// Counts keyed by spec name
+    pub specs: BTreeMap<String, SpecCounts>,
+    /// Specs that both passed and failed across iterations
+    pub flaky: Vec<String>,
+}
+
+impl TestSummary {
+    /// Add one iteration. `run` is `None` when the iteration produced no report.
+    pub fn record(&mut self, run: Option<&TestRunResult>, succeeded: bool, elapsed: Duration) {
+        self.iterations += 1;
+        if !succeeded {
+            self.failed_iterations += 1;
+        }
+
+        // Prefer ginkgo's own timing; fall back to wall-clock time
+        let duration = run.map(|r| r.duration).filter(|d| !d.is_zero());
+        self.total_duration_seconds += duration.unwrap_or(elapsed).as_secs_f64();
+
+        for spec in run.map(|r| r.specs.as_slice()).unwrap_or_default() {
+            let counts = self.specs.entry(spec.name.clone()).or_default();
+            if spec.passed {
+                counts.passed += 1;
+            } else {
+                counts.failed += 1;
+            }
+        }
+
+        self.flaky = self
+            .specs
+            .iter()
+            .filter(|(_, counts)| counts.passed > 0 && counts.failed > 0)
+            .map(|(name, _)| name.clone())
+            .collect();
+    }
+
+    /// Write the summary as pretty-printed JSON
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n")
+            .with_context(|| format!("Failed to write summary: {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REPORT: &str = r#"[
+        {
+            "SuiteDescription": "E2E Suite",
+            "RunTime": 2500000000,
+            "SpecReports": [
+                {
+                    "LeafNodeType": "SynchronizedBeforeSuite",
+                    "LeafNodeText": "",
+                    "ContainerHierarchyTexts": null,
+                    "State": "passed"
+                },
+                {
+                    "LeafNodeType": "It",
+                    "LeafNodeText": "should admit the job",
+                    "ContainerHierarchyTexts": ["Kueue", "when a Job is created"],
+                    "State": "passed"
+                },
+                {
+                    "LeafNodeType": "It",
+                    "LeafNodeText": "should preempt",
+                    "ContainerHierarchyTexts": ["Kueue"],
+                    "State": "failed"
+                },
+                {
+                    "LeafNodeType": "It",
+                    "LeafNodeText": "should be skipped",
+                    "ContainerHierarchyTexts": ["Kueue"],
+                    "State": "skipped"
+                }
+            ]
+        }
+    ]"#;
+
+    #[test]
+    fn test_parse_report() {
+        let run = TestRunResult::from_report(REPORT).unwrap();
+        assert_eq!(run.duration, Duration::from_millis(2500));
+        assert_eq!(
+            run.specs,
+            vec![
+                SpecResult {
+                    name: "Kueue when a Job is created should admit the job".to_string(),
+                    passed: true,
+                },
+                SpecResult {
+                    name: "Kueue should preempt".to_string(),
+                    passed: false,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summary_tracks_flaky_specs() {
+        let failing = TestRunResult::from_report(REPORT).unwrap();
+        let mut passing = failing.clone();
+        passing.specs[1].passed = true;
+
+        let mut summary = TestSummary::default();
+        summary.record(Some(&failing), false, Duration::from_secs(10));
+        summary.record(Some(&passing), true, Duration::from_secs(10));
+        summary.record(None, false, Duration::from_secs(1));
+
+        assert_eq!(summary.iterations, 3);
+        assert_eq!(summary.failed_iterations, 2);
+        assert_eq!(summary.total_duration_seconds, 6.0);
+        assert_eq!(
+            summary.specs["Kueue should preempt"],
+            SpecCounts {
+                passed: 1,
+                failed: 1
+            }
+        );
+        assert_eq!(summary.flaky, vec!["Kueue should preempt"]);
+    }
+}
@@ -0,0 +1,246 @@
+//! Dependency versions tested with each kueue release
+
+use anyhow::{Result, bail};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::k8s::version::parse_semver;
+
+/// Built-in matrix, used when the config file has no `[[compatibility]]` entries
+const DEFAULT_MATRIX: &str = r#"
+[[compatibility]]
+kueue = "v0.13"
+cert_manager = { min = "v1.16.0", before = "v1.19.0" }
+jobset = { min = "v0.8.0", before = "v0.11.0" }
+leaderworkerset = { min = "v0.6.0", before = "v0.8.0" }
+appwrapper = { min = "v1.1.0", before = "v1.2.0" }
+training_operator = { min = "v1.8.0", before = "v1.10.0" }
+
+[[compatibility]]
+kueue = "v0.14"
+cert_manager = { min = "v1.17.0", before = "v1.20.0" }
+jobset = { min = "v0.10.0", before = "v0.11.0" }
+leaderworkerset = { min = "v0.7.0", before = "v0.8.0" }
+appwrapper = { min = "v1.1.0", before = "v1.2.0" }
+training_operator = { min = "v1.8.0", before = "v1.10.0" }
+"#;
+
+/// Tested dependency ranges for one kueue minor release
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct CompatibilityEntry {
+    /// Kueue minor release, e.g. `v0.14`
+    pub kueue: String,
+    /// Tested range per dependency, keyed like the `[versions]` settings
+    #[serde(flatten)]
+    pub dependencies: BTreeMap<String, VersionRange>,
+}
+
+/// Versions from `min` (inclusive) up to `before` (exclusive)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct VersionRange {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+}
+
+impl VersionRange {
+    /// Whether `version` is in range; None if any of the versions cannot be parsed
+    pub fn contains(&self, version: &str) -> Option<bool> {
+        let version = parse_semver(version)?;
+        if let Some(min) = &self.min
+            && version < parse_semver(min)?
+        {
+            return Some(false);
+        }
+        if let Some(before) = &self.before
+            && version >= parse_semver(before)?
+        {
+            return Some(false);
+        }
+        Some(true)
+    }
+}
+
+impl std::fmt::Display for VersionRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.min, &self.before) {
+            (Some(min), Some(before)) => write!(f, ">= {}, < {}", min, before),
+            (Some(min), None) => write!(f, ">= {}", min),
+            (None, Some(before)) => write!(f, "< {}", before),
+            (None, None) => write!(f, "any"),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Matrix {
+    compatibility: Vec<CompatibilityEntry>,
+}
+
+/// The built-in compatibility matrix
+pub fn default_matrix() -> Vec<CompatibilityEntry> {
+    toml::from_str::<Matrix>(DEFAULT_MATRIX)
+        .expect("built-in compatibility matrix is valid")
+        .compatibility
+}
+
+/// Entry for the minor release of `kueue_version`, if the matrix has one
+pub fn entry_for<'a>(
+    matrix: &'a [CompatibilityEntry],
+    kueue_version: &str,
+) -> Option<&'a CompatibilityEntry> {
+    let (major, minor, _) = parse_semver(kueue_version)?;
+    matrix
+        .iter()
+        .find(|entry| parse_semver(&entry.kueue).is_some_and(|(m, n, _)| (m, n) == (major, minor)))
+}
+
+/// Check `(dependency, version)` pairs against the ranges tested with
+/// `kueue_version`. Out-of-range versions are warnings, or an error with `strict`.
+/// With `strict`, versions that cannot be checked at all (unknown kueue version
+/// or no matrix entry for it) are an error too.
+pub fn validate(
+    matrix: &[CompatibilityEntry],
+    kueue_version: Option<&str>,
+    versions: &[(&str, &str)],
+    strict: bool,
+) -> Result<()> {
+    if versions.is_empty() {
+        return Ok(());
+    }
+    let Some(kueue_version) = kueue_version else {
+        if strict {
+            bail!(
+                "Could not determine the kueue version, so dependency versions cannot be checked (--strict-versions)"
+            );
+        }
+        crate::log_info!(
+            "Could not determine the kueue version; skipping dependency version checks"
+        );
+        return Ok(());
+    };
+    let Some(entry) = entry_for(matrix, kueue_version) else {
+        if strict {
+            bail!(
+                "No compatibility data for kueue {}, so dependency versions cannot be checked (--strict-versions)",
+                kueue_version
+            );
+        }
+        crate::log_info!(
+            "No compatibility data for kueue {}; skipping dependency version checks",
+            kueue_version
+        );
+        return Ok(());
+    };
+
+    let problems: Vec<String> = versions
+        .iter()
+        .filter_map(|(dependency, version)| {
+            let range = entry.dependencies.get(*dependency)?;
+            match range.contains(version) {
+                Some(true) => None,
+                Some(false) => Some(format!(
+                    "{} {} is outside the range tested with kueue {} ({})",
+                    dependency, version, entry.kueue, range
+                )),
+                None => Some(format!(
+                    "{} {} cannot be compared with the range tested with kueue {} ({})",
+                    dependency, version, entry.kueue, range
+                )),
+            }
+        })
+        .collect();
+
+    if problems.is_empty() {
+        return Ok(());
+    }
+    if strict {
+        bail!(
+            "Untested dependency versions (--strict-versions):\n  {}",
+            problems.join("\n  ")
+        );
+    }
+    for problem in &problems {
+        crate::log_warn!("{}", problem);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matrix_covers_default_versions() {
+        let matrix = default_matrix();
+        let versions = crate::config::settings::Versions::default();
+        let entry = entry_for(&matrix, "v0.14.2-rc.0").unwrap();
+
+        for (dependency, version) in [
+            ("cert_manager", &versions.cert_manager),
+            ("jobset", &versions.jobset),
+            ("leaderworkerset", &versions.leaderworkerset),
+            ("appwrapper", &versions.appwrapper),
+            ("training_operator", &versions.training_operator),
+        ] {
+            assert_eq!(
+                entry.dependencies[dependency].contains(version),
+                Some(true),
+                "{} {}",
+                dependency,
+                version
+            );
+        }
+        assert!(entry_for(&matrix, "v0.9.0").is_none());
+    }
+
+    #[test]
+    fn test_validate() {
+        let matrix = default_matrix();
+
+        assert!(validate(&matrix, Some("v0.14.0"), &[("jobset", "v0.10.1")], true).is_ok());
+        assert!(validate(&matrix, Some("v0.14.0"), &[("jobset", "v0.9.0")], false).is_ok());
+        let err = validate(&matrix, Some("v0.14.0"), &[("jobset", "v0.9.0")], true).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("jobset v0.9.0 is outside the range")
+        );
+        assert!(validate(&matrix, Some("v0.14.0"), &[("jobset", "latest")], true).is_err());
+
+        // Unknown kueue versions are only skipped without strict; dependencies
+        // without a range are not checked
+        assert!(validate(&matrix, None, &[("jobset", "v0.1.0")], false).is_ok());
+        assert!(validate(&matrix, Some("v0.9.0"), &[("jobset", "v0.1.0")], false).is_ok());
+        assert!(validate(&matrix, Some("v0.14.0"), &[("calico", "v1.0.0")], true).is_ok());
+        assert!(validate(&matrix, None, &[], true).is_ok());
+    }
+
+    #[test]
+    fn test_validate_strict_unknown_kueue_version() {
+        let matrix = default_matrix();
+
+        let err = validate(&matrix, None, &[("jobset", "v0.10.1")], true).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Could not determine the kueue version")
+        );
+
+        let err = validate(&matrix, Some("v0.9.0"), &[("jobset", "v0.10.1")], true).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("No compatibility data for kueue v0.9.0")
+        );
+    }
+
+    #[test]
+    fn test_version_range_display() {
+        let range = VersionRange {
+            min: Some("v1.0.0".to_string()),
+            before: None,
+        };
+        assert_eq!(range.to_string(), ">= v1.0.0");
+        assert_eq!(range.contains("v0.9.9"), Some(false));
+        assert_eq!(range.contains("v2.0.0"), Some(true));
+    }
+}
@@ -0,0 +1,226 @@
+//! Read and update individual settings addressed by a dotted key path
+
+use anyhow::{Context, Result, anyhow, bail};
+use toml_edit::{DocumentMut, Item, Value};
+
+use super::settings::Settings;
+
+/// Effective value of `key` (e.g. `versions.cert_manager`), including defaults.
+/// Strings are returned unquoted; other values in TOML syntax.
+pub fn get_value(settings: &Settings, key: &str) -> Result<String> {
+    let value = lookup(&settings_table(settings)?, key)
+        .ok_or_else(|| anyhow!("Unknown or unset setting: {}", key))?
+        .clone();
+
+    Ok(match value {
+        toml::Value::String(s) => s,
+        toml::Value::Table(table) => toml::to_string_pretty(&table)?.trim_end().to_string(),
+        other => other.to_string(),
+    })
+}
+
+/// Set `key` to `raw` in the TOML document `contents` and return the updated
+/// document. Comments and formatting of everything else are preserved.
+///
+/// `raw` is parsed as a TOML value (`true`, `30`, `["a", "b"]`) unless the
+/// setting is a string, in which case it is stored as-is. The result must
+/// still deserialize into [`Settings`].
+pub fn set_value(contents: &str, key: &str, raw: &str) -> Result<String> {
+    let segments = key_segments(key)?;
+    let mut doc: DocumentMut = contents.parse().context("Failed to parse config file")?;
+
+    let defaults = settings_table(&Settings::default())?;
+    let new_value = match lookup(&defaults, key) {
+        Some(toml::Value::String(_)) => Value::from(raw),
+        Some(toml::Value::Table(_)) => bail!("{} is a section; set one of its keys instead", key),
+        _ => raw.parse::<Value>().unwrap_or_else(|_| Value::from(raw)),
+    };
+
+    let (last, parents) = segments.split_last().expect("key has segments");
+    let mut table = doc.as_table_mut() as &mut dyn toml_edit::TableLike;
+    for segment in parents {
+        table = table
+            .entry(segment)
+            .or_insert(toml_edit::table())
+            .as_table_like_mut()
+            .ok_or_else(|| anyhow!("{} is not a section", segment))?;
+    }
+
+    match table.get_mut(last).and_then(Item::as_value_mut) {
+        Some(existing) => replace_value(existing, new_value),
+        None => {
+            table.insert(last, Item::Value(new_value));
+        }
+    }
+
+    let updated = doc.to_string();
+    let settings: Settings =
+        toml::from_str(&updated).with_context(|| format!("Invalid value for {}: {}", key, raw))?;
+    if lookup(&settings_table(&settings)?, key).is_none() {
+        bail!("Unknown setting: {}", key);
+    }
+
+    Ok(updated)
+}
+
+/// Write `settings` into the TOML document `contents`, rewriting only the
+/// values that changed. Comments, key order and whitespace are preserved, and
+/// keys the file has but `settings` doesn't serialize (unset optional
+/// settings, unknown keys) are left alone.
+pub fn merge_settings(contents: &str, settings: &Settings) -> Result<String> {
+    let mut doc: DocumentMut = contents.parse().context("Failed to parse config file")?;
+    let serialized = toml::to_string_pretty(settings).context("Failed to serialize settings")?;
+    let new: DocumentMut = serialized
+        .parse()
+        .context("Failed to parse serialized settings")?;
+
+    merge_table(doc.as_table_mut(), new.as_table());
+    Ok(doc.to_string())
+}
+
+fn merge_table(target: &mut dyn toml_edit::TableLike, source: &dyn toml_edit::TableLike) {
+    for (key, item) in source.iter() {
+        match (target.get_mut(key), item) {
+            (Some(existing), _) if existing.is_table_like() && item.is_table_like() => {
+                let existing = existing.as_table_like_mut().expect("checked table-like");
+                merge_table(existing, item.as_table_like().expect("checked table-like"));
+            }
+            (Some(Item::Value(existing)), Item::Value(new)) => {
+                if !same_value(existing, new) {
+                    replace_value(existing, new.clone());
+                }
+            }
+            (Some(existing), _) => *existing = item.clone(),
+            (None, _) => {
+                target.insert(key, item.clone());
+            }
+        }
+    }
+}
+
+/// Compare values semantically, ignoring quoting style and decoration
+fn same_value(a: &Value, b: &Value) -> bool {
+    let parse = |v: &Value| {
+        let mut v = v.clone();
+        v.decor_mut().clear();
+        v.to_string().parse::<toml::Value>().ok()
+    };
+    matches!((parse(a), parse(b)), (Some(a), Some(b)) if a == b)
+}
+
+/// Replace a value, keeping its surrounding whitespace and trailing comment
+fn replace_value(existing: &mut Value, new_value: Value) {
+    let decor = existing.decor().clone();
+    *existing = new_value;
+    *existing.decor_mut() = decor;
+}
+
+fn key_segments(key: &str) -> Result<Vec<&str>> {
+    let segments: Vec<&str> = key.split('.').collect();
+    if segments.iter().any(|s| s.trim().is_empty()) {
+        bail!("Invalid setting key: {:?}", key);
+    }
+    Ok(segments)
+}
+
+fn settings_table(settings: &Settings) -> Result<toml::Value> {
+    toml::Value::try_from(settings).context("Failed to serialize settings")
+}
+
+fn lookup<'a>(table: &'a toml::Value, key: &str) -> Option<&'a toml::Value> {
+    key.split('.')
+        .try_fold(table, |value, segment| value.get(segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"# kueue-dev configuration
+
+[versions]
+cert_manager = "v1.18.0" # pinned for CI
+
+[behavior]
+show_progress = true
+"#;
+
+    #[test]
+    fn test_set_value_preserves_comments() {
+        let updated = set_value(CONFIG, "versions.cert_manager", "v1.17.0").unwrap();
+        assert_eq!(
+            updated,
+            CONFIG.replace("\"v1.18.0\" # pinned", "\"v1.17.0\" # pinned")
+        );
+    }
+
+    #[test]
+    fn test_set_value_typed_and_new_sections() {
+        let updated = set_value(CONFIG, "behavior.show_progress", "false").unwrap();
+        assert!(updated.contains("show_progress = false"));
+
+        let updated = set_value("", "behavior.operator_ready_timeout", "300").unwrap();
+        let settings: Settings = toml::from_str(&updated).unwrap();
+        assert_eq!(settings.behavior.operator_ready_timeout, 300);
+
+        // String settings keep the raw text even when it looks like another type
+        let updated = set_value("", "defaults.cluster_name", "true").unwrap();
+        assert!(updated.contains(r#"cluster_name = "true""#));
+    }
+
+    #[test]
+    fn test_set_value_rejects_bad_input() {
+        assert!(set_value(CONFIG, "behavior.show_progress", "maybe").is_err());
+        assert!(set_value(CONFIG, "behavior.no_such_key", "1").is_err());
+        assert!(set_value(CONFIG, "versions", "v1").is_err());
+        assert!(set_value(CONFIG, "versions..calico", "v1").is_err());
+    }
+
+    #[test]
+    fn test_merge_settings_rewrites_only_changed_values() {
+        let contents = r#"# kueue-dev configuration
+
+[versions]
+# keep in sync with CI
+cert_manager = 'v1.18.0' # pinned
+calico = "v3.28.2"
+
+[custom]
+note = "not a kueue-dev setting"
+"#;
+        let mut settings: Settings = toml::from_str(contents).unwrap();
+        settings.versions.calico = "v3.29.0".to_string();
+
+        let merged = merge_settings(contents, &settings).unwrap();
+        assert!(merged.starts_with(
+            "# kueue-dev configuration\n\n[versions]\n# keep in sync with CI\n\
+             cert_manager = 'v1.18.0' # pinned\ncalico = \"v3.29.0\"\n"
+        ));
+        assert!(merged.contains("[custom]\nnote = \"not a kueue-dev setting\"\n"));
+
+        let reloaded: Settings = toml::from_str(&merged).unwrap();
+        assert_eq!(reloaded.versions.calico, "v3.29.0");
+        assert_eq!(
+            reloaded.behavior.show_progress,
+            settings.behavior.show_progress
+        );
+
+        let fresh = merge_settings("", &settings).unwrap();
+        let reloaded: Settings = toml::from_str(&fresh).unwrap();
+        assert_eq!(reloaded.versions.calico, "v3.29.0");
+    }
+
+    #[test]
+    fn test_get_value() {
+        let settings: Settings = toml::from_str(CONFIG).unwrap();
+        assert_eq!(
+            get_value(&settings, "versions.cert_manager").unwrap(),
+            "v1.18.0"
+        );
+        assert_eq!(
+            get_value(&settings, "behavior.show_progress").unwrap(),
+            "true"
+        );
+        assert!(get_value(&settings, "versions.nope").is_err());
+    }
+}
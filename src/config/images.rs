@@ -1,4 +1,4 @@
-//! Image configuration parsing from related_images.json
+//! Image configuration parsing from related_images.json (or .yaml)
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
@@ -17,20 +17,33 @@ pub struct ImageConfig {
 }
 
 impl ImageConfig {
-    /// Load image configuration from JSON file
+    /// Load image configuration from a JSON or YAML file, chosen by extension
+    /// (`.json`, `.yaml`/`.yml`). Other extensions are tried as JSON, then YAML.
     pub fn load(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path)
             .with_context(|| format!("Failed to read image config file: {}", path.display()))?;
 
-        let images: Vec<RelatedImage> = serde_json::from_str(&content)
-            .with_context(|| format!("Failed to parse image config: {}", path.display()))?;
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase);
+        let images = match extension.as_deref() {
+            Some("json") => parse_json(&content),
+            Some("yaml" | "yml") => parse_yaml(&content),
+            _ => parse_json(&content).or_else(|_| parse_yaml(&content)),
+        }
+        .with_context(|| format!("Failed to parse image config: {}", path.display()))?;
+
+        Ok(Self::from_related_images(images))
+    }
 
+    fn from_related_images(images: Vec<RelatedImage>) -> Self {
         let mut image_map = HashMap::new();
         for img in images {
             image_map.insert(img.name.clone(), img.image);
         }
 
-        Ok(Self { images: image_map })
+        Self { images: image_map }
     }
 
     /// Get image URL by name
@@ -68,6 +81,120 @@ impl ImageConfig {
             .map(|(k, v)| (k.as_str(), v.as_str()))
             .collect()
     }
+
+    /// Copy of this configuration with `-<suffix>` appended to every image tag
+    pub fn with_tag_suffix(&self, suffix: &str) -> Result<Self> {
+        let images = self
+            .images
+            .iter()
+            .map(|(name, image)| Ok((name.clone(), suffixed_image(image, suffix)?)))
+            .collect::<Result<_>>()?;
+        Ok(Self { images })
+    }
+}
+
+/// Difference for one image name between two configurations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageChange {
+    Added {
+        name: String,
+        image: String,
+    },
+    Removed {
+        name: String,
+        image: String,
+    },
+    Changed {
+        name: String,
+        old: String,
+        new: String,
+    },
+}
+
+/// Compare two image configurations, returning the changes sorted by image name
+pub fn diff(old: &ImageConfig, new: &ImageConfig) -> Vec<ImageChange> {
+    let mut names: Vec<&String> = old.images.keys().chain(new.images.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    names
+        .into_iter()
+        .filter_map(|name| match (old.images.get(name), new.images.get(name)) {
+            (None, Some(image)) => Some(ImageChange::Added {
+                name: name.clone(),
+                image: image.clone(),
+            }),
+            (Some(image), None) => Some(ImageChange::Removed {
+                name: name.clone(),
+                image: image.clone(),
+            }),
+            (Some(old), Some(new)) if old != new => Some(ImageChange::Changed {
+                name: name.clone(),
+                old: old.clone(),
+                new: new.clone(),
+            }),
+            _ => None,
+        })
+        .collect()
+}
+
+fn parse_json(content: &str) -> Result<Vec<RelatedImage>> {
+    Ok(serde_json::from_str(content)?)
+}
+
+fn parse_yaml(content: &str) -> Result<Vec<RelatedImage>> {
+    Ok(serde_yaml::from_str(content)?)
+}
+
+/// Append `-<suffix>` to an image's tag (`repo:dev` -> `repo:dev-<suffix>`).
+/// Untagged images get the suffix as their tag.
+fn suffixed_image(image: &str, suffix: &str) -> Result<String> {
+    let suffix = suffix.trim_start_matches('-');
+    if suffix.is_empty()
+        || !suffix
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    {
+        return Err(anyhow::anyhow!(
+            "Invalid tag suffix '{}': use only letters, digits, '_', '.' and '-'",
+            suffix
+        ));
+    }
+    if image.contains('@') {
+        return Err(anyhow::anyhow!(
+            "Cannot add a tag suffix to digest reference {}",
+            image
+        ));
+    }
+
+    // A ':' after the last '/' separates the tag; earlier ones belong to a registry port
+    let name_start = image.rfind('/').map_or(0, |i| i + 1);
+    let result = if image[name_start..].contains(':') {
+        format!("{}-{}", image, suffix)
+    } else {
+        format!("{}:{}", image, suffix)
+    };
+
+    let tag_len = result.len() - result.rfind(':').map_or(0, |i| i + 1);
+    if tag_len > 128 {
+        return Err(anyhow::anyhow!(
+            "Tag for {} would exceed 128 characters",
+            result
+        ));
+    }
+
+    Ok(result)
+}
+
+/// Repository of an image reference, without tag or digest
+/// (`localhost:5000/kueue:dev` -> `localhost:5000/kueue`)
+pub fn repository(image: &str) -> &str {
+    let image = image.split('@').next().unwrap_or(image);
+    let name_start = image.rfind('/').map_or(0, |i| i + 1);
+    match image[name_start..].rfind(':') {
+        Some(i) => &image[..name_start + i],
+        None => image,
+    }
 }
 
 #[cfg(test)]
@@ -75,6 +202,20 @@ mod tests {
     use super::*;
     use std::io::Write;
 
+    #[test]
+    fn test_repository() {
+        assert_eq!(repository("quay.io/org/kueue:dev"), "quay.io/org/kueue");
+        assert_eq!(
+            repository("localhost:5000/kueue:dev"),
+            "localhost:5000/kueue"
+        );
+        assert_eq!(repository("localhost:5000/kueue"), "localhost:5000/kueue");
+        assert_eq!(
+            repository("quay.io/org/kueue@sha256:abc"),
+            "quay.io/org/kueue"
+        );
+    }
+
     #[test]
     fn test_parse_image_config() {
         let json = r#"[
@@ -99,6 +240,97 @@ mod tests {
         assert_eq!(config.operand().unwrap(), "quay.io/example/operand:latest");
     }
 
+    fn sample_images() -> Vec<RelatedImage> {
+        ["operator", "operand", "must-gather", "bundle"]
+            .iter()
+            .map(|name| RelatedImage {
+                name: name.to_string(),
+                image: format!("quay.io/example/{}:dev", name),
+            })
+            .collect()
+    }
+
+    fn assert_sample_config(config: &ImageConfig) {
+        assert_eq!(config.operator().unwrap(), "quay.io/example/operator:dev");
+        assert_eq!(config.operand().unwrap(), "quay.io/example/operand:dev");
+        assert_eq!(
+            config.must_gather().unwrap(),
+            "quay.io/example/must-gather:dev"
+        );
+        assert_eq!(config.bundle().unwrap(), "quay.io/example/bundle:dev");
+        assert_eq!(config.list().len(), 4);
+    }
+
+    fn write_temp(suffix: &str, content: &str) -> tempfile::NamedTempFile {
+        let mut temp = tempfile::Builder::new().suffix(suffix).tempfile().unwrap();
+        temp.write_all(content.as_bytes()).unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = serde_json::to_string_pretty(&sample_images()).unwrap();
+        let temp = write_temp(".json", &json);
+        assert_sample_config(&ImageConfig::load(temp.path()).unwrap());
+    }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let yaml = serde_yaml::to_string(&sample_images()).unwrap();
+        for suffix in [".yaml", ".yml"] {
+            let temp = write_temp(suffix, &yaml);
+            assert_sample_config(&ImageConfig::load(temp.path()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_unknown_extension_tries_json_then_yaml() {
+        let json = serde_json::to_string(&sample_images()).unwrap();
+        let temp = write_temp(".images", &json);
+        assert_sample_config(&ImageConfig::load(temp.path()).unwrap());
+
+        let yaml = serde_yaml::to_string(&sample_images()).unwrap();
+        let temp = write_temp("", &yaml);
+        assert_sample_config(&ImageConfig::load(temp.path()).unwrap());
+
+        // A YAML file with a .json extension is not second-guessed
+        let temp = write_temp(".json", &yaml);
+        assert!(ImageConfig::load(temp.path()).is_err());
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = ImageConfig::from_related_images(sample_images());
+        let mut images = sample_images();
+        images.retain(|img| img.name != "bundle");
+        images[0].image = "quay.io/example/operator:v2".to_string();
+        images.push(RelatedImage {
+            name: "catalog".to_string(),
+            image: "quay.io/example/catalog:dev".to_string(),
+        });
+        let new = ImageConfig::from_related_images(images);
+
+        assert_eq!(
+            diff(&old, &new),
+            vec![
+                ImageChange::Removed {
+                    name: "bundle".to_string(),
+                    image: "quay.io/example/bundle:dev".to_string(),
+                },
+                ImageChange::Added {
+                    name: "catalog".to_string(),
+                    image: "quay.io/example/catalog:dev".to_string(),
+                },
+                ImageChange::Changed {
+                    name: "operator".to_string(),
+                    old: "quay.io/example/operator:dev".to_string(),
+                    new: "quay.io/example/operator:v2".to_string(),
+                },
+            ]
+        );
+        assert!(diff(&old, &old).is_empty());
+    }
+
     #[test]
     fn test_missing_image() {
         let json = r#"[{"name": "operator", "image": "quay.io/example/operator:latest"}]"#;
@@ -109,4 +341,21 @@ mod tests {
         let config = ImageConfig::load(temp.path()).unwrap();
         assert!(config.get("nonexistent").is_err());
     }
+
+    #[test]
+    fn test_suffixed_image() {
+        assert_eq!(
+            suffixed_image("quay.io/me/kueue-operator:dev", "abc1234").unwrap(),
+            "quay.io/me/kueue-operator:dev-abc1234"
+        );
+        // A registry port is not a tag
+        assert_eq!(
+            suffixed_image("localhost:5000/kueue", "-20250101").unwrap(),
+            "localhost:5000/kueue:20250101"
+        );
+        assert!(suffixed_image("quay.io/me/kueue:dev", "bad/suffix").is_err());
+        assert!(suffixed_image("quay.io/me/kueue:dev", "").is_err());
+        assert!(suffixed_image("quay.io/me/kueue@sha256:abc", "x").is_err());
+        assert!(suffixed_image("quay.io/me/kueue:dev", &"x".repeat(128)).is_err());
+    }
 }
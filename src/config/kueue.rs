@@ -2,6 +2,7 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Kueue management state
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
@@ -42,6 +43,8 @@ pub struct KueueConfigBuilder {
     namespace: Option<String>,
     management_state: Option<ManagementState>,
     frameworks: Vec<Framework>,
+    labels: BTreeMap<String, String>,
+    annotations: BTreeMap<String, String>,
 }
 
 impl KueueConfigBuilder {
@@ -74,6 +77,17 @@ impl KueueConfigBuilder {
         self
     }
 
+    /// Add a CR label; overrides a default label with the same key
+    pub fn label(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.labels.insert(key.into(), value.into());
+        self
+    }
+
+    pub fn annotation(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.annotations.insert(key.into(), value.into());
+        self
+    }
+
     pub fn build(self) -> Result<KueueConfig> {
         Ok(KueueConfig {
             name: self.name.unwrap_or_else(|| "cluster".to_string()),
@@ -86,9 +100,27 @@ impl KueueConfigBuilder {
             } else {
                 self.frameworks
             },
+            labels: Self::default_labels()
+                .into_iter()
+                .chain(self.labels)
+                .collect(),
+            annotations: self.annotations,
         })
     }
 
+    fn default_labels() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            (
+                "app.kubernetes.io/name".to_string(),
+                "kueue-operator".to_string(),
+            ),
+            (
+                "app.kubernetes.io/managed-by".to_string(),
+                "kustomize".to_string(),
+            ),
+        ])
+    }
+
     fn default_frameworks() -> Vec<Framework> {
         vec![
             Framework::BatchJob,
@@ -108,6 +140,33 @@ pub struct KueueConfig {
     pub namespace: String,
     pub management_state: ManagementState,
     pub frameworks: Vec<Framework>,
+    pub labels: BTreeMap<String, String>,
+    pub annotations: BTreeMap<String, String>,
+}
+
+/// Parse a `key=value` CR label or annotation
+pub fn parse_metadata_entry(entry: &str) -> Result<(String, String), String> {
+    match entry.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => {
+            Ok((key.trim().to_string(), value.to_string()))
+        }
+        _ => Err(format!("expected KEY=VALUE, got '{}'", entry)),
+    }
+}
+
+/// Render a metadata map as an indented YAML block; values are always quoted
+fn metadata_yaml(field: &str, entries: &BTreeMap<String, String>) -> String {
+    if entries.is_empty() {
+        return String::new();
+    }
+
+    let mut yaml = format!("  {}:\n", field);
+    for (key, value) in entries {
+        // A JSON string is a valid double-quoted YAML scalar
+        let value = serde_json::to_string(value).expect("string serialization cannot fail");
+        yaml.push_str(&format!("    {}: {}\n", key, value));
+    }
+    yaml
 }
 
 impl KueueConfig {
@@ -133,10 +192,7 @@ impl KueueConfig {
             r#"apiVersion: kueue.openshift.io/v1
 kind: Kueue
 metadata:
-  labels:
-    app.kubernetes.io/name: kueue-operator
-    app.kubernetes.io/managed-by: kustomize
-  name: {}
+{}{}  name: {}
   namespace: {}
 spec:
   managementState: {}
@@ -145,7 +201,12 @@ spec:
       frameworks:
 {}
 "#,
-            self.name, self.namespace, management_state, frameworks_yaml
+            metadata_yaml("labels", &self.labels),
+            metadata_yaml("annotations", &self.annotations),
+            self.name,
+            self.namespace,
+            management_state,
+            frameworks_yaml
         )
     }
 }
@@ -199,5 +260,43 @@ mod tests {
         assert!(yaml.contains("- BatchJob"));
         assert!(yaml.contains("- Pod"));
         assert!(yaml.contains("managementState: Managed"));
+        assert!(yaml.contains("app.kubernetes.io/name: \"kueue-operator\""));
+        assert!(!yaml.contains("annotations:"));
+    }
+
+    #[test]
+    fn test_custom_labels_and_annotations() {
+        let config = KueueConfig::builder()
+            .label("example.com/commit", "0123456")
+            .label("app.kubernetes.io/managed-by", "argocd")
+            .annotation("example.com/source", "git@example.com:org/repo.git")
+            .build()
+            .unwrap();
+
+        // Defaults are kept unless overridden
+        assert_eq!(config.labels["app.kubernetes.io/name"], "kueue-operator");
+        assert_eq!(config.labels["app.kubernetes.io/managed-by"], "argocd");
+
+        let yaml = config.to_yaml();
+        assert!(yaml.contains("    example.com/commit: \"0123456\"\n"));
+        assert!(yaml.contains("    app.kubernetes.io/managed-by: \"argocd\"\n"));
+        assert!(yaml.contains(
+            "  annotations:\n    example.com/source: \"git@example.com:org/repo.git\"\n"
+        ));
+        assert!(!yaml.contains("kustomize"));
+    }
+
+    #[test]
+    fn test_parse_metadata_entry() {
+        assert_eq!(
+            parse_metadata_entry("example.com/commit=abc=1"),
+            Ok(("example.com/commit".to_string(), "abc=1".to_string()))
+        );
+        assert_eq!(
+            parse_metadata_entry("empty="),
+            Ok(("empty".to_string(), String::new()))
+        );
+        assert!(parse_metadata_entry("novalue").is_err());
+        assert!(parse_metadata_entry("=value").is_err());
     }
 }
@@ -1,5 +1,7 @@
 //! Configuration management
 
+pub mod compat;
+pub mod edit;
 pub mod images;
 pub mod kueue;
 pub mod settings;
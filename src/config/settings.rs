@@ -5,6 +5,8 @@ use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::PathBuf;
 
+use super::compat::CompatibilityEntry;
+
 /// Main configuration structure
 #[derive(Debug, Deserialize, Serialize, Clone, Default)]
 pub struct Settings {
@@ -17,6 +19,9 @@ pub struct Settings {
     #[serde(default)]
     pub behavior: Behavior,
 
+    #[serde(default)]
+    pub operator: OperatorSettings,
+
     #[serde(default)]
     pub kueue: KueueSettings,
 
@@ -25,6 +30,32 @@ pub struct Settings {
 
     #[serde(default)]
     pub versions: Versions,
+
+    #[serde(default)]
+    pub interactive: InteractiveSettings,
+
+    /// Dependency versions tested with each kueue release; replaces the
+    /// built-in matrix when set
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compatibility: Vec<CompatibilityEntry>,
+}
+
+/// Targets for the `interactive` menu. Unset values are detected from the
+/// cluster: OpenShift monitoring when `openshift-monitoring` exists, otherwise
+/// the Prometheus that kueue-dev installs into `default`.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct InteractiveSettings {
+    /// Namespace of the Prometheus instance
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitoring_namespace: Option<String>,
+
+    /// Service to port-forward to for the Prometheus UI
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prometheus_service: Option<String>,
+
+    /// Namespace of the prometheus-operator deployment
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prometheus_operator_namespace: Option<String>,
 }
 
 /// Test configuration settings
@@ -35,6 +66,59 @@ pub struct TestSettings {
 
     #[serde(default = "default_upstream_skip_patterns")]
     pub upstream_skip_patterns: Vec<String>,
+
+    /// Layer the configured skip patterns on top of the built-in defaults
+    /// instead of replacing them
+    #[serde(default)]
+    pub extend_default_skips: bool,
+
+    /// Namespace name prefixes that identify e2e test namespaces for cleanup
+    #[serde(default = "default_namespace_prefixes")]
+    pub namespace_prefixes: Vec<String>,
+
+    /// Namespaces whose NetworkPolicies `test upstream` never deletes
+    #[serde(default = "default_netpol_excluded_namespaces")]
+    pub netpol_excluded_namespaces: Vec<String>,
+
+    /// Extra `key=value` labels for the worker nodes
+    #[serde(default)]
+    pub node_labels: Vec<String>,
+
+    /// How `node_labels` are applied: uniform or distribute
+    #[serde(default = "default_node_label_mode")]
+    pub node_label_mode: String,
+}
+
+impl TestSettings {
+    /// Effective skip patterns for operator tests
+    pub fn effective_operator_skip_patterns(&self) -> Vec<String> {
+        self.effective_skips(
+            default_operator_skip_patterns(),
+            &self.operator_skip_patterns,
+        )
+    }
+
+    /// Effective skip patterns for upstream tests
+    pub fn effective_upstream_skip_patterns(&self) -> Vec<String> {
+        self.effective_skips(
+            default_upstream_skip_patterns(),
+            &self.upstream_skip_patterns,
+        )
+    }
+
+    fn effective_skips(&self, defaults: Vec<String>, configured: &[String]) -> Vec<String> {
+        if !self.extend_default_skips {
+            return configured.to_vec();
+        }
+
+        let mut merged = defaults;
+        for pattern in configured {
+            if !merged.contains(pattern) {
+                merged.push(pattern.clone());
+            }
+        }
+        merged
+    }
 }
 
 /// Default values for common operations
@@ -49,6 +133,10 @@ pub struct Defaults {
     #[serde(default = "default_images_file")]
     pub images_file: String,
 
+    /// Operator deploy method for kind: auto, bundle, or manifest
+    #[serde(default = "default_deploy_method")]
+    pub deploy_method: String,
+
     /// Optional path to kueue-operator source directory.
     /// If not set, the current working directory will be used.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -87,6 +175,31 @@ pub struct Behavior {
 
     #[serde(default = "default_true")]
     pub show_progress: bool,
+
+    /// Maximum seconds to wait for the operator to become leader after its
+    /// deployment is Available
+    #[serde(default = "default_operator_ready_timeout")]
+    pub operator_ready_timeout: u64,
+
+    /// Seconds to wait for each OLM deployment to become Available after installing OLM
+    #[serde(default = "default_olm_ready_timeout")]
+    pub olm_ready_timeout: u64,
+
+    /// Seconds `kubectl apply` of a kustomize build may run before it is killed
+    #[serde(default = "default_apply_timeout")]
+    pub apply_timeout: u64,
+
+    /// Preflight warns when the container runtime has less free disk (GiB) than this
+    #[serde(default = "default_min_free_disk_gb")]
+    pub min_free_disk_gb: u64,
+}
+
+/// kueue-operator deployment settings
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct OperatorSettings {
+    /// Namespace the operator is deployed into
+    #[serde(default = "default_operator_namespace")]
+    pub namespace: String,
 }
 
 /// Kueue CR configuration settings
@@ -125,6 +238,10 @@ pub struct Versions {
 
     #[serde(default = "default_prometheus_operator_version")]
     pub prometheus_operator: String,
+
+    /// Oldest OpenShift release kueue-dev deploys to without a warning
+    #[serde(default = "default_min_openshift_version")]
+    pub min_openshift: String,
 }
 
 // Default value functions
@@ -136,6 +253,10 @@ fn default_cni_provider() -> String {
     "calico".to_string()
 }
 
+fn default_deploy_method() -> String {
+    "auto".to_string()
+}
+
 fn default_images_file() -> String {
     "related_images.json".to_string()
 }
@@ -144,10 +265,30 @@ fn default_true() -> bool {
     true
 }
 
+fn default_operator_ready_timeout() -> u64 {
+    120
+}
+
+fn default_apply_timeout() -> u64 {
+    300
+}
+
+fn default_olm_ready_timeout() -> u64 {
+    300
+}
+
+fn default_min_free_disk_gb() -> u64 {
+    10
+}
+
 fn default_theme() -> String {
     "default".to_string()
 }
 
+fn default_operator_namespace() -> String {
+    crate::install::operator::DEFAULT_OPERATOR_NAMESPACE.to_string()
+}
+
 fn default_kueue_name() -> String {
     "cluster".to_string()
 }
@@ -195,6 +336,10 @@ fn default_prometheus_operator_version() -> String {
     "v0.82.2".to_string()
 }
 
+fn default_min_openshift_version() -> String {
+    "4.18".to_string()
+}
+
 fn default_operator_skip_patterns() -> Vec<String> {
     vec![
         "AppWrapper".to_string(),
@@ -213,6 +358,29 @@ fn default_operator_skip_patterns() -> Vec<String> {
     ]
 }
 
+fn default_node_label_mode() -> String {
+    "uniform".to_string()
+}
+
+fn default_namespace_prefixes() -> Vec<String> {
+    vec![
+        "e2e-".to_string(),
+        "sts-e2e-".to_string(),
+        "deployment-e2e-".to_string(),
+        "lws-e2e-".to_string(),
+        "pod-e2e-".to_string(),
+        "jobset-e2e-".to_string(),
+    ]
+}
+
+fn default_netpol_excluded_namespaces() -> Vec<String> {
+    vec![
+        "kube-system".to_string(),
+        "openshift-kueue-operator".to_string(),
+        "kueue-system".to_string(),
+    ]
+}
+
 fn default_upstream_skip_patterns() -> Vec<String> {
     vec![
         // do not deploy AppWrapper in OCP
@@ -248,6 +416,7 @@ impl Default for Defaults {
             cluster_name: default_cluster_name(),
             cni_provider: default_cni_provider(),
             images_file: default_images_file(),
+            deploy_method: default_deploy_method(),
             kueue_operator_source_path: None,
             kubeconfig_path: None,
             upstream_source: None,
@@ -270,6 +439,18 @@ impl Default for Behavior {
             confirm_destructive: default_true(),
             parallel_operations: default_true(),
             show_progress: default_true(),
+            operator_ready_timeout: default_operator_ready_timeout(),
+            olm_ready_timeout: default_olm_ready_timeout(),
+            apply_timeout: default_apply_timeout(),
+            min_free_disk_gb: default_min_free_disk_gb(),
+        }
+    }
+}
+
+impl Default for OperatorSettings {
+    fn default() -> Self {
+        Self {
+            namespace: default_operator_namespace(),
         }
     }
 }
@@ -289,6 +470,11 @@ impl Default for TestSettings {
         Self {
             operator_skip_patterns: default_operator_skip_patterns(),
             upstream_skip_patterns: default_upstream_skip_patterns(),
+            extend_default_skips: false,
+            namespace_prefixes: default_namespace_prefixes(),
+            netpol_excluded_namespaces: default_netpol_excluded_namespaces(),
+            node_labels: Vec::new(),
+            node_label_mode: default_node_label_mode(),
         }
     }
 }
@@ -303,11 +489,21 @@ impl Default for Versions {
             training_operator: default_training_operator_version(),
             calico: default_calico_version(),
             prometheus_operator: default_prometheus_operator_version(),
+            min_openshift: default_min_openshift_version(),
         }
     }
 }
 
 impl Settings {
+    /// Compatibility matrix from the config file, or the built-in one
+    pub fn compatibility_matrix(&self) -> Vec<CompatibilityEntry> {
+        if self.compatibility.is_empty() {
+            super::compat::default_matrix()
+        } else {
+            self.compatibility.clone()
+        }
+    }
+
     /// Load settings from file or return defaults
     pub fn load() -> Result<Self> {
         if let Some(path) = Self::find_config_file() {
@@ -340,14 +536,13 @@ impl Settings {
         }
 
         // Check XDG config directory
-        if let Some(config_dir) = dirs::config_dir() {
-            let xdg_config = config_dir.join("kueue-dev").join("config.toml");
-            if xdg_config.exists() {
-                return Some(xdg_config);
-            }
-        }
+        Self::user_config_file().filter(|path| path.exists())
+    }
 
-        None
+    /// Path of the per-user config file (`~/.config/kueue-dev/config.toml`),
+    /// whether or not it exists
+    pub fn user_config_file() -> Option<PathBuf> {
+        dirs::config_dir().map(|dir| dir.join("kueue-dev").join("config.toml"))
     }
 
     /// Return the active config file path, resolved to an absolute path when possible.
@@ -365,9 +560,16 @@ impl Settings {
         })
     }
 
-    /// Save settings to file
+    /// Save settings to file. An existing file is updated in place so its
+    /// comments and formatting survive; only changed values are rewritten.
     pub fn save(&self, path: &PathBuf) -> Result<()> {
-        let contents = toml::to_string_pretty(self).context("Failed to serialize settings")?;
+        let existing = if path.exists() {
+            fs::read_to_string(path)
+                .with_context(|| format!("Failed to read config file: {}", path.display()))?
+        } else {
+            String::new()
+        };
+        let contents = super::edit::merge_settings(&existing, self)?;
 
         fs::write(path, contents)
             .with_context(|| format!("Failed to write config file: {}", path.display()))?;
@@ -392,6 +594,7 @@ impl Settings {
 cluster_name = "kueue-test"
 cni_provider = "calico"
 images_file = "related_images.json"
+deploy_method = "auto"  # Options: auto, bundle, manifest
 # kueue_operator_source_path = "/path/to/kueue-operator"  # Optional: Path to kueue-operator source. Defaults to current directory.
 # kubeconfig_path = "kube.kubeconfig"  # Optional: Path where kind should save kubeconfig. If not set, kubeconfig won't be saved to file.
 # upstream_source = "/path/to/kueue/upstream/src"  # Optional: Path to upstream kueue source for kustomize/helm deployment.
@@ -404,6 +607,14 @@ theme = "default"  # Options: default, dark, light, none
 confirm_destructive = true
 parallel_operations = true
 show_progress = true
+operator_ready_timeout = 120  # Seconds to wait for the operator to acquire its leader lease
+olm_ready_timeout = 300       # Seconds to wait for each OLM deployment to become Available
+apply_timeout = 300           # Seconds a kustomize kubectl apply may run before it is killed
+min_free_disk_gb = 10         # Preflight warns below this much free container storage
+
+[operator]
+# Namespace the kueue-operator is deployed into
+namespace = "openshift-kueue-operator"
 
 [kueue]
 # Kueue CR name - should always be "cluster"
@@ -414,6 +625,21 @@ namespace = "openshift-kueue-operator"
 frameworks = ["BatchJob", "Pod", "Deployment", "StatefulSet", "JobSet", "LeaderWorkerSet"]
 
 [tests]
+# Set to true to add the patterns below to the built-in defaults instead of replacing them
+extend_default_skips = false
+
+# Namespace prefixes that `kueue-dev cleanup` treats as e2e test namespaces
+namespace_prefixes = ["e2e-", "sts-e2e-", "deployment-e2e-", "lws-e2e-", "pod-e2e-", "jobset-e2e-"]
+
+# Namespaces whose NetworkPolicies `kueue-dev test upstream` never deletes
+netpol_excluded_namespaces = ["kube-system", "openshift-kueue-operator", "kueue-system"]
+
+# Extra labels for the worker nodes, e.g. for TopologyAwareScheduling tests.
+# "uniform" gives every worker every label; "distribute" assigns the values
+# given for a key to the workers in turn.
+# node_labels = ["topology.kubernetes.io/zone=zone-a", "topology.kubernetes.io/zone=zone-b"]
+# node_label_mode = "distribute"
+
 # Test patterns to skip for operator tests
 operator_skip_patterns = [
     "AppWrapper",
@@ -454,10 +680,28 @@ cert_manager = "v1.18.0"
 jobset = "v0.10.1"
 # Version of LeaderWorkerSet to install
 leaderworkerset = "v0.7.0"
+# Version of AppWrapper to install (deploy upstream)
+appwrapper = "v1.1.2"
+# Version of the Kubeflow Training Operator to install (deploy upstream)
+training_operator = "v1.8.1"
 # Version of Calico CNI to install
 calico = "v3.28.2"
 # Version of Prometheus Operator to install
 prometheus_operator = "v0.82.2"
+# Oldest supported OpenShift version; older clusters prompt before deploying
+min_openshift = "4.18"
+
+[interactive]
+# Detected from the cluster when unset
+# monitoring_namespace = "openshift-monitoring"
+# prometheus_service = "prometheus"
+# prometheus_operator_namespace = "default"
+
+# Dependency versions tested with each kueue minor release (replaces the built-in matrix).
+# Upstream deploys warn about overridden versions outside these ranges.
+# [[compatibility]]
+# kueue = "v0.14"
+# jobset = { min = "v0.10.0", before = "v0.11.0" }
 "#
                 .to_string()
             }
@@ -469,6 +713,27 @@ prometheus_operator = "v0.82.2"
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_compatibility_matrix() {
+        assert!(!Settings::default().compatibility_matrix().is_empty());
+
+        let settings: Settings = toml::from_str(
+            r#"
+[[compatibility]]
+kueue = "v0.15"
+jobset = { min = "v0.11.0" }
+"#,
+        )
+        .unwrap();
+        let matrix = settings.compatibility_matrix();
+        assert_eq!(matrix.len(), 1);
+        assert_eq!(matrix[0].kueue, "v0.15");
+        assert_eq!(
+            matrix[0].dependencies["jobset"].min.as_deref(),
+            Some("v0.11.0")
+        );
+    }
+
     #[test]
     fn test_default_settings() {
         let settings = Settings::default();
@@ -476,6 +741,9 @@ mod tests {
         assert_eq!(settings.defaults.cni_provider, "calico");
         assert!(settings.colors.enabled);
         assert!(settings.behavior.show_progress);
+        assert_eq!(settings.defaults.deploy_method, "auto");
+        assert_eq!(settings.versions.appwrapper, "v1.1.2");
+        assert_eq!(settings.versions.training_operator, "v1.8.1");
     }
 
     #[test]
@@ -484,6 +752,8 @@ mod tests {
         let toml_str = toml::to_string_pretty(&settings).unwrap();
         assert!(toml_str.contains("cluster_name"));
         assert!(toml_str.contains("kueue-test"));
+        assert!(toml_str.contains("appwrapper = \"v1.1.2\""));
+        assert!(toml_str.contains("training_operator = \"v1.8.1\""));
     }
 
     #[test]
@@ -498,14 +768,52 @@ enabled = false
 
 [behavior]
 confirm_destructive = false
+
+[versions]
+appwrapper = "v1.1.1"
+training_operator = "v1.9.0"
 "#;
         let settings: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(settings.versions.appwrapper, "v1.1.1");
+        assert_eq!(settings.versions.training_operator, "v1.9.0");
+        assert_eq!(settings.versions.jobset, default_jobset_version());
         assert_eq!(settings.defaults.cluster_name, "my-cluster");
         assert_eq!(settings.defaults.cni_provider, "default");
         assert!(!settings.colors.enabled);
         assert!(!settings.behavior.confirm_destructive);
     }
 
+    #[test]
+    fn test_skip_patterns_replace_defaults() {
+        let toml_str = r#"
+[tests]
+operator_skip_patterns = ["OnlyThis"]
+"#;
+        let settings: Settings = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            settings.tests.effective_operator_skip_patterns(),
+            vec!["OnlyThis"]
+        );
+        assert_eq!(
+            settings.tests.effective_upstream_skip_patterns(),
+            default_upstream_skip_patterns()
+        );
+    }
+
+    #[test]
+    fn test_skip_patterns_extend_defaults() {
+        let toml_str = r#"
+[tests]
+extend_default_skips = true
+operator_skip_patterns = ["AppWrapper", "MyFlakyTest"]
+"#;
+        let settings: Settings = toml::from_str(toml_str).unwrap();
+        let effective = settings.tests.effective_operator_skip_patterns();
+        let mut expected = default_operator_skip_patterns();
+        expected.push("MyFlakyTest".to_string());
+        assert_eq!(effective, expected);
+    }
+
     #[test]
     fn test_example_config() {
         let example = Settings::example_config();
@@ -513,5 +821,11 @@ confirm_destructive = false
         assert!(example.contains("[defaults]"));
         assert!(example.contains("[colors]"));
         assert!(example.contains("[behavior]"));
+
+        let settings: Settings = toml::from_str(&example).unwrap();
+        assert_eq!(
+            settings.versions.training_operator,
+            default_training_operator_version()
+        );
     }
 }
@@ -1,11 +1,14 @@
 //! AppWrapper installation
 
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install AppWrapper
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing AppWrapper {}...", version);
 
     let appwrapper_url = format!(
@@ -14,8 +17,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
     );
 
     // Check if AppWrapper namespace already exists
-    let ns_check =
-        kubectl::run_kubectl_output(&["get", "namespace", "appwrapper-system"], kubeconfig);
+    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "appwrapper-system"], &target);
 
     if ns_check.is_ok() {
         crate::log_info!("AppWrapper namespace already exists, skipping installation");
@@ -25,13 +27,11 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
     crate::log_info!("Downloading AppWrapper manifest...");
 
     // Download and apply the manifest
-    let appwrapper_yaml = reqwest::blocking::get(&appwrapper_url)
-        .context("Failed to download AppWrapper manifest")?
-        .text()
-        .context("Failed to read AppWrapper manifest")?;
+    let appwrapper_yaml =
+        http::download_text(&appwrapper_url).context("Failed to download AppWrapper manifest")?;
 
     // Use server-side apply to avoid annotation size limits for large CRDs
-    kubectl::apply_yaml_server_side(&appwrapper_yaml, kubeconfig)
+    kubectl::apply_yaml_server_side(&appwrapper_yaml, &target)
         .context("Failed to apply AppWrapper manifest")?;
 
     crate::log_info!("Waiting for AppWrapper controller to be ready...");
@@ -42,7 +42,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("appwrapper-system"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("AppWrapper controller deployment not ready")?;
 
@@ -1,11 +1,22 @@
 //! Calico CNI installation
 
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
+/// Check whether the Calico Installation resource exists
+pub fn is_installed(kubeconfig: Option<&Path>) -> bool {
+    kubectl::run_kubectl_output(
+        &["get", "installation", "default"],
+        &KubeTarget::new(kubeconfig),
+    )
+    .is_ok()
+}
+
 /// Install Calico CNI
-pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+pub fn install(version: &str, target: &KubeTarget) -> Result<()> {
     crate::log_info!("Installing Calico CNI {}...", version);
 
     let calico_operator_url = format!(
@@ -16,12 +27,10 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
     crate::log_info!("Applying Calico operator manifest...");
 
     // Download and apply the operator manifest
-    let operator_yaml = reqwest::blocking::get(&calico_operator_url)
-        .context("Failed to download Calico operator manifest")?
-        .text()
-        .context("Failed to read Calico operator manifest")?;
+    let operator_yaml = http::download_text(&calico_operator_url)
+        .context("Failed to download Calico operator manifest")?;
 
-    kubectl::create_yaml(&operator_yaml, kubeconfig).context("Failed to apply Calico operator")?;
+    kubectl::create_yaml(&operator_yaml, target).context("Failed to apply Calico operator")?;
 
     crate::log_info!("Waiting for Calico CRDs to be established...");
 
@@ -31,7 +40,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=established",
         None,
         "60s",
-        kubeconfig,
+        target,
     )
     .context("Failed waiting for Installation CRD")?;
 
@@ -40,7 +49,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=established",
         None,
         "60s",
-        kubeconfig,
+        target,
     )
     .context("Failed waiting for APIServer CRD")?;
 
@@ -67,8 +76,7 @@ metadata:
 spec: {}
 "#;
 
-    kubectl::apply_yaml(calico_cr, kubeconfig)
-        .context("Failed to apply Calico custom resources")?;
+    kubectl::apply_yaml(calico_cr, target).context("Failed to apply Calico custom resources")?;
 
     crate::log_info!("Waiting for Calico pods to be ready...");
 
@@ -78,7 +86,7 @@ spec: {}
         "condition=ready",
         Some("tigera-operator"),
         "300s",
-        kubeconfig,
+        target,
     )
     .ok(); // Ignore errors, continue
 
@@ -88,7 +96,7 @@ spec: {}
         "condition=ready",
         Some("calico-system"),
         "300s",
-        kubeconfig,
+        target,
     )
     .ok(); // Ignore errors, continue
 
@@ -98,7 +106,7 @@ spec: {}
         "condition=ready",
         Some("calico-apiserver"),
         "60s",
-        kubeconfig,
+        target,
     )
     .ok(); // Ignore errors, it's optional
 
@@ -106,14 +114,14 @@ spec: {}
 
     // Wait for nodes to be ready
     crate::log_info!("Waiting for all nodes to be ready...");
-    kubectl::wait_for_condition("nodes", "condition=Ready", None, "180s", kubeconfig)
+    kubectl::wait_for_condition("nodes", "condition=Ready", None, "180s", target)
         .context("Nodes did not become ready")?;
 
     // Display node resources
     crate::log_info!("Cluster node resources:");
     let nodes_output = kubectl::get_nodes(
         "custom-columns=NAME:.metadata.name,CPU:.status.capacity.cpu,MEMORY:.status.capacity.memory",
-        kubeconfig,
+        target,
     ).context("Failed to get node resources")?;
 
     println!("{}", nodes_output);
@@ -1,11 +1,15 @@
 //! cert-manager installation
 
+use crate::install::existing;
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install cert-manager
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing cert-manager {}...", version);
 
     let cert_manager_url = format!(
@@ -13,23 +17,23 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         version
     );
 
-    // Check if cert-manager namespace already exists
-    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "cert-manager"], kubeconfig);
-
-    if ns_check.is_ok() {
-        crate::log_info!("cert-manager namespace already exists, skipping installation");
+    if !existing::needs_install(
+        "cert-manager",
+        "cert-manager",
+        "cert-manager",
+        version,
+        &target,
+    ) {
         return Ok(());
     }
 
     crate::log_info!("Downloading cert-manager manifest...");
 
     // Download and apply the manifest
-    let cert_manager_yaml = reqwest::blocking::get(&cert_manager_url)
-        .context("Failed to download cert-manager manifest")?
-        .text()
-        .context("Failed to read cert-manager manifest")?;
+    let cert_manager_yaml = http::download_text(&cert_manager_url)
+        .context("Failed to download cert-manager manifest")?;
 
-    kubectl::apply_yaml(&cert_manager_yaml, kubeconfig)
+    kubectl::apply_yaml(&cert_manager_yaml, &target)
         .context("Failed to apply cert-manager manifest")?;
 
     crate::log_info!("Waiting for cert-manager to be ready...");
@@ -40,7 +44,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("cert-manager"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("cert-manager deployment not ready")?;
 
@@ -49,7 +53,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("cert-manager"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("cert-manager-webhook deployment not ready")?;
 
@@ -58,7 +62,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("cert-manager"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("cert-manager-cainjector deployment not ready")?;
 
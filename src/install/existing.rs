@@ -0,0 +1,161 @@
+//! Detect dependencies that are already installed on the cluster
+
+use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+
+/// A dependency's install state relative to the requested version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Installed {
+    /// Its controller deployment is not on the cluster
+    Missing,
+    /// The controller runs the requested version
+    Requested,
+    /// The controller runs another version (its image tag)
+    Other(String),
+    /// The controller's images are untagged (e.g. pinned by digest), so its
+    /// version cannot be told
+    Unknown,
+}
+
+impl Installed {
+    /// Compare the image tags of `deployment` in `namespace` against `version`
+    pub fn check(deployment: &str, namespace: &str, version: &str, target: &KubeTarget) -> Self {
+        match kubectl::run_kubectl_output(
+            &[
+                "get",
+                "deployment",
+                deployment,
+                "-n",
+                namespace,
+                "-o",
+                "jsonpath={.spec.template.spec.containers[*].image}",
+            ],
+            target,
+        ) {
+            Ok(images) => Self::from_images(&images, version),
+            Err(_) => Self::Missing,
+        }
+    }
+
+    /// State from a deployment's space-separated container images
+    fn from_images(images: &str, version: &str) -> Self {
+        let images: Vec<&str> = images.split_whitespace().collect();
+        if images.is_empty() {
+            return Self::Missing;
+        }
+
+        let tags: Vec<&str> = images.iter().filter_map(|image| image_tag(image)).collect();
+        if tags.iter().any(|tag| same_version(tag, version)) {
+            return Self::Requested;
+        }
+        match tags.first() {
+            Some(tag) => Self::Other(tag.to_string()),
+            None => Self::Unknown,
+        }
+    }
+}
+
+/// Whether `name` at `version` still has to be installed. Logs the reason when
+/// the requested version is already running or another version gets replaced.
+/// An install whose version cannot be told, such as one managed by OLM, is kept.
+pub fn needs_install(
+    name: &str,
+    deployment: &str,
+    namespace: &str,
+    version: &str,
+    target: &KubeTarget,
+) -> bool {
+    match Installed::check(deployment, namespace, version, target) {
+        Installed::Missing => true,
+        Installed::Requested => {
+            crate::log_info!(
+                "{} {} is already installed, skipping installation",
+                name,
+                version
+            );
+            false
+        }
+        Installed::Other(found) => {
+            crate::log_info!(
+                "{} {} is installed; installing {} over it",
+                name,
+                found,
+                version
+            );
+            true
+        }
+        Installed::Unknown => {
+            crate::log_info!(
+                "{} is already installed at an unknown version, skipping installation",
+                name
+            );
+            false
+        }
+    }
+}
+
+/// Tag of an image reference, ignoring any digest
+fn image_tag(image: &str) -> Option<&str> {
+    let image = image.split('@').next().unwrap_or(image);
+    let (_, tag) = image.rsplit_once(':')?;
+    (!tag.contains('/')).then_some(tag)
+}
+
+/// `v1.2.3` and `1.2.3` name the same version
+fn same_version(tag: &str, version: &str) -> bool {
+    tag.trim_start_matches('v') == version.trim_start_matches('v')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_installed_from_images() {
+        assert_eq!(Installed::from_images("", "v1.18.0"), Installed::Missing);
+        assert_eq!(
+            Installed::from_images(
+                "quay.io/jetstack/cert-manager-controller:v1.18.0",
+                "v1.18.0"
+            ),
+            Installed::Requested
+        );
+        // A sidecar listed first doesn't hide the controller's tag
+        assert_eq!(
+            Installed::from_images(
+                "quay.io/brancz/kube-rbac-proxy:v0.18.0 registry.k8s.io/jobset/jobset:v0.10.1",
+                "0.10.1"
+            ),
+            Installed::Requested
+        );
+        assert_eq!(
+            Installed::from_images("registry.k8s.io/lws/lws:v0.6.1", "v0.7.0"),
+            Installed::Other("v0.6.1".to_string())
+        );
+        assert_eq!(
+            Installed::from_images("localhost:5000/lws", "v0.7.0"),
+            Installed::Unknown
+        );
+        assert_eq!(
+            Installed::from_images(
+                "registry.redhat.io/cert-manager/controller@sha256:abc",
+                "v1.18.0"
+            ),
+            Installed::Unknown
+        );
+    }
+
+    #[test]
+    fn test_image_tag() {
+        assert_eq!(
+            image_tag("registry.k8s.io/jobset/jobset:v0.10.1"),
+            Some("v0.10.1")
+        );
+        assert_eq!(
+            image_tag("quay.io/jetstack/cert-manager-controller:v1.18.0@sha256:abc"),
+            Some("v1.18.0")
+        );
+        assert_eq!(image_tag("localhost:5000/lws"), None);
+        assert_eq!(image_tag("lws"), None);
+    }
+}
@@ -1,11 +1,15 @@
 //! JobSet installation
 
+use crate::install::existing;
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install JobSet
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing JobSet {}...", version);
 
     let jobset_url = format!(
@@ -13,24 +17,24 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         version
     );
 
-    // Check if JobSet namespace already exists
-    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "jobset-system"], kubeconfig);
-
-    if ns_check.is_ok() {
-        crate::log_info!("JobSet namespace already exists, skipping installation");
+    if !existing::needs_install(
+        "JobSet",
+        "jobset-controller-manager",
+        "jobset-system",
+        version,
+        &target,
+    ) {
         return Ok(());
     }
 
     crate::log_info!("Downloading JobSet manifest...");
 
     // Download and apply the manifest
-    let jobset_yaml = reqwest::blocking::get(&jobset_url)
-        .context("Failed to download JobSet manifest")?
-        .text()
-        .context("Failed to read JobSet manifest")?;
+    let jobset_yaml =
+        http::download_text(&jobset_url).context("Failed to download JobSet manifest")?;
 
     // Use server-side apply to avoid annotation size limits for large CRDs
-    kubectl::apply_yaml_server_side(&jobset_yaml, kubeconfig)
+    kubectl::apply_yaml_server_side(&jobset_yaml, &target)
         .context("Failed to apply JobSet manifest")?;
 
     crate::log_info!("Waiting for JobSet controller to be ready...");
@@ -41,7 +45,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("jobset-system"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("JobSet controller deployment not ready")?;
 
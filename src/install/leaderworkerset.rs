@@ -1,11 +1,15 @@
 //! LeaderWorkerSet installation
 
+use crate::install::existing;
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install LeaderWorkerSet
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing LeaderWorkerSet {}...", version);
 
     let lws_url = format!(
@@ -13,24 +17,24 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         version
     );
 
-    // Check if LeaderWorkerSet namespace already exists
-    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "lws-system"], kubeconfig);
-
-    if ns_check.is_ok() {
-        crate::log_info!("LeaderWorkerSet namespace already exists, skipping installation");
+    if !existing::needs_install(
+        "LeaderWorkerSet",
+        "lws-controller-manager",
+        "lws-system",
+        version,
+        &target,
+    ) {
         return Ok(());
     }
 
     crate::log_info!("Downloading LeaderWorkerSet manifest...");
 
     // Download and apply the manifest
-    let lws_yaml = reqwest::blocking::get(&lws_url)
-        .context("Failed to download LeaderWorkerSet manifest")?
-        .text()
-        .context("Failed to read LeaderWorkerSet manifest")?;
+    let lws_yaml =
+        http::download_text(&lws_url).context("Failed to download LeaderWorkerSet manifest")?;
 
     // Use server-side apply to avoid annotation size limits for large CRDs
-    kubectl::apply_yaml_server_side(&lws_yaml, kubeconfig)
+    kubectl::apply_yaml_server_side(&lws_yaml, &target)
         .context("Failed to apply LeaderWorkerSet manifest")?;
 
     crate::log_info!("Waiting for LeaderWorkerSet controller to be ready...");
@@ -41,7 +45,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("lws-system"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("LeaderWorkerSet controller deployment not ready")?;
 
@@ -3,6 +3,7 @@
 pub mod appwrapper;
 pub mod calico;
 pub mod cert_manager;
+pub mod existing;
 pub mod jobset;
 pub mod leaderworkerset;
 pub mod olm;
@@ -1,17 +1,71 @@
 //! OLM (Operator Lifecycle Manager) installation
 
-use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::k8s::{kubectl, version};
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 use std::process::Command;
 
-/// Check if the kueue-operator is already installed
-pub fn is_operator_installed(kubeconfig: Option<&Path>) -> bool {
+/// Oldest operator-sdk known to accept the `run bundle --timeout` and
+/// `cleanup` invocations used here
+const MIN_OPERATOR_SDK_VERSION: &str = "v1.25.0";
+
+/// Get the installed operator-sdk version, if it can be determined
+fn operator_sdk_version() -> Option<String> {
+    let output = Command::new("operator-sdk").arg("version").output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_operator_sdk_version(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract the version from `operator-sdk version` output, e.g.
+/// `operator-sdk version: "v1.39.1", commit: "...", kubernetes version: "v1.31.0", ...`
+fn parse_operator_sdk_version(output: &str) -> Option<String> {
+    let line = output
+        .lines()
+        .find(|l| l.contains("operator-sdk version"))?;
+    version::extract_version_from_log(line)
+}
+
+/// Log the operator-sdk version and warn if it is older than the known-good minimum
+fn check_operator_sdk_version() {
+    let Some(sdk_version) = operator_sdk_version() else {
+        crate::log_warn!("Could not determine operator-sdk version");
+        return;
+    };
+
+    crate::log_info!("operator-sdk version: {}", sdk_version);
+    if version::version_at_least(&sdk_version, MIN_OPERATOR_SDK_VERSION) == Some(false) {
+        crate::log_warn!(
+            "operator-sdk {} is older than {}; 'run bundle'/'cleanup' flags used by kueue-dev may be rejected",
+            sdk_version,
+            MIN_OPERATOR_SDK_VERSION
+        );
+        crate::log_warn!("Upgrade from: https://sdk.operatorframework.io/docs/installation/");
+    }
+}
+
+/// Append a version-compatibility note to operator-sdk errors caused by unknown flags
+fn operator_sdk_flag_hint(stderr: &str) -> String {
+    if stderr.contains("unknown flag") || stderr.contains("unknown shorthand flag") {
+        format!(
+            "\nHint: this operator-sdk does not support a flag kueue-dev passes; \
+             operator-sdk >= {} is required",
+            MIN_OPERATOR_SDK_VERSION
+        )
+    } else {
+        String::new()
+    }
+}
+
+/// Check if the kueue-operator is already installed in `namespace`
+pub fn is_operator_installed(namespace: &str, kubeconfig: Option<&Path>) -> bool {
+    let target = KubeTarget::new(kubeconfig);
+
     // Check if the operator namespace exists
-    let namespace_check = kubectl::run_kubectl_output(
-        &["get", "namespace", "openshift-kueue-operator"],
-        kubeconfig,
-    );
+    let namespace_check = kubectl::run_kubectl_output(&["get", "namespace", namespace], &target);
 
     if namespace_check.is_err() {
         return false;
@@ -24,9 +78,9 @@ pub fn is_operator_installed(kubeconfig: Option<&Path>) -> bool {
             "deployment",
             "openshift-kueue-operator",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
         ],
-        kubeconfig,
+        &target,
     );
 
     let catalog_check = kubectl::run_kubectl_output(
@@ -35,17 +89,18 @@ pub fn is_operator_installed(kubeconfig: Option<&Path>) -> bool {
             "catalogsource",
             "kueue-operator-catalog",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
         ],
-        kubeconfig,
+        &target,
     );
 
     deployment_check.is_ok() || catalog_check.is_ok()
 }
 
-/// Uninstall the kueue-operator if it's installed via OLM cleanup
-pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
-    if !is_operator_installed(kubeconfig) {
+/// Uninstall the kueue-operator from `namespace` if it's installed via OLM cleanup
+pub fn uninstall_operator_if_exists(namespace: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
+    if !is_operator_installed(namespace, kubeconfig) {
         crate::log_info!("No existing operator installation detected");
         return Ok(());
     }
@@ -68,12 +123,7 @@ pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
         cleanup_cmd.env("KUBECONFIG", kc);
     }
 
-    cleanup_cmd.args([
-        "cleanup",
-        "kueue-operator",
-        "-n",
-        "openshift-kueue-operator",
-    ]);
+    cleanup_cmd.args(["cleanup", "kueue-operator", "-n", namespace]);
 
     let cleanup_output = cleanup_cmd
         .output()
@@ -100,9 +150,9 @@ pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
                 "deployment",
                 "openshift-kueue-operator",
                 "-n",
-                "openshift-kueue-operator",
+                namespace,
             ],
-            kubeconfig,
+            &target,
         );
 
         if deployment_check.is_err() {
@@ -124,11 +174,11 @@ pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
         &[
             "delete",
             "namespace",
-            "openshift-kueue-operator",
+            namespace,
             "--ignore-not-found",
             "--timeout=60s",
         ],
-        kubeconfig,
+        &target,
     )
     .ok();
 
@@ -143,7 +193,7 @@ pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
             "--timeout=30s",
             "--ignore-not-found",
         ],
-        kubeconfig,
+        &target,
     )
     .ok();
 
@@ -153,8 +203,10 @@ pub fn uninstall_operator_if_exists(kubeconfig: Option<&Path>) -> Result<()> {
 
 /// Check if OLM is already installed
 pub fn is_olm_installed(kubeconfig: Option<&Path>) -> bool {
+    let target = KubeTarget::new(kubeconfig);
+
     // Check if the olm namespace exists and has the expected deployments
-    let namespace_check = kubectl::run_kubectl_output(&["get", "namespace", "olm"], kubeconfig);
+    let namespace_check = kubectl::run_kubectl_output(&["get", "namespace", "olm"], &target);
 
     if namespace_check.is_err() {
         return false;
@@ -163,10 +215,8 @@ pub fn is_olm_installed(kubeconfig: Option<&Path>) -> bool {
     // Check if key OLM deployments exist
     let deployments = ["olm-operator", "catalog-operator"];
     for deployment in &deployments {
-        let result = kubectl::run_kubectl_output(
-            &["get", "deployment", deployment, "-n", "olm"],
-            kubeconfig,
-        );
+        let result =
+            kubectl::run_kubectl_output(&["get", "deployment", deployment, "-n", "olm"], &target);
         if result.is_err() {
             return false;
         }
@@ -175,8 +225,131 @@ pub fn is_olm_installed(kubeconfig: Option<&Path>) -> bool {
     true
 }
 
-/// Install OLM (Operator Lifecycle Manager)
-pub fn install_olm(kubeconfig: Option<&Path>) -> Result<()> {
+/// Maximum time for the operator's ClusterServiceVersion to reach Succeeded
+const CSV_SUCCEEDED_TIMEOUT_SECS: u64 = 300;
+
+/// `<name>\t<phase>\t<reason>\t<message>` per ClusterServiceVersion
+const CSV_STATUS_JSONPATH: &str = r#"{range .items[*]}{.metadata.name}{"\t"}{.status.phase}{"\t"}{.status.reason}{"\t"}{.status.message}{"\n"}{end}"#;
+
+/// Install state of the ClusterServiceVersions in the operator namespace
+#[derive(Debug, PartialEq, Eq)]
+enum CsvState {
+    /// All CSVs Succeeded (names)
+    Succeeded(Vec<String>),
+    /// A CSV Failed, with its name and message
+    Failed(String),
+    /// No CSV yet, or still installing (current status for logging)
+    Pending(String),
+}
+
+/// Evaluate [`CSV_STATUS_JSONPATH`] output. CSVs copied into the namespace by
+/// all-namespace operators (reason `Copied`) are ignored.
+fn evaluate_csv_status(output: &str) -> CsvState {
+    let csvs: Vec<Vec<&str>> = output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split('\t').map(str::trim).collect::<Vec<_>>())
+        .filter(|fields| fields.get(2) != Some(&"Copied"))
+        .collect();
+
+    if csvs.is_empty() {
+        return CsvState::Pending("no ClusterServiceVersion yet".to_string());
+    }
+
+    let field =
+        |fields: &Vec<&str>, i: usize| fields.get(i).copied().unwrap_or_default().to_string();
+    if let Some(failed) = csvs.iter().find(|fields| fields.get(1) == Some(&"Failed")) {
+        return CsvState::Failed(format!(
+            "{}: {} {}",
+            field(failed, 0),
+            field(failed, 2),
+            field(failed, 3)
+        ));
+    }
+
+    if csvs
+        .iter()
+        .all(|fields| fields.get(1) == Some(&"Succeeded"))
+    {
+        return CsvState::Succeeded(csvs.iter().map(|fields| field(fields, 0)).collect());
+    }
+
+    let pending: Vec<String> = csvs
+        .iter()
+        .filter(|fields| fields.get(1) != Some(&"Succeeded"))
+        .map(|fields| {
+            let phase = field(fields, 1);
+            format!(
+                "{} is {}",
+                field(fields, 0),
+                if phase.is_empty() {
+                    "Pending".to_string()
+                } else {
+                    phase
+                }
+            )
+        })
+        .collect();
+    CsvState::Pending(pending.join(", "))
+}
+
+/// Poll the operator's ClusterServiceVersion until it Succeeded, failing if it
+/// Failed or is still installing after [`CSV_SUCCEEDED_TIMEOUT_SECS`]
+fn wait_for_csv_succeeded(namespace: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    use std::time::{Duration, Instant};
+
+    crate::log_info!("Waiting for the ClusterServiceVersion to reach Succeeded...");
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(CSV_SUCCEEDED_TIMEOUT_SECS);
+
+    loop {
+        let state = match kubectl::get_with_jsonpath(
+            "csv",
+            Some(namespace),
+            CSV_STATUS_JSONPATH,
+            &KubeTarget::new(kubeconfig),
+        ) {
+            Ok(output) => evaluate_csv_status(&output),
+            Err(e) => CsvState::Pending(format!("{:#}", e)),
+        };
+
+        match state {
+            CsvState::Succeeded(names) => {
+                crate::log_info!("ClusterServiceVersion {} Succeeded", names.join(", "));
+                return Ok(());
+            }
+            CsvState::Failed(message) => {
+                return Err(anyhow::anyhow!(
+                    "ClusterServiceVersion failed to install in {}: {}",
+                    namespace,
+                    message
+                ));
+            }
+            CsvState::Pending(status) => {
+                if start.elapsed() >= timeout {
+                    return Err(anyhow::anyhow!(
+                        "ClusterServiceVersion in {} did not reach Succeeded within {}s ({})",
+                        namespace,
+                        CSV_SUCCEEDED_TIMEOUT_SECS,
+                        status
+                    ));
+                }
+            }
+        }
+
+        std::thread::sleep(Duration::from_secs(5));
+    }
+}
+
+/// Deployments in the `olm` namespace that must be Available before bundles can be installed
+const OLM_DEPLOYMENTS: &[&str] = &["catalog-operator", "olm-operator", "packageserver"];
+
+/// Install OLM (Operator Lifecycle Manager), waiting up to `ready_timeout`
+/// seconds for each of its deployments to become Available
+pub fn install_olm(ready_timeout: u64, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
+
     // Check if OLM is already installed
     if is_olm_installed(kubeconfig) {
         crate::log_info!("OLM is already installed, skipping installation");
@@ -186,14 +359,12 @@ pub fn install_olm(kubeconfig: Option<&Path>) -> Result<()> {
     crate::log_info!("Installing latest OLM...");
 
     // Get the latest OLM release version from GitHub API
-    let client = reqwest::blocking::Client::new();
-    let response = client
-        .get("https://api.github.com/repos/operator-framework/operator-lifecycle-manager/releases/latest")
-        .header("User-Agent", "kueue-dev")
-        .send()
-        .context("Failed to fetch OLM releases")?;
-
-    let release: serde_json::Value = response.json()?;
+    let release = http::download_text(
+        "https://api.github.com/repos/operator-framework/operator-lifecycle-manager/releases/latest",
+    )
+    .context("Failed to fetch OLM releases")?;
+    let release: serde_json::Value =
+        serde_json::from_str(&release).context("Failed to parse OLM release")?;
     let olm_version = release["tag_name"]
         .as_str()
         .ok_or_else(|| anyhow::anyhow!("Failed to get latest OLM version"))?;
@@ -212,14 +383,8 @@ pub fn install_olm(kubeconfig: Option<&Path>) -> Result<()> {
 
     // Apply CRDs with server-side apply
     crate::log_info!("Applying OLM CRDs...");
-    let crds_yaml = reqwest::blocking::get(&crds_url)
-        .context("Failed to download OLM CRDs")?
-        .text()?;
-
-    let mut temp_crds = tempfile::NamedTempFile::new()?;
-    use std::io::Write;
-    temp_crds.write_all(crds_yaml.as_bytes())?;
-    temp_crds.flush()?;
+    let temp_crds = tempfile::NamedTempFile::new()?;
+    http::download_to_file(&crds_url, temp_crds.path()).context("Failed to download OLM CRDs")?;
 
     kubectl::run_kubectl(
         &[
@@ -228,18 +393,14 @@ pub fn install_olm(kubeconfig: Option<&Path>) -> Result<()> {
             "-f",
             temp_crds.path().to_str().unwrap(),
         ],
-        kubeconfig,
+        &target,
     )?;
 
     // Apply OLM manifests
     crate::log_info!("Applying OLM manifests...");
-    let olm_yaml = reqwest::blocking::get(&olm_url)
-        .context("Failed to download OLM manifests")?
-        .text()?;
-
-    let mut temp_olm = tempfile::NamedTempFile::new()?;
-    temp_olm.write_all(olm_yaml.as_bytes())?;
-    temp_olm.flush()?;
+    let temp_olm = tempfile::NamedTempFile::new()?;
+    http::download_to_file(&olm_url, temp_olm.path())
+        .context("Failed to download OLM manifests")?;
 
     kubectl::run_kubectl(
         &[
@@ -248,47 +409,75 @@ pub fn install_olm(kubeconfig: Option<&Path>) -> Result<()> {
             "-f",
             temp_olm.path().to_str().unwrap(),
         ],
-        kubeconfig,
+        &target,
     )?;
 
     crate::log_info!("Waiting for OLM to be ready...");
 
     // Wait for OLM deployments
     std::thread::sleep(std::time::Duration::from_secs(5));
+    wait_for_olm_deployments(ready_timeout, kubeconfig)?;
 
-    kubectl::wait_for_condition(
-        "deployment/catalog-operator",
-        "condition=Available",
-        Some("olm"),
-        "300s",
-        kubeconfig,
-    )
-    .ok();
+    crate::log_info!("OLM installed successfully");
+    Ok(())
+}
 
-    kubectl::wait_for_condition(
-        "deployment/olm-operator",
-        "condition=Available",
-        Some("olm"),
-        "300s",
-        kubeconfig,
-    )
-    .ok();
+/// Wait for every OLM deployment, then fail naming each one that isn't Available
+fn wait_for_olm_deployments(ready_timeout: u64, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
+    let timeout = format!("{}s", ready_timeout);
+    let mut failures = Vec::new();
+
+    for deployment in OLM_DEPLOYMENTS {
+        let resource = format!("deployment/{}", deployment);
+        if let Err(e) = kubectl::wait_for_condition(
+            &resource,
+            "condition=Available",
+            Some("olm"),
+            &timeout,
+            &target,
+        ) {
+            crate::log_error!("{} is not Available: {:#}", deployment, e);
+            let selector = format!("app={}", deployment);
+            let pods = kubectl::run_kubectl_output(
+                &["get", "pods", "-n", "olm", "-l", &selector, "-o", "wide"],
+                &target,
+            )
+            .unwrap_or_else(|e| format!("(could not list pods: {:#})", e));
+            failures.push((*deployment, pods));
+        }
+    }
 
-    kubectl::wait_for_condition(
-        "deployment/packageserver",
-        "condition=Available",
-        Some("olm"),
-        "300s",
-        kubeconfig,
-    )
-    .ok();
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(anyhow::anyhow!(olm_not_ready_message(
+            ready_timeout,
+            &failures
+        )))
+    }
+}
 
-    crate::log_info!("OLM installed successfully");
-    Ok(())
+/// Error for OLM deployments that didn't become Available, with their pod status
+fn olm_not_ready_message(ready_timeout: u64, failures: &[(&str, String)]) -> String {
+    let names: Vec<&str> = failures.iter().map(|(name, _)| *name).collect();
+    let mut message = format!(
+        "OLM is not ready: {} not Available after {}s (behavior.olm_ready_timeout)",
+        names.join(", "),
+        ready_timeout
+    );
+    for (name, pods) in failures {
+        message.push_str(&format!("\n\n{} pods in olm:\n{}", name, pods.trim_end()));
+    }
+    message
 }
 
 /// Helper function to run operator-sdk run bundle with retry on catalog exists error
-fn run_bundle_with_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Result<bool> {
+fn run_bundle_with_retry(
+    bundle_image: &str,
+    namespace: &str,
+    kubeconfig: Option<&Path>,
+) -> Result<bool> {
     // Check if operator is already running (catalog source exists)
     let catalog_check = kubectl::run_kubectl_output(
         &[
@@ -296,9 +485,9 @@ fn run_bundle_with_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Resul
             "catalogsource",
             "kueue-operator-catalog",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
         ],
-        kubeconfig,
+        &KubeTarget::new(kubeconfig),
     );
 
     // If catalog source exists, go directly to cleanup and retry
@@ -306,7 +495,7 @@ fn run_bundle_with_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Resul
         crate::log_warn!("Operator catalog source already exists from previous deployment");
         crate::log_info!("Running cleanup before attempting installation...");
 
-        return cleanup_and_retry(bundle_image, kubeconfig);
+        return cleanup_and_retry(bundle_image, namespace, kubeconfig);
     }
 
     // Catalog doesn't exist, proceed with normal installation
@@ -320,7 +509,7 @@ fn run_bundle_with_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Resul
         "bundle",
         bundle_image,
         "--namespace",
-        "openshift-kueue-operator",
+        namespace,
         "--timeout",
         "10m",
     ]);
@@ -336,21 +525,27 @@ fn run_bundle_with_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Resul
     let stdout = String::from_utf8_lossy(&output.stdout);
 
     if stderr.contains("already exists") || stdout.contains("already exists") {
-        return cleanup_and_retry(bundle_image, kubeconfig);
+        return cleanup_and_retry(bundle_image, namespace, kubeconfig);
     }
 
     // For other errors, return the original error
     Err(anyhow::anyhow!(
-        "operator-sdk run bundle failed:\n{}\n{}",
+        "operator-sdk run bundle failed:\n{}\n{}{}",
         stdout,
-        stderr
+        stderr,
+        operator_sdk_flag_hint(&stderr)
     ))
 }
 
 /// Cleanup existing operator installation and retry bundle installation
-fn cleanup_and_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Result<bool> {
+fn cleanup_and_retry(
+    bundle_image: &str,
+    namespace: &str,
+    kubeconfig: Option<&Path>,
+) -> Result<bool> {
     crate::log_info!(
-        "Running cleanup: operator-sdk cleanup kueue-operator -n openshift-kueue-operator"
+        "Running cleanup: operator-sdk cleanup kueue-operator -n {}",
+        namespace
     );
 
     // Run cleanup
@@ -359,12 +554,7 @@ fn cleanup_and_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Result<bo
         cleanup_cmd.env("KUBECONFIG", kc);
     }
 
-    cleanup_cmd.args([
-        "cleanup",
-        "kueue-operator",
-        "-n",
-        "openshift-kueue-operator",
-    ]);
+    cleanup_cmd.args(["cleanup", "kueue-operator", "-n", namespace]);
 
     let cleanup_output = cleanup_cmd
         .output()
@@ -392,7 +582,7 @@ fn cleanup_and_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Result<bo
         "bundle",
         bundle_image,
         "--namespace",
-        "openshift-kueue-operator",
+        namespace,
         "--timeout",
         "10m",
     ]);
@@ -406,58 +596,282 @@ fn cleanup_and_retry(bundle_image: &str, kubeconfig: Option<&Path>) -> Result<bo
         let retry_stderr = String::from_utf8_lossy(&retry_output.stderr);
         let retry_stdout = String::from_utf8_lossy(&retry_output.stdout);
         Err(anyhow::anyhow!(
-            "operator-sdk run bundle failed after cleanup:\n{}\n{}",
+            "operator-sdk run bundle failed after cleanup:\n{}\n{}{}",
             retry_stdout,
-            retry_stderr
+            retry_stderr,
+            operator_sdk_flag_hint(&retry_stderr)
         ))
     }
 }
 
-/// Install operator via OLM bundle
+/// Manifest for the namespace the operator is installed into
+fn namespace_yaml(namespace: &str) -> String {
+    format!(
+        r#"apiVersion: v1
+kind: Namespace
+metadata:
+  name: {}
+"#,
+        namespace
+    )
+}
+
+/// Install operator via OLM bundle into `namespace`
 pub fn install_bundle(
     bundle_image: &str,
+    namespace: &str,
     _cluster_name: &str,
     kubeconfig: Option<&Path>,
 ) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing kueue-operator via OLM bundle...");
 
     // Create namespace
-    crate::log_info!("Creating namespace openshift-kueue-operator...");
-    let namespace_yaml = r#"apiVersion: v1
-kind: Namespace
-metadata:
-  name: openshift-kueue-operator
-"#;
-    kubectl::apply_yaml(namespace_yaml, kubeconfig)?;
+    crate::log_info!("Creating namespace {}...", namespace);
+    kubectl::apply_yaml(&namespace_yaml(namespace), &target)?;
+
+    check_operator_sdk_version();
 
     // Use operator-sdk run bundle (with retry on catalog exists error)
     crate::log_info!("Running operator-sdk run bundle...");
 
-    let result = run_bundle_with_retry(bundle_image, kubeconfig)?;
+    let result = run_bundle_with_retry(bundle_image, namespace, kubeconfig)?;
 
-    if result {
-        crate::log_info!("Operator installed successfully via OLM bundle");
-    } else {
+    if !result {
         return Err(anyhow::anyhow!(
             "operator-sdk run bundle failed after retry"
         ));
     }
 
+    wait_for_csv_succeeded(namespace, kubeconfig)?;
+    crate::log_info!("Operator installed successfully via OLM bundle");
+
     // Show deployment status
     crate::log_info!("Operator deployment status:");
-    kubectl::run_kubectl(
-        &["get", "deployments", "-n", "openshift-kueue-operator"],
-        kubeconfig,
+    kubectl::run_kubectl(&["get", "deployments", "-n", namespace], &target).ok();
+
+    Ok(())
+}
+
+/// Where to install kueue-operator from when operator-sdk is not used
+#[derive(Debug, Clone)]
+pub struct CatalogInstallOptions {
+    /// Catalog (index) image serving the kueue-operator package
+    pub catalog_image: String,
+    /// Package to subscribe to
+    pub package: String,
+    /// Subscription channel (package default channel if unset)
+    pub channel: Option<String>,
+    /// Namespace to install the operator into
+    pub namespace: String,
+}
+
+/// Render the CatalogSource, OperatorGroup and Subscription that install
+/// kueue-operator from a catalog image into the operator namespace
+fn catalog_manifests(options: &CatalogInstallOptions) -> [String; 3] {
+    let catalog_source = format!(
+        r#"apiVersion: operators.coreos.com/v1alpha1
+kind: CatalogSource
+metadata:
+  name: kueue-operator-catalog
+  namespace: {}
+spec:
+  sourceType: grpc
+  image: {}
+  displayName: Kueue Operator
+"#,
+        options.namespace, options.catalog_image
+    );
+
+    let operator_group = format!(
+        r#"apiVersion: operators.coreos.com/v1
+kind: OperatorGroup
+metadata:
+  name: kueue-operator
+  namespace: {}
+spec: {{}}
+"#,
+        options.namespace
+    );
+
+    let channel = options
+        .channel
+        .as_ref()
+        .map(|channel| format!("  channel: {}\n", channel))
+        .unwrap_or_default();
+    let subscription = format!(
+        r#"apiVersion: operators.coreos.com/v1alpha1
+kind: Subscription
+metadata:
+  name: kueue-operator
+  namespace: {namespace}
+spec:
+  name: {package}
+{channel}  source: kueue-operator-catalog
+  sourceNamespace: {namespace}
+  installPlanApproval: Automatic
+"#,
+        namespace = options.namespace,
+        package = options.package,
+        channel = channel
+    );
+
+    [catalog_source, operator_group, subscription]
+}
+
+/// Install kueue-operator through a CatalogSource and Subscription applied
+/// with kubectl, for machines without operator-sdk
+pub fn install_catalog(options: &CatalogInstallOptions, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
+    crate::log_info!(
+        "Installing kueue-operator from catalog image {}...",
+        options.catalog_image
+    );
+
+    // Create namespace
+    crate::log_info!("Creating namespace {}...", options.namespace);
+    kubectl::apply_yaml(&namespace_yaml(&options.namespace), &target)?;
+
+    let [catalog_source, operator_group, subscription] = catalog_manifests(options);
+
+    crate::log_info!("Creating CatalogSource kueue-operator-catalog...");
+    kubectl::apply_yaml(&catalog_source, &target).context("Failed to create CatalogSource")?;
+
+    // A namespace may only have one OperatorGroup; reuse one left by
+    // a previous operator-sdk install
+    let existing_groups = kubectl::run_kubectl_output(
+        &[
+            "get",
+            "operatorgroups",
+            "-n",
+            &options.namespace,
+            "-o",
+            "name",
+        ],
+        &target,
     )
-    .ok();
+    .unwrap_or_default();
+    let existing_groups: Vec<&str> = existing_groups
+        .lines()
+        .filter(|name| *name != "operatorgroup.operators.coreos.com/kueue-operator")
+        .collect();
+    if existing_groups.is_empty() {
+        crate::log_info!("Creating OperatorGroup kueue-operator...");
+        kubectl::apply_yaml(&operator_group, &target).context("Failed to create OperatorGroup")?;
+    } else {
+        crate::log_info!("Using existing {}", existing_groups.join(", "));
+    }
+
+    crate::log_info!("Creating Subscription to package {}...", options.package);
+    kubectl::apply_yaml(&subscription, &target).context("Failed to create Subscription")?;
+
+    wait_for_csv_succeeded(&options.namespace, kubeconfig)?;
+    crate::log_info!("Operator installed successfully via CatalogSource");
+
+    // Show deployment status
+    crate::log_info!("Operator deployment status:");
+    kubectl::run_kubectl(&["get", "deployments", "-n", &options.namespace], &target).ok();
 
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catalog_manifests() {
+        let mut options = CatalogInstallOptions {
+            catalog_image: "quay.io/me/kueue-catalog:dev".to_string(),
+            package: "kueue-operator".to_string(),
+            channel: None,
+            namespace: "kueue-operator-dev".to_string(),
+        };
+
+        let [catalog_source, operator_group, subscription] = catalog_manifests(&options);
+        assert!(catalog_source.contains("kind: CatalogSource\n"));
+        assert!(catalog_source.contains("  image: quay.io/me/kueue-catalog:dev\n"));
+        assert!(operator_group.contains("kind: OperatorGroup\n"));
+        assert!(operator_group.ends_with("  namespace: kueue-operator-dev\nspec: {}\n"));
+        assert!(subscription.contains("  sourceNamespace: kueue-operator-dev\n"));
+        assert!(
+            subscription
+                .contains("spec:\n  name: kueue-operator\n  source: kueue-operator-catalog\n")
+        );
+        assert!(!subscription.contains("channel"));
+
+        options.channel = Some("stable-v1.1".to_string());
+        let [_, _, subscription] = catalog_manifests(&options);
+        assert!(subscription.contains("  name: kueue-operator\n  channel: stable-v1.1\n  source:"));
+    }
+
+    #[test]
+    fn test_olm_not_ready_message() {
+        let failures = [
+            (
+                "olm-operator",
+                "NAME                READY   STATUS\nolm-operator-abc    0/1     CrashLoopBackOff\n".to_string(),
+            ),
+            ("packageserver", "No resources found in olm namespace.".to_string()),
+        ];
+
+        let message = olm_not_ready_message(300, &failures);
+        assert!(
+            message.starts_with(
+                "OLM is not ready: olm-operator, packageserver not Available after 300s"
+            )
+        );
+        assert!(message.contains("olm-operator pods in olm:\nNAME"));
+        assert!(
+            message.ends_with("packageserver pods in olm:\nNo resources found in olm namespace.")
+        );
+    }
+
+    #[test]
+    fn test_evaluate_csv_status() {
+        assert_eq!(
+            evaluate_csv_status(""),
+            CsvState::Pending("no ClusterServiceVersion yet".to_string())
+        );
+        assert_eq!(
+            evaluate_csv_status("kueue-operator.v1.1.0\tInstalling\tInstallWaiting\twaiting\n"),
+            CsvState::Pending("kueue-operator.v1.1.0 is Installing".to_string())
+        );
+        assert_eq!(
+            evaluate_csv_status(
+                "kueue-operator.v1.1.0\tSucceeded\tInstallSucceeded\tinstall strategy completed\n\
+                 cert-manager.v1.16.0\tPending\tCopied\tcopied from openshift-operators\n"
+            ),
+            CsvState::Succeeded(vec!["kueue-operator.v1.1.0".to_string()])
+        );
+        assert_eq!(
+            evaluate_csv_status(
+                "kueue-operator.v1.1.0\tFailed\tInstallCheckFailed\tinstall timeout\n"
+            ),
+            CsvState::Failed(
+                "kueue-operator.v1.1.0: InstallCheckFailed install timeout".to_string()
+            )
+        );
+    }
+
     #[test]
     fn test_olm_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_parse_operator_sdk_version() {
+        let output = r#"operator-sdk version: "v1.39.1", commit: "b8a728e1", kubernetes version: "v1.31.0", go version: "go1.22.7", GOOS: "linux", GOARCH: "amd64""#;
+        assert_eq!(
+            parse_operator_sdk_version(output),
+            Some("v1.39.1".to_string())
+        );
+        assert_eq!(parse_operator_sdk_version("command not found"), None);
+    }
+
+    #[test]
+    fn test_operator_sdk_flag_hint() {
+        assert!(operator_sdk_flag_hint("Error: unknown flag: --timeout").contains("v1.25.0"));
+        assert_eq!(operator_sdk_flag_hint("catalog source not ready"), "");
+    }
 }
@@ -3,8 +3,122 @@
 use crate::config::images::ImageConfig;
 use crate::config::kueue::KueueConfig;
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
 use anyhow::{Context, Result};
 use std::path::Path;
+use std::str::FromStr;
+
+/// Namespace the operator manifests deploy into, and the default for
+/// `operator.namespace` in the config file
+pub const DEFAULT_OPERATOR_NAMESPACE: &str = "openshift-kueue-operator";
+
+/// Leader election lease held by the running operator
+const OPERATOR_LEASE_NAME: &str = "openshift-kueue-operator-lock";
+
+/// Consecutive Ready observations required when the lease is not yet held
+const OPERATOR_READY_CONSECUTIVE_CHECKS: u32 = 3;
+
+/// How long to keep polling when the lease never appears, matching the fixed
+/// delay used before readiness could be observed
+const OPERATOR_FALLBACK_DELAY_SECS: u64 = 30;
+
+/// Operator container in 07_deployment.yaml
+const OPERATOR_CONTAINER: &str = "openshift-kueue-operator";
+
+/// Operator env vars naming the operand and must-gather images
+const OPERAND_IMAGE_ENV: &str = "RELATED_IMAGE_OPERAND_IMAGE";
+const MUST_GATHER_IMAGE_ENV: &str = "RELATED_IMAGE_MUST_GATHER_IMAGE";
+
+/// Overrides applied to the operator manifests before they are applied
+#[derive(Debug, Clone, Default)]
+pub struct OperatorOverrides {
+    /// Replica count instead of the one in 07_deployment.yaml
+    pub replicas: Option<u32>,
+    /// Namespace to deploy the operator into instead of [`DEFAULT_OPERATOR_NAMESPACE`]
+    pub namespace: Option<String>,
+    /// imagePullPolicy for the operator containers; None keeps the manifest's
+    pub image_pull_policy: Option<ImagePullPolicy>,
+}
+
+/// Container `imagePullPolicy`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ImagePullPolicy {
+    #[value(name = "Always")]
+    Always,
+    #[value(name = "IfNotPresent")]
+    IfNotPresent,
+    #[value(name = "Never")]
+    Never,
+}
+
+impl FromStr for ImagePullPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "always" => Ok(ImagePullPolicy::Always),
+            "ifnotpresent" => Ok(ImagePullPolicy::IfNotPresent),
+            "never" => Ok(ImagePullPolicy::Never),
+            _ => Err(anyhow::anyhow!(
+                "Invalid image pull policy: {}. Must be 'Always', 'IfNotPresent', or 'Never'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ImagePullPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ImagePullPolicy::Always => write!(f, "Always"),
+            ImagePullPolicy::IfNotPresent => write!(f, "IfNotPresent"),
+            ImagePullPolicy::Never => write!(f, "Never"),
+        }
+    }
+}
+
+/// Namespace override for deploying into `namespace`; `None` for the
+/// default namespace the manifests already use
+pub fn namespace_override(namespace: &str) -> Option<String> {
+    (namespace != DEFAULT_OPERATOR_NAMESPACE).then(|| namespace.to_string())
+}
+
+impl OperatorOverrides {
+    /// Namespace the operator ends up in
+    pub fn namespace(&self) -> &str {
+        self.namespace
+            .as_deref()
+            .unwrap_or(DEFAULT_OPERATOR_NAMESPACE)
+    }
+}
+
+/// Operator namespace `base` with a per-run suffix, e.g. openshift-kueue-operator-a1b2c3
+pub fn suffixed_namespace(base: &str, suffix: &str) -> Result<String> {
+    let namespace = format!("{}-{}", base, suffix);
+
+    let valid_chars = suffix
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if suffix.is_empty() || !valid_chars || suffix.ends_with('-') || namespace.len() > 63 {
+        return Err(anyhow::anyhow!(
+            "Invalid namespace suffix '{}': use lowercase letters, digits and '-' \
+             (not at the end), keeping '{}' within 63 characters",
+            suffix,
+            namespace
+        ));
+    }
+
+    Ok(namespace)
+}
+
+/// Generate a short suffix that is unique per run
+pub fn generate_namespace_suffix() -> String {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("{:06x}", (nanos / 1_000_000) & 0xff_ffff)
+}
 
 /// Install Kueue operator CRDs
 pub fn install_crds(kubeconfig: Option<&Path>) -> Result<()> {
@@ -20,22 +134,52 @@ pub fn install_crds(kubeconfig: Option<&Path>) -> Result<()> {
     }
 
     // Apply all CRD files in the directory
-    kubectl::run_kubectl(&["apply", "-f", crd_dir.to_str().unwrap()], kubeconfig)
-        .context("Failed to apply CRDs")?;
+    kubectl::run_kubectl(
+        &["apply", "-f", crd_dir.to_str().unwrap()],
+        &KubeTarget::new(kubeconfig),
+    )
+    .context("Failed to apply CRDs")?;
 
     crate::log_info!("CRDs installed successfully");
     Ok(())
 }
 
-/// Install Kueue operator
-pub fn install_operator(image_config: &ImageConfig, kubeconfig: Option<&Path>) -> Result<()> {
-    install_operator_with_config(image_config, None, kubeconfig)
+/// Install Kueue operator into `namespace`, waiting for it to become ready if `wait` is set.
+/// `show_progress` draws a spinner during the waits.
+pub fn install_operator(
+    image_config: &ImageConfig,
+    namespace: &str,
+    ready_timeout_secs: u64,
+    image_pull_policy: Option<ImagePullPolicy>,
+    wait: bool,
+    show_progress: bool,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    let overrides = OperatorOverrides {
+        namespace: namespace_override(namespace),
+        image_pull_policy,
+        ..Default::default()
+    };
+    install_operator_with_config(
+        image_config,
+        None,
+        &overrides,
+        ready_timeout_secs,
+        wait,
+        show_progress,
+        kubeconfig,
+    )
 }
 
-/// Install Kueue operator with optional Kueue CR configuration
+/// Install Kueue operator with optional Kueue CR configuration. With `wait`
+/// unset, returns right after applying instead of waiting for readiness.
 pub fn install_operator_with_config(
     image_config: &ImageConfig,
     kueue_config: Option<&KueueConfig>,
+    overrides: &OperatorOverrides,
+    ready_timeout_secs: u64,
+    wait: bool,
+    show_progress: bool,
     kubeconfig: Option<&Path>,
 ) -> Result<()> {
     crate::log_info!("Installing kueue-operator...");
@@ -64,44 +208,68 @@ pub fn install_operator_with_config(
     // Update deployment file with images
     update_deployment_images(temp_path, operator_image, operand_image, must_gather_image)?;
 
+    if let Some(replicas) = overrides.replicas {
+        update_deployment_replicas(temp_path, replicas)?;
+    }
+
+    if let Some(policy) = overrides.image_pull_policy {
+        update_image_pull_policy(temp_path, policy)?;
+    }
+
+    if let Some(namespace) = &overrides.namespace {
+        update_manifest_namespaces(temp_path, namespace)?;
+    }
+
     // Apply manifests in order
     apply_operator_manifests(temp_path, kubeconfig)?;
 
-    // Wait for operator deployment to be available
-    crate::log_info!("Waiting for operator deployment to be available...");
-    kubectl::wait_for_condition(
-        "deployment/openshift-kueue-operator",
-        "condition=Available",
-        Some("openshift-kueue-operator"),
-        "300s",
-        kubeconfig,
-    )
-    .context("Operator deployment not available")?;
+    if wait {
+        // Wait for operator deployment to be available
+        crate::log_info!("Waiting for operator deployment to be available...");
+        kubectl::wait_for_deployment_available(
+            "openshift-kueue-operator",
+            overrides.namespace(),
+            "300s",
+            &KubeTarget::new(kubeconfig),
+            show_progress,
+        )
+        .context("Operator deployment not available")?;
 
-    crate::log_info!("Operator deployment is available");
+        crate::log_info!("Operator deployment is available");
 
-    // Give the operator time to start its controllers and be ready to reconcile
-    crate::log_info!("Waiting for operator controllers to be ready...");
-    std::thread::sleep(std::time::Duration::from_secs(30));
+        // Wait for the operator to start its controllers and be ready to reconcile
+        wait_for_operator_ready(ready_timeout_secs, overrides.namespace(), kubeconfig)?;
+    }
 
     // Create Kueue CR if config provided
     if let Some(config) = kueue_config {
-        create_kueue_cr(config, kubeconfig)?;
+        create_kueue_cr(config, wait, show_progress, kubeconfig)?;
     }
 
     Ok(())
 }
 
-/// Create Kueue CR from configuration
-pub fn create_kueue_cr(config: &KueueConfig, kubeconfig: Option<&Path>) -> Result<()> {
+/// Create Kueue CR from configuration, then wait for kueue-controller-manager
+/// to become available if `wait` is set
+pub fn create_kueue_cr(
+    config: &KueueConfig,
+    wait: bool,
+    show_progress: bool,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Creating Kueue CR: {}/{}", config.namespace, config.name);
 
     let yaml = config.to_yaml();
 
-    kubectl::apply_yaml(&yaml, kubeconfig).context("Failed to create Kueue CR")?;
+    kubectl::apply_yaml(&yaml, &target).context("Failed to create Kueue CR")?;
 
     crate::log_info!("Kueue CR created successfully");
 
+    if !wait {
+        return Ok(());
+    }
+
     // Wait for operator to reconcile and create the kueue-controller-manager deployment
     crate::log_info!("Waiting for operator to create kueue-controller-manager deployment...");
     wait_for_deployment_to_exist(
@@ -114,20 +282,227 @@ pub fn create_kueue_cr(config: &KueueConfig, kubeconfig: Option<&Path>) -> Resul
 
     // Now wait for the deployment to be available
     crate::log_info!("Waiting for kueue-controller-manager deployment to be available...");
-    kubectl::wait_for_condition(
-        "deployment/kueue-controller-manager",
-        "condition=Available",
-        Some(&config.namespace),
+    kubectl::wait_for_deployment_available(
+        "kueue-controller-manager",
+        &config.namespace,
         "300s",
-        kubeconfig,
+        &target,
+        show_progress,
     )
     .context("Kueue controller-manager deployment did not become available")?;
 
     crate::log_info!("Kueue controller-manager deployment is available");
 
+    // Make sure the operator actually accepted the CR
+    wait_for_kueue_cr_ready(&config.name, &config.namespace, 120, kubeconfig)?;
+
     Ok(())
 }
 
+/// Reconciliation state of a Kueue CR derived from its status conditions
+#[derive(Debug, PartialEq, Eq)]
+enum KueueCrState {
+    /// An `*Available` condition is True and nothing is Degraded
+    Ready,
+    /// A `*Degraded` condition is True, with its message
+    Degraded(String),
+    /// No decisive condition reported yet
+    Pending,
+}
+
+/// Evaluate the status conditions of a Kueue CR from `kubectl get -o json` output
+fn evaluate_kueue_cr_conditions(json: &str) -> KueueCrState {
+    let Ok(cr) = serde_json::from_str::<serde_json::Value>(json) else {
+        return KueueCrState::Pending;
+    };
+    let Some(conditions) = cr["status"]["conditions"].as_array() else {
+        return KueueCrState::Pending;
+    };
+
+    let is_true = |c: &serde_json::Value| c["status"].as_str() == Some("True");
+    let type_ends_with = |c: &serde_json::Value, suffix: &str| {
+        c["type"].as_str().is_some_and(|t| t.ends_with(suffix))
+    };
+
+    if let Some(degraded) = conditions
+        .iter()
+        .find(|c| type_ends_with(c, "Degraded") && is_true(c))
+    {
+        let message = degraded["message"]
+            .as_str()
+            .or_else(|| degraded["reason"].as_str())
+            .unwrap_or("no message");
+        return KueueCrState::Degraded(format!(
+            "{}: {}",
+            degraded["type"].as_str().unwrap_or("Degraded"),
+            message
+        ));
+    }
+
+    if conditions
+        .iter()
+        .any(|c| type_ends_with(c, "Available") && is_true(c))
+    {
+        return KueueCrState::Ready;
+    }
+
+    KueueCrState::Pending
+}
+
+/// Poll the Kueue CR until its status conditions report Available, failing
+/// with the Degraded message if the operator rejects it
+fn wait_for_kueue_cr_ready(
+    name: &str,
+    namespace: &str,
+    timeout_secs: u64,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    use std::time::{Duration, Instant};
+
+    crate::log_info!("Waiting for Kueue CR {} to report Available...", name);
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(timeout_secs);
+
+    loop {
+        let state = kubectl::run_kubectl_output(
+            &["get", "kueue", name, "-n", namespace, "-o", "json"],
+            &KubeTarget::new(kubeconfig),
+        )
+        .map(|json| evaluate_kueue_cr_conditions(&json))
+        .unwrap_or(KueueCrState::Pending);
+
+        match state {
+            KueueCrState::Ready => {
+                crate::log_info!("Kueue CR {} is Available", name);
+                return Ok(());
+            }
+            KueueCrState::Degraded(message) => {
+                return Err(anyhow::anyhow!(
+                    "Kueue CR {} was rejected by the operator: {}",
+                    name,
+                    message
+                ));
+            }
+            KueueCrState::Pending => {}
+        }
+
+        if start.elapsed() >= timeout {
+            crate::log_warn!(
+                "Kueue CR {} did not report an Available condition within {}s; continuing",
+                name,
+                timeout_secs
+            );
+            return Ok(());
+        }
+
+        std::thread::sleep(Duration::from_secs(5));
+    }
+}
+
+/// Wait for the operator to be ready to reconcile after its deployment is Available
+///
+/// The operator is considered ready once it holds the leader election lease, or
+/// once its pods have reported Ready for several consecutive checks. If the lease
+/// doesn't exist, polling stops after the fixed fallback delay instead.
+pub fn wait_for_operator_ready(
+    timeout_secs: u64,
+    namespace: &str,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
+    use std::time::{Duration, Instant};
+
+    crate::log_info!("Waiting for operator controllers to be ready...");
+
+    let start = Instant::now();
+    let timeout = Duration::from_secs(timeout_secs);
+    let mut lease_found = false;
+    let mut ready_streak = 0;
+
+    loop {
+        if let Ok(holder) = kubectl::run_kubectl_output(
+            &[
+                "get",
+                "lease",
+                OPERATOR_LEASE_NAME,
+                "-n",
+                namespace,
+                "-o",
+                "jsonpath={.spec.holderIdentity}",
+            ],
+            &target,
+        ) {
+            lease_found = true;
+            if lease_acquired(&holder) {
+                crate::log_info!("Operator acquired leader election lease ({})", holder);
+                return Ok(());
+            }
+        }
+
+        let pods_ready = kubectl::run_kubectl_output(
+            &[
+                "get",
+                "pods",
+                "-n",
+                namespace,
+                "-l",
+                "name=openshift-kueue-operator",
+                "-o",
+                r#"jsonpath={.items[*].status.conditions[?(@.type=="Ready")].status}"#,
+            ],
+            &target,
+        )
+        .map(|statuses| all_pods_ready(&statuses))
+        .unwrap_or(false);
+
+        ready_streak = if pods_ready { ready_streak + 1 } else { 0 };
+        if ready_streak >= OPERATOR_READY_CONSECUTIVE_CHECKS {
+            crate::log_info!(
+                "Operator pods reported Ready for {} consecutive checks",
+                ready_streak
+            );
+            return Ok(());
+        }
+
+        // Without a lease the polling so far stands in for the fixed delay
+        if !lease_found && start.elapsed() >= Duration::from_secs(OPERATOR_FALLBACK_DELAY_SECS) {
+            break;
+        }
+        if start.elapsed() >= timeout {
+            break;
+        }
+
+        std::thread::sleep(Duration::from_secs(2));
+    }
+
+    if !lease_found {
+        crate::log_warn!(
+            "Leader election lease {} not found after {}s; continuing",
+            OPERATOR_LEASE_NAME,
+            start.elapsed().as_secs()
+        );
+        return Ok(());
+    }
+
+    Err(anyhow::anyhow!(
+        "Timeout after {}s waiting for the operator to acquire lease {}",
+        timeout_secs,
+        OPERATOR_LEASE_NAME
+    ))
+}
+
+/// Whether a lease holderIdentity indicates the lease has been acquired
+fn lease_acquired(holder: &str) -> bool {
+    !holder.trim().is_empty()
+}
+
+/// Whether a space-separated list of pod Ready condition statuses are all True
+fn all_pods_ready(statuses: &str) -> bool {
+    let mut statuses = statuses.split_whitespace().peekable();
+    statuses.peek().is_some() && statuses.all(|status| status == "True")
+}
+
 /// Wait for a deployment to exist (be created by reconciliation)
 fn wait_for_deployment_to_exist(
     name: &str,
@@ -142,8 +517,10 @@ fn wait_for_deployment_to_exist(
 
     loop {
         // Check if deployment exists
-        let result =
-            kubectl::run_kubectl_output(&["get", "deployment", name, "-n", namespace], kubeconfig);
+        let result = kubectl::run_kubectl_output(
+            &["get", "deployment", name, "-n", namespace],
+            &KubeTarget::new(kubeconfig),
+        );
 
         if result.is_ok() {
             crate::log_info!("Deployment {} created", name);
@@ -209,27 +586,8 @@ fn update_deployment_images(
     }
 
     let content = std::fs::read_to_string(&deployment_file)?;
-
-    // Replace operator image
-    let content = content.replace(
-        "image: registry.redhat.io/kueue/kueue-rhel9-operator:latest",
-        &format!("image: {}", operator_image),
-    );
-
-    // Replace operand image in env vars
-    let content = content.replace(
-        "value: registry.redhat.io/kueue/kueue-rhel9:latest",
-        &format!("value: {}", operand_image),
-    );
-
-    // Replace must-gather image in env vars
-    let content = content.replace(
-        "value: registry.redhat.io/kueue/kueue-must-gather-rhel9:latest",
-        &format!("value: {}", must_gather_image),
-    );
-
-    // Set imagePullPolicy to IfNotPresent for kind clusters
-    let content = content.replace("imagePullPolicy: Always", "imagePullPolicy: IfNotPresent");
+    let content = set_deployment_images(&content, operator_image, operand_image, must_gather_image)
+        .with_context(|| format!("Failed to update images in {}", deployment_file.display()))?;
 
     std::fs::write(&deployment_file, content)?;
 
@@ -259,6 +617,198 @@ fn update_deployment_images(
     Ok(())
 }
 
+/// Set the operator container's image and the operand/must-gather image env
+/// vars, whatever images the manifest ships with
+fn set_deployment_images(
+    deployment_yaml: &str,
+    operator_image: &str,
+    operand_image: &str,
+    must_gather_image: &str,
+) -> Result<String> {
+    use serde_yaml::Value;
+
+    let mut deployment: Value = serde_yaml::from_str(deployment_yaml)?;
+    let containers = deployment["spec"]["template"]["spec"]
+        .get_mut("containers")
+        .and_then(Value::as_sequence_mut)
+        .ok_or_else(|| anyhow::anyhow!("No containers in operator deployment manifest"))?;
+
+    let index = match containers
+        .iter()
+        .position(|c| c["name"].as_str() == Some(OPERATOR_CONTAINER))
+    {
+        Some(index) => index,
+        None if containers.len() == 1 => 0,
+        None => {
+            return Err(anyhow::anyhow!(
+                "No '{}' container in operator deployment manifest",
+                OPERATOR_CONTAINER
+            ));
+        }
+    };
+    let container = containers[index]
+        .as_mapping_mut()
+        .ok_or_else(|| anyhow::anyhow!("Malformed operator container"))?;
+    container.insert("image".into(), operator_image.into());
+
+    let env = container
+        .get_mut("env")
+        .and_then(Value::as_sequence_mut)
+        .ok_or_else(|| anyhow::anyhow!("Operator container has no env"))?;
+    for (name, image) in [
+        (OPERAND_IMAGE_ENV, operand_image),
+        (MUST_GATHER_IMAGE_ENV, must_gather_image),
+    ] {
+        let var = env
+            .iter_mut()
+            .filter_map(Value::as_mapping_mut)
+            .find(|var| var.get("name").and_then(Value::as_str) == Some(name))
+            .ok_or_else(|| anyhow::anyhow!("No {} env var in the operator container", name))?;
+        var.insert("value".into(), image.into());
+    }
+
+    Ok(serde_yaml::to_string(&deployment)?)
+}
+
+/// Set the operator deployment's replica count
+fn update_deployment_replicas(temp_dir: &Path, replicas: u32) -> Result<()> {
+    let deployment_file = temp_dir.join("07_deployment.yaml");
+    let content = std::fs::read_to_string(&deployment_file)
+        .with_context(|| format!("Failed to read {}", deployment_file.display()))?;
+
+    std::fs::write(&deployment_file, set_replicas(&content, replicas)?)?;
+
+    crate::log_info!("Operator replicas: {}", replicas);
+    Ok(())
+}
+
+/// Set the imagePullPolicy of the operator deployment's containers
+fn update_image_pull_policy(temp_dir: &Path, policy: ImagePullPolicy) -> Result<()> {
+    let deployment_file = temp_dir.join("07_deployment.yaml");
+    let content = std::fs::read_to_string(&deployment_file)
+        .with_context(|| format!("Failed to read {}", deployment_file.display()))?;
+
+    let Some(content) = set_image_pull_policy(&content, policy) else {
+        crate::log_warn!(
+            "No imagePullPolicy in {}; leaving it unchanged",
+            deployment_file.display()
+        );
+        return Ok(());
+    };
+    std::fs::write(&deployment_file, content)?;
+
+    crate::log_info!("Operator imagePullPolicy: {}", policy);
+    Ok(())
+}
+
+/// Replace every `imagePullPolicy:` field; None if there are none
+fn set_image_pull_policy(deployment_yaml: &str, policy: ImagePullPolicy) -> Option<String> {
+    let policy_re = regex::Regex::new(r"(?m)^(\s*)imagePullPolicy:.*$").unwrap();
+    if !policy_re.is_match(deployment_yaml) {
+        return None;
+    }
+    let replacement = format!("${{1}}imagePullPolicy: {}", policy);
+    Some(
+        policy_re
+            .replace_all(deployment_yaml, replacement.as_str())
+            .into_owned(),
+    )
+}
+
+/// Replace the first `replicas:` field, or add one under the top-level `spec:`
+fn set_replicas(deployment_yaml: &str, replicas: u32) -> Result<String> {
+    let replicas_re = regex::Regex::new(r"(?m)^(\s*)replicas:\s*\d+[ \t]*$").unwrap();
+    if replicas_re.is_match(deployment_yaml) {
+        let replacement = format!("${{1}}replicas: {}", replicas);
+        return Ok(replicas_re
+            .replace(deployment_yaml, replacement.as_str())
+            .into_owned());
+    }
+
+    let spec_re = regex::Regex::new(r"(?m)^spec:[ \t]*$").unwrap();
+    let spec = spec_re
+        .find(deployment_yaml)
+        .ok_or_else(|| anyhow::anyhow!("No spec found in operator deployment manifest"))?;
+
+    Ok(format!(
+        "{}\n  replicas: {}{}",
+        &deployment_yaml[..spec.end()],
+        replicas,
+        &deployment_yaml[spec.end()..]
+    ))
+}
+
+/// Move every operator manifest from [`DEFAULT_OPERATOR_NAMESPACE`] into `namespace`
+fn update_manifest_namespaces(temp_dir: &Path, namespace: &str) -> Result<()> {
+    for entry in std::fs::read_dir(temp_dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|s| s.to_str()) != Some("yaml") {
+            continue;
+        }
+
+        let content = std::fs::read_to_string(&path)?;
+        let rewritten = retarget_manifests(&content, namespace)
+            .with_context(|| format!("Failed to rewrite namespace in {}", path.display()))?;
+        std::fs::write(&path, rewritten)?;
+    }
+
+    crate::log_info!("Operator namespace: {}", namespace);
+    Ok(())
+}
+
+/// Rewrite a multi-document manifest so it targets `namespace`.
+/// ClusterRoleBindings are renamed so they do not collide with other instances.
+fn retarget_manifests(yaml: &str, namespace: &str) -> Result<String> {
+    use serde::Deserialize;
+    use serde_yaml::Value;
+
+    let mut documents = Vec::new();
+    for document in serde_yaml::Deserializer::from_str(yaml) {
+        let mut value = Value::deserialize(document)?;
+        if value.is_null() {
+            continue;
+        }
+
+        let kind = value["kind"].as_str().unwrap_or_default().to_string();
+        if let Some(metadata) = value.get_mut("metadata").and_then(Value::as_mapping_mut) {
+            let name = metadata
+                .get("name")
+                .and_then(Value::as_str)
+                .map(String::from);
+
+            if kind == "Namespace" && name.as_deref() == Some(DEFAULT_OPERATOR_NAMESPACE) {
+                metadata.insert("name".into(), namespace.into());
+            }
+            if let Some(name) = name.filter(|_| kind == "ClusterRoleBinding") {
+                let renamed = if name.contains(DEFAULT_OPERATOR_NAMESPACE) {
+                    name.replace(DEFAULT_OPERATOR_NAMESPACE, namespace)
+                } else {
+                    format!("{}-{}", name, namespace)
+                };
+                metadata.insert("name".into(), renamed.into());
+            }
+            if metadata.get("namespace").and_then(Value::as_str) == Some(DEFAULT_OPERATOR_NAMESPACE)
+            {
+                metadata.insert("namespace".into(), namespace.into());
+            }
+        }
+
+        if let Some(subjects) = value.get_mut("subjects").and_then(Value::as_sequence_mut) {
+            for subject in subjects.iter_mut().filter_map(Value::as_mapping_mut) {
+                if subject.get("namespace").and_then(Value::as_str)
+                    == Some(DEFAULT_OPERATOR_NAMESPACE)
+                {
+                    subject.insert("namespace".into(), namespace.into());
+                }
+            }
+        }
+
+        documents.push(serde_yaml::to_string(&value)?);
+    }
+
+    Ok(documents.join("---\n"))
+}
+
 /// Apply operator manifests in order
 fn apply_operator_manifests(temp_dir: &Path, kubeconfig: Option<&Path>) -> Result<()> {
     let manifests = vec![
@@ -283,7 +833,7 @@ fn apply_operator_manifests(temp_dir: &Path, kubeconfig: Option<&Path>) -> Resul
         crate::log_info!("Applying {}...", manifest);
         kubectl::run_kubectl(
             &["apply", "-f", manifest_path.to_str().unwrap()],
-            kubeconfig,
+            &KubeTarget::new(kubeconfig),
         )?;
     }
 
@@ -292,8 +842,235 @@ fn apply_operator_manifests(temp_dir: &Path, kubeconfig: Option<&Path>) -> Resul
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_operator_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_kueue_cr_available() {
+        let json = r#"{"status":{"conditions":[
+            {"type":"KueueAvailable","status":"True"},
+            {"type":"KueueDegraded","status":"False"}]}}"#;
+        assert_eq!(evaluate_kueue_cr_conditions(json), KueueCrState::Ready);
+    }
+
+    #[test]
+    fn test_kueue_cr_degraded() {
+        let json = r#"{"status":{"conditions":[
+            {"type":"Available","status":"True"},
+            {"type":"Degraded","status":"True","reason":"InvalidConfig",
+             "message":"unknown framework Foo"}]}}"#;
+        assert_eq!(
+            evaluate_kueue_cr_conditions(json),
+            KueueCrState::Degraded("Degraded: unknown framework Foo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_kueue_cr_pending() {
+        assert_eq!(
+            evaluate_kueue_cr_conditions(r#"{"status":{}}"#),
+            KueueCrState::Pending
+        );
+        assert_eq!(
+            evaluate_kueue_cr_conditions(
+                r#"{"status":{"conditions":[{"type":"Available","status":"False"}]}}"#
+            ),
+            KueueCrState::Pending
+        );
+        assert_eq!(
+            evaluate_kueue_cr_conditions("garbage"),
+            KueueCrState::Pending
+        );
+    }
+
+    #[test]
+    fn test_set_replicas() {
+        let yaml = "kind: Deployment\nspec:\n  replicas: 1\n  selector:\n    matchLabels: {}\n";
+        assert_eq!(
+            set_replicas(yaml, 3).unwrap(),
+            "kind: Deployment\nspec:\n  replicas: 3\n  selector:\n    matchLabels: {}\n"
+        );
+
+        // No replicas field: add one under spec
+        let yaml = "kind: Deployment\nspec:\n  selector: {}\n";
+        assert_eq!(
+            set_replicas(yaml, 2).unwrap(),
+            "kind: Deployment\nspec:\n  replicas: 2\n  selector: {}\n"
+        );
+
+        assert!(set_replicas("kind: Deployment\n", 2).is_err());
+    }
+
+    #[test]
+    fn test_set_deployment_images() {
+        let yaml = r#"apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: openshift-kueue-operator
+spec:
+  template:
+    spec:
+      containers:
+        - name: openshift-kueue-operator
+          image: registry.redhat.io/kueue/kueue-rhel9-operator:v1.1
+          imagePullPolicy: Always
+          env:
+            - name: OPERATOR_NAME
+              value: openshift-kueue-operator
+            - name: RELATED_IMAGE_OPERAND_IMAGE
+              value: registry.redhat.io/kueue/kueue-rhel9:v1.1
+            - name: RELATED_IMAGE_MUST_GATHER_IMAGE
+              value: registry.redhat.io/kueue/kueue-must-gather-rhel9:v1.1
+"#;
+        let updated = set_deployment_images(
+            yaml,
+            "quay.io/me/operator:dev",
+            "quay.io/me/operand:dev",
+            "quay.io/me/must-gather:dev",
+        )
+        .unwrap();
+        let deployment: serde_yaml::Value = serde_yaml::from_str(&updated).unwrap();
+        let container = &deployment["spec"]["template"]["spec"]["containers"][0];
+        assert_eq!(container["image"], "quay.io/me/operator:dev");
+        assert_eq!(container["imagePullPolicy"], "Always");
+        assert_eq!(container["env"][0]["value"], "openshift-kueue-operator");
+        assert_eq!(container["env"][1]["value"], "quay.io/me/operand:dev");
+        assert_eq!(container["env"][2]["value"], "quay.io/me/must-gather:dev");
+
+        // Forks ship manifests pointing at their own registry
+        let fork = yaml.replace("registry.redhat.io/kueue", "registry.example.com:5000/fork");
+        let updated = set_deployment_images(
+            &fork,
+            "quay.io/me/operator:dev",
+            "quay.io/me/operand:dev",
+            "quay.io/me/must-gather:dev",
+        )
+        .unwrap();
+        assert!(!updated.contains("registry.example.com"));
+        let deployment: serde_yaml::Value = serde_yaml::from_str(&updated).unwrap();
+        let container = &deployment["spec"]["template"]["spec"]["containers"][0];
+        assert_eq!(container["image"], "quay.io/me/operator:dev");
+        assert_eq!(container["env"][1]["value"], "quay.io/me/operand:dev");
+
+        let without_env = yaml.replace("RELATED_IMAGE_MUST_GATHER_IMAGE", "OTHER");
+        let err = set_deployment_images(&without_env, "a", "b", "c").unwrap_err();
+        assert!(err.to_string().contains("RELATED_IMAGE_MUST_GATHER_IMAGE"));
+    }
+
+    #[test]
+    fn test_set_image_pull_policy() {
+        let yaml = "containers:\n  - image: a\n    imagePullPolicy: Always\n  - image: b\n    imagePullPolicy: IfNotPresent\n";
+        assert_eq!(
+            set_image_pull_policy(yaml, ImagePullPolicy::Never).unwrap(),
+            "containers:\n  - image: a\n    imagePullPolicy: Never\n  - image: b\n    imagePullPolicy: Never\n"
+        );
+        assert_eq!(
+            set_image_pull_policy("containers: []\n", ImagePullPolicy::Always),
+            None
+        );
+
+        assert_eq!(
+            ImagePullPolicy::from_str("ifnotpresent").unwrap(),
+            ImagePullPolicy::IfNotPresent
+        );
+        assert_eq!(ImagePullPolicy::IfNotPresent.to_string(), "IfNotPresent");
+        assert!(ImagePullPolicy::from_str("Sometimes").is_err());
+    }
+
+    #[test]
+    fn test_suffixed_namespace() {
+        assert_eq!(
+            suffixed_namespace(DEFAULT_OPERATOR_NAMESPACE, "a1b2c3").unwrap(),
+            "openshift-kueue-operator-a1b2c3"
+        );
+        assert_eq!(
+            suffixed_namespace("kueue-operator", "a1b2c3").unwrap(),
+            "kueue-operator-a1b2c3"
+        );
+        let base = DEFAULT_OPERATOR_NAMESPACE;
+        assert!(suffixed_namespace(base, "").is_err());
+        assert!(suffixed_namespace(base, "Upper").is_err());
+        assert!(suffixed_namespace(base, "trailing-").is_err());
+        assert!(suffixed_namespace(base, &"x".repeat(40)).is_err());
+
+        let generated = generate_namespace_suffix();
+        assert!(suffixed_namespace(base, &generated).is_ok());
+    }
+
+    #[test]
+    fn test_namespace_override() {
+        assert_eq!(namespace_override(DEFAULT_OPERATOR_NAMESPACE), None);
+        assert_eq!(
+            namespace_override("kueue-operator-dev").as_deref(),
+            Some("kueue-operator-dev")
+        );
+    }
+
+    #[test]
+    fn test_retarget_manifests() {
+        let yaml = "\
+apiVersion: v1
+kind: Namespace
+metadata:
+  name: openshift-kueue-operator
+---
+apiVersion: rbac.authorization.k8s.io/v1
+kind: ClusterRoleBinding
+metadata:
+  name: openshift-kueue-operator
+roleRef:
+  kind: ClusterRole
+  name: openshift-kueue-operator
+subjects:
+- kind: ServiceAccount
+  name: openshift-kueue-operator
+  namespace: openshift-kueue-operator
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: openshift-kueue-operator
+  namespace: openshift-kueue-operator
+";
+        let ns = "openshift-kueue-operator-ab12";
+        let rewritten = retarget_manifests(yaml, ns).unwrap();
+        let docs: Vec<serde_yaml::Value> = rewritten
+            .split("---\n")
+            .map(|d| serde_yaml::from_str(d).unwrap())
+            .collect();
+
+        assert_eq!(docs.len(), 3);
+        assert_eq!(docs[0]["metadata"]["name"].as_str(), Some(ns));
+        assert_eq!(docs[1]["metadata"]["name"].as_str(), Some(ns));
+        // The shared ClusterRole keeps its name
+        assert_eq!(
+            docs[1]["roleRef"]["name"].as_str(),
+            Some("openshift-kueue-operator")
+        );
+        assert_eq!(docs[1]["subjects"][0]["namespace"].as_str(), Some(ns));
+        assert_eq!(
+            docs[2]["metadata"]["name"].as_str(),
+            Some("openshift-kueue-operator")
+        );
+        assert_eq!(docs[2]["metadata"]["namespace"].as_str(), Some(ns));
+    }
+
+    #[test]
+    fn test_lease_acquired() {
+        assert!(lease_acquired("openshift-kueue-operator-7d9f_1234"));
+        assert!(!lease_acquired(""));
+        assert!(!lease_acquired("  \n"));
+    }
+
+    #[test]
+    fn test_all_pods_ready() {
+        assert!(all_pods_ready("True"));
+        assert!(all_pods_ready("True True"));
+        assert!(!all_pods_ready("True False"));
+        assert!(!all_pods_ready(""));
+    }
 }
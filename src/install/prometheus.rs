@@ -1,17 +1,20 @@
 //! Prometheus operator installation
 
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
+use crate::utils::http;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install Prometheus operator and create a Prometheus instance
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing Prometheus Operator {}...", version);
 
     // Check if prometheus-operator is already installed
     let ns_check = kubectl::run_kubectl_output(
         &["get", "deployment", "prometheus-operator", "-n", "default"],
-        kubeconfig,
+        &target,
     );
 
     if ns_check.is_ok() {
@@ -27,16 +30,11 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
     crate::log_info!("Downloading Prometheus Operator manifest...");
 
     // Download and apply the bundle (includes CRDs and operator)
-    let bundle_yaml = reqwest::blocking::get(&bundle_url)
-        .context("Failed to download Prometheus Operator bundle")?
-        .text()
-        .context("Failed to read Prometheus Operator bundle")?;
+    let temp_file = tempfile::NamedTempFile::new()?;
+    http::download_to_file(&bundle_url, temp_file.path())
+        .context("Failed to download Prometheus Operator bundle")?;
 
     // Use server-side apply to avoid annotation size limits
-    let mut temp_file = tempfile::NamedTempFile::new()?;
-    use std::io::Write;
-    temp_file.write_all(bundle_yaml.as_bytes())?;
-    temp_file.flush()?;
 
     kubectl::run_kubectl(
         &[
@@ -45,7 +43,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
             "-f",
             temp_file.path().to_str().unwrap(),
         ],
-        kubeconfig,
+        &target,
     )?;
 
     crate::log_info!("Waiting for Prometheus Operator deployment to be created...");
@@ -67,7 +65,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
             "-p",
             r#"[{"op":"add","path":"/spec/template/spec/containers/0/args/-","value":"--log-level=debug"}]"#,
         ],
-        kubeconfig,
+        &target,
     )
     .ok(); // Ignore errors if already patched
 
@@ -77,7 +75,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("default"),
         "300s",
-        kubeconfig,
+        &target,
     )?;
 
     crate::log_info!("Prometheus Operator installed successfully");
@@ -90,6 +88,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
 
 /// Create Prometheus instance with RBAC
 fn create_prometheus_instance(kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Creating Prometheus instance...");
 
     // Service Account
@@ -160,29 +159,22 @@ spec:
 "#;
 
     // Apply all resources
-    kubectl::apply_yaml(service_account_yaml, kubeconfig)
+    kubectl::apply_yaml(service_account_yaml, &target)
         .context("Failed to create Prometheus ServiceAccount")?;
 
-    kubectl::apply_yaml(cluster_role_yaml, kubeconfig)
+    kubectl::apply_yaml(cluster_role_yaml, &target)
         .context("Failed to create Prometheus ClusterRole")?;
 
-    kubectl::apply_yaml(cluster_role_binding_yaml, kubeconfig)
+    kubectl::apply_yaml(cluster_role_binding_yaml, &target)
         .context("Failed to create Prometheus ClusterRoleBinding")?;
 
-    kubectl::apply_yaml(prometheus_instance_yaml, kubeconfig)
+    kubectl::apply_yaml(prometheus_instance_yaml, &target)
         .context("Failed to create Prometheus instance")?;
 
     crate::log_info!("Waiting for Prometheus pods to be ready...");
 
     // Wait for the statefulset and pods
-    kubectl::wait_for_condition(
-        "pod",
-        "condition=ready",
-        Some("default"),
-        "300s",
-        kubeconfig,
-    )
-    .ok(); // Ignore errors, might take time
+    kubectl::wait_for_condition("pod", "condition=ready", Some("default"), "300s", &target).ok(); // Ignore errors, might take time
 
     crate::log_info!("Prometheus instance created successfully");
     Ok(())
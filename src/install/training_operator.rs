@@ -1,15 +1,17 @@
 //! Kubeflow Training Operator installation
 
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
 use anyhow::{Context, Result};
 use std::path::Path;
 
 /// Install Kubeflow Training Operator (standalone mode)
 pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
+    let target = KubeTarget::new(kubeconfig);
     crate::log_info!("Installing Kubeflow Training Operator {}...", version);
 
     // Check if Training Operator namespace already exists
-    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "kubeflow"], kubeconfig);
+    let ns_check = kubectl::run_kubectl_output(&["get", "namespace", "kubeflow"], &target);
 
     if ns_check.is_ok() {
         crate::log_info!(
@@ -31,11 +33,8 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
     );
 
     // Use kubectl apply -k for kustomize-based installation
-    kubectl::run_kubectl(
-        &["apply", "--server-side", "-k", &kustomize_url],
-        kubeconfig,
-    )
-    .context("Failed to apply Training Operator manifest")?;
+    kubectl::run_kubectl(&["apply", "--server-side", "-k", &kustomize_url], &target)
+        .context("Failed to apply Training Operator manifest")?;
 
     crate::log_info!("Waiting for Training Operator controller to be ready...");
 
@@ -45,7 +44,7 @@ pub fn install(version: &str, kubeconfig: Option<&Path>) -> Result<()> {
         "condition=Available",
         Some("kubeflow"),
         "300s",
-        kubeconfig,
+        &target,
     )
     .context("Training Operator deployment not ready")?;
 
@@ -1,10 +1,14 @@
 //! Upstream Kueue installation via kustomize or helm
 
 use anyhow::{Context, Result, anyhow};
+use regex::Regex;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 
 use crate::k8s::kubectl;
+use crate::k8s::target::KubeTarget;
 
 /// Default upstream source path (placeholder - user should set via CLI or config)
 pub const DEFAULT_UPSTREAM_SOURCE: &str = "/path/to/kueue/upstream/src";
@@ -18,6 +22,18 @@ pub const DEFAULT_RELEASE_NAME: &str = "kueue";
 /// Default namespace for upstream kueue
 pub const DEFAULT_NAMESPACE: &str = "kueue-system";
 
+/// Key Kueue CRDs that must be established before the controller can start
+pub const KUEUE_CRDS: &[&str] = &[
+    "workloads.kueue.x-k8s.io",
+    "clusterqueues.kueue.x-k8s.io",
+    "localqueues.kueue.x-k8s.io",
+    "resourceflavors.kueue.x-k8s.io",
+    "admissionchecks.kueue.x-k8s.io",
+];
+
+/// Default time to wait for the Kueue CRDs to be established
+pub const DEFAULT_CRD_TIMEOUT_SECS: u64 = 120;
+
 /// Options for deploying upstream kueue via kustomize
 pub struct KustomizeOptions {
     /// Path to upstream kueue source
@@ -30,6 +46,12 @@ pub struct KustomizeOptions {
     pub namespace: String,
     /// Path to kubeconfig
     pub kubeconfig: Option<PathBuf>,
+    /// Wait for the deployment to become available after applying
+    pub wait: bool,
+    /// Kill `kubectl apply` if it runs longer than this
+    pub apply_timeout: Duration,
+    /// Draw spinners while applying and waiting (`behavior.show_progress`)
+    pub show_progress: bool,
 }
 
 /// Options for deploying upstream kueue via helm
@@ -44,8 +66,16 @@ pub struct HelmOptions {
     pub values_file: Option<PathBuf>,
     /// Additional --set values
     pub set_values: Vec<String>,
+    /// Additional --set-string values, kept as strings by helm
+    pub set_string_values: Vec<String>,
+    /// Additional --set-file values (KEY=path), set to the file's contents
+    pub set_file_values: Vec<String>,
     /// Path to kubeconfig
     pub kubeconfig: Option<PathBuf>,
+    /// Wait for the deployment to become available after applying
+    pub wait: bool,
+    /// Draw a spinner while waiting (`behavior.show_progress`)
+    pub show_progress: bool,
 }
 
 /// Resolve the upstream source path
@@ -53,15 +83,27 @@ pub struct HelmOptions {
 /// 1. Explicit path provided via CLI (--source)
 /// 2. Path from settings config (defaults.upstream_source)
 /// 3. Current working directory (if it looks like a kueue source tree)
+///
+/// A source inside the kueue-operator source tree is ambiguous: it is rejected
+/// when picked up from the current directory, and a warning (an error with
+/// `strict`) when given explicitly.
 pub fn resolve_upstream_source(
     cli_path: Option<&str>,
     settings_path: Option<&str>,
+    strict: bool,
 ) -> Result<PathBuf> {
+    let operator_source = crate::utils::get_operator_source_path()
+        .ok()
+        .flatten()
+        .map(|p| p.canonicalize().unwrap_or(p));
+
     // If explicit CLI path provided, use it
     if let Some(path) = cli_path {
         let p = PathBuf::from(path);
         if p.exists() {
-            return Ok(p.canonicalize().unwrap_or(p));
+            let p = p.canonicalize().unwrap_or(p);
+            check_explicit_source(&p, operator_source.as_deref(), strict)?;
+            return Ok(p);
         }
         return Err(anyhow!("Upstream source path does not exist: {}", path));
     }
@@ -70,7 +112,9 @@ pub fn resolve_upstream_source(
     if let Some(path) = settings_path {
         let p = PathBuf::from(path);
         if p.exists() {
-            return Ok(p.canonicalize().unwrap_or(p));
+            let p = p.canonicalize().unwrap_or(p);
+            check_explicit_source(&p, operator_source.as_deref(), strict)?;
+            return Ok(p);
         }
         return Err(anyhow!(
             "Upstream source path from config does not exist: {}",
@@ -83,16 +127,67 @@ pub fn resolve_upstream_source(
     if cwd.join("config/default/kustomization.yaml").exists()
         || cwd.join("charts/kueue/Chart.yaml").exists()
     {
+        if let Some(operator_root) = operator_tree_root(&cwd, operator_source.as_deref()) {
+            return Err(anyhow!(
+                "The current directory {} looks like an upstream kueue source, but it is inside \
+                 the kueue-operator source ({}).\n\
+                 Pass --upstream-source (or set defaults.upstream_source) to choose the upstream tree explicitly, \
+                 e.g. --upstream-source {}",
+                cwd.display(),
+                operator_root.display(),
+                operator_root.join("upstream/kueue/src").display()
+            ));
+        }
         return Ok(cwd);
     }
 
     Err(anyhow!(
         "No upstream kueue source specified.\n\
-         Specify the path with --source or set defaults.upstream_source in config.\n\
-         Example: kueue-dev deploy upstream kustomize --source /path/to/kueue/upstream/src"
+         Specify the path with --upstream-source or set defaults.upstream_source in config.\n\
+         Example: kueue-dev deploy upstream kustomize --upstream-source /path/to/kueue/upstream/src"
     ))
 }
 
+/// Warn (or fail when `strict`) if an explicitly chosen upstream source is
+/// inside the kueue-operator source tree
+fn check_explicit_source(
+    source: &Path,
+    operator_source: Option<&Path>,
+    strict: bool,
+) -> Result<()> {
+    let Some(operator_root) = operator_tree_root(source, operator_source) else {
+        return Ok(());
+    };
+
+    let message = format!(
+        "Upstream source {} is inside the kueue-operator source ({}); make sure it is the upstream kueue tree",
+        source.display(),
+        operator_root.display()
+    );
+    if strict {
+        return Err(anyhow!("{} (--strict)", message));
+    }
+    crate::log_warn!("{}", message);
+    Ok(())
+}
+
+/// `source` itself when it is the root of the kueue-operator source tree: the
+/// configured operator source, or a directory with the operator's `deploy/crd`
+/// directory or `go.mod`. Subdirectories are not flagged, so the vendored
+/// upstream tree under `upstream/` (the usual upstream source) is accepted.
+fn operator_tree_root(source: &Path, operator_source: Option<&Path>) -> Option<PathBuf> {
+    let is_configured_root = operator_source
+        .is_some_and(|op| source == op || op.canonicalize().is_ok_and(|op| source == op.as_path()));
+    let is_operator_tree = source.join("deploy/crd").is_dir()
+        || std::fs::read_to_string(source.join("go.mod")).is_ok_and(|go_mod| {
+            go_mod
+                .lines()
+                .next()
+                .is_some_and(|module| module.contains("kueue-operator"))
+        });
+    (is_configured_root || is_operator_tree).then(|| source.to_path_buf())
+}
+
 /// Validate that the upstream source has the expected structure
 pub fn validate_upstream_source(source_path: &Path) -> Result<()> {
     // Check for kustomize config
@@ -176,25 +271,44 @@ pub fn deploy_kustomize(options: &KustomizeOptions) -> Result<()> {
         }
 
         // Build and apply from temp overlay
-        apply_kustomize_build(&temp_overlay, options.kubeconfig.as_deref())?;
+        apply_kustomize_build(
+            &temp_overlay,
+            options.kubeconfig.as_deref(),
+            options.apply_timeout,
+            options.show_progress,
+        )?;
     } else {
         // Build and apply directly
-        apply_kustomize_build(&overlay_path, options.kubeconfig.as_deref())?;
+        apply_kustomize_build(
+            &overlay_path,
+            options.kubeconfig.as_deref(),
+            options.apply_timeout,
+            options.show_progress,
+        )?;
+    }
+
+    if !options.wait {
+        crate::log_info!("Upstream kueue manifests applied (--no-wait)");
+        return Ok(());
     }
 
     // Wait for CRDs to be established before the controller starts
     // The workloads.kueue.x-k8s.io CRD is particularly large and may take time to be ready
     crate::log_info!("Waiting for Kueue CRDs to be established...");
-    wait_for_kueue_crds(options.kubeconfig.as_deref())?;
+    wait_for_kueue_crds(
+        options.kubeconfig.as_deref(),
+        Duration::from_secs(DEFAULT_CRD_TIMEOUT_SECS),
+        options.show_progress,
+    )?;
 
     // Wait for deployment to be available
     crate::log_info!("Waiting for kueue-controller-manager deployment...");
-    kubectl::wait_for_condition(
-        "deployment/kueue-controller-manager",
-        "condition=Available",
-        Some(&options.namespace),
+    kubectl::wait_for_deployment_available(
+        "kueue-controller-manager",
+        &options.namespace,
         "300s",
-        options.kubeconfig.as_deref(),
+        &KubeTarget::new(options.kubeconfig.as_deref()),
+        options.show_progress,
     )
     .context("Kueue controller-manager deployment did not become available")?;
 
@@ -204,7 +318,12 @@ pub fn deploy_kustomize(options: &KustomizeOptions) -> Result<()> {
 }
 
 /// Build kustomize output and apply to cluster
-fn apply_kustomize_build(overlay_path: &Path, kubeconfig: Option<&Path>) -> Result<()> {
+fn apply_kustomize_build(
+    overlay_path: &Path,
+    kubeconfig: Option<&Path>,
+    timeout: Duration,
+    show_progress: bool,
+) -> Result<()> {
     crate::log_info!("Building kustomize overlay: {}", overlay_path.display());
 
     // Run kustomize build
@@ -218,42 +337,203 @@ fn apply_kustomize_build(overlay_path: &Path, kubeconfig: Option<&Path>) -> Resu
         return Err(anyhow!("kustomize build failed: {}", stderr));
     }
 
-    // Apply the output using server-side apply to avoid annotation size limits
-    // Kueue CRDs are large and exceed the 256KB last-applied-configuration annotation limit
-    crate::log_info!("Applying kustomize output to cluster (server-side apply)...");
+    let output = String::from_utf8(kustomize_output.stdout)
+        .context("kustomize build output is not valid UTF-8")?;
+    let split = split_crds(&output)?;
+
+    // CRDs go first and must be Established before the controller starts,
+    // otherwise it can come up without its CRDs
+    if !split.crd_names.is_empty() {
+        crate::log_info!("Applying {} CRDs...", split.crd_names.len());
+        server_side_apply(split.crds, "CRDs", kubeconfig, timeout, show_progress)?;
+        crate::log_info!(
+            "Waiting for {} CRDs to be established...",
+            split.crd_names.len()
+        );
+        wait_for_crds(
+            &split.crd_names,
+            kubeconfig,
+            Duration::from_secs(DEFAULT_CRD_TIMEOUT_SECS),
+            show_progress,
+        )?;
+    }
 
-    let mut kubectl_args = vec!["apply", "--server-side", "--force-conflicts", "-f", "-"];
-    let kubeconfig_str;
-    if let Some(kc) = kubeconfig {
-        kubeconfig_str = kc.to_string_lossy().to_string();
-        kubectl_args.push("--kubeconfig");
-        kubectl_args.push(&kubeconfig_str);
+    if split.rest.trim().is_empty() {
+        return Ok(());
     }
+    crate::log_info!("Applying remaining kustomize output...");
+    server_side_apply(
+        split.rest,
+        "kustomize output",
+        kubeconfig,
+        timeout,
+        show_progress,
+    )
+}
 
-    let mut kubectl_cmd = Command::new("kubectl")
-        .args(&kubectl_args)
-        .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::inherit())
-        .stderr(std::process::Stdio::inherit())
-        .spawn()
-        .context("Failed to spawn kubectl apply")?;
+/// A kustomize build split into CustomResourceDefinitions and everything else
+#[derive(Debug, Default)]
+struct CrdSplit {
+    /// YAML stream of the CRD documents
+    crds: String,
+    /// Names of the CRDs in `crds`
+    crd_names: Vec<String>,
+    /// YAML stream of all other documents
+    rest: String,
+}
 
-    {
-        use std::io::Write;
-        let stdin = kubectl_cmd.stdin.as_mut().expect("Failed to open stdin");
-        stdin
-            .write_all(&kustomize_output.stdout)
-            .context("Failed to write to kubectl stdin")?;
+/// Split a multi-document YAML stream into CRDs and the rest. Documents are
+/// parsed to read their kind but kept byte-for-byte.
+fn split_crds(yaml: &str) -> Result<CrdSplit> {
+    let mut split = CrdSplit::default();
+
+    let mut documents = vec![String::new()];
+    for line in yaml.lines() {
+        if line == "---" || line.starts_with("--- ") {
+            documents.push(String::new());
+            continue;
+        }
+        let current = documents.last_mut().expect("at least one document");
+        current.push_str(line);
+        current.push('\n');
     }
 
-    let status = kubectl_cmd.wait().context("Failed to wait for kubectl")?;
-    if !status.success() {
-        return Err(anyhow!("kubectl apply failed"));
+    for document in documents {
+        let value: serde_yaml::Value =
+            serde_yaml::from_str(&document).context("Failed to parse kustomize output")?;
+        if value.is_null() {
+            continue;
+        }
+
+        let target = if value["kind"].as_str() == Some("CustomResourceDefinition") {
+            let name = value["metadata"]["name"]
+                .as_str()
+                .ok_or_else(|| anyhow!("CustomResourceDefinition without metadata.name"))?;
+            split.crd_names.push(name.to_string());
+            &mut split.crds
+        } else {
+            &mut split.rest
+        };
+        target.push_str("---\n");
+        target.push_str(&document);
+    }
+
+    Ok(split)
+}
+
+/// `kubectl apply --server-side` a YAML stream, with a spinner, killing kubectl
+/// after `timeout`. `what` names the manifests in messages.
+fn server_side_apply(
+    manifests: String,
+    what: &str,
+    kubeconfig: Option<&Path>,
+    timeout: Duration,
+    show_progress: bool,
+) -> Result<()> {
+    // Server-side apply avoids annotation size limits: Kueue CRDs are large and
+    // exceed the 256KB last-applied-configuration annotation limit
+    let mut kubectl_cmd = KubeTarget::new(kubeconfig).kubectl();
+    kubectl_cmd.args(["apply", "--server-side", "--force-conflicts", "-f", "-"]);
+
+    let message = format!("Applying {} (server-side apply)...", what);
+    let spinner =
+        kubectl::progress_enabled(show_progress).then(|| crate::utils::create_spinner(&message));
+    let result = run_with_input(kubectl_cmd, manifests.into_bytes(), timeout, |elapsed| {
+        if let Some(spinner) = &spinner {
+            spinner.set_message(format!("{} {}s", message, elapsed.as_secs()));
+        }
+    });
+    if let Some(spinner) = spinner {
+        spinner.finish_and_clear();
+    }
+
+    let Some(output) = result? else {
+        return Err(anyhow!(
+            "kubectl apply of {} did not finish within {}s and was killed. \
+             Raise behavior.apply_timeout for slow clusters",
+            what,
+            timeout.as_secs()
+        ));
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!(
+            "kubectl apply of {} failed: {}",
+            what,
+            stderr.trim()
+        ));
     }
 
+    let applied = String::from_utf8_lossy(&output.stdout).lines().count();
+    crate::log_info!("Applied {} resources", applied);
+
     Ok(())
 }
 
+/// Run `cmd` with `input` on stdin, capturing stdout and stderr. The child is
+/// killed if it runs longer than `timeout`, returning None; `tick` is called while waiting.
+fn run_with_input(
+    mut cmd: Command,
+    input: Vec<u8>,
+    timeout: Duration,
+    tick: impl Fn(Duration),
+) -> Result<Option<std::process::Output>> {
+    use std::io::{Read, Write};
+    use std::process::Stdio;
+
+    let mut child = cmd
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to spawn kubectl apply")?;
+
+    // Feed stdin and drain the pipes on threads so a full pipe can't block the child
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let writer = std::thread::spawn(move || stdin.write_all(&input));
+    let mut stdout = child.stdout.take().expect("stdout is piped");
+    let stdout_reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        stdout.read_to_end(&mut buf).map(|_| buf)
+    });
+    let mut stderr = child.stderr.take().expect("stderr is piped");
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        stderr.read_to_end(&mut buf).map(|_| buf)
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().context("Failed to wait for kubectl")? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            child.kill().ok();
+            child.wait().ok();
+            return Ok(None);
+        }
+        tick(started.elapsed());
+        std::thread::sleep(Duration::from_millis(200));
+    };
+
+    // A child that exits early closes stdin; its exit status says why
+    writer.join().ok();
+    let stdout = stdout_reader
+        .join()
+        .map_err(|_| anyhow!("stdout reader panicked"))?
+        .context("Failed to read kubectl output")?;
+    let stderr = stderr_reader
+        .join()
+        .map_err(|_| anyhow!("stderr reader panicked"))?
+        .context("Failed to read kubectl output")?;
+
+    Ok(Some(std::process::Output {
+        status,
+        stdout,
+        stderr,
+    }))
+}
+
 /// Deploy upstream kueue using helm
 pub fn deploy_helm(options: &HelmOptions) -> Result<()> {
     crate::log_info!("Deploying upstream kueue via helm...");
@@ -278,56 +558,50 @@ pub fn deploy_helm(options: &HelmOptions) -> Result<()> {
         return Err(anyhow!("Helm chart not found at: {}", chart_path.display()));
     }
 
-    // Build helm command
-    let mut args = vec![
-        "install".to_string(),
-        options.release_name.clone(),
-        chart_path.to_string_lossy().to_string(),
-        "--namespace".to_string(),
-        options.namespace.clone(),
-        "--create-namespace".to_string(),
-    ];
-
-    // Add kubeconfig if specified
-    if let Some(ref kc) = options.kubeconfig {
-        args.push("--kubeconfig".to_string());
-        args.push(kc.to_string_lossy().to_string());
+    if let Some(ref values) = options.values_file
+        && !values.exists()
+    {
+        return Err(anyhow!("Values file not found: {}", values.display()));
     }
-
-    // Add values file if specified
-    if let Some(ref values) = options.values_file {
-        if !values.exists() {
-            return Err(anyhow!("Values file not found: {}", values.display()));
+    validate_set_values("--set", &options.set_values)?;
+    validate_set_values("--set-string", &options.set_string_values)?;
+    validate_set_values("--set-file", &options.set_file_values)?;
+    for path in options
+        .set_file_values
+        .iter()
+        .flat_map(|v| set_file_paths(v))
+    {
+        if !Path::new(path).exists() {
+            return Err(anyhow!("--set-file file not found: {}", path));
         }
-        args.push("-f".to_string());
-        args.push(values.to_string_lossy().to_string());
     }
 
-    // Add --set values
-    for set_value in &options.set_values {
-        args.push("--set".to_string());
-        args.push(set_value.clone());
-    }
+    let args = helm_install_args(options, &chart_path);
 
     crate::log_info!("Running: helm {}", args.join(" "));
 
-    let status = Command::new("helm")
-        .args(&args)
-        .status()
-        .context("Failed to run helm install")?;
+    let mut cmd = KubeTarget::new(options.kubeconfig.as_deref()).command("helm");
+    cmd.args(&args);
+
+    let status = cmd.status().context("Failed to run helm install")?;
 
     if !status.success() {
         return Err(anyhow!("helm install failed"));
     }
 
+    if !options.wait {
+        crate::log_info!("Upstream kueue helm release installed (--no-wait)");
+        return Ok(());
+    }
+
     // Wait for deployment to be available
     crate::log_info!("Waiting for kueue-controller-manager deployment...");
-    kubectl::wait_for_condition(
-        "deployment/kueue-controller-manager",
-        "condition=Available",
-        Some(&options.namespace),
+    kubectl::wait_for_deployment_available(
+        "kueue-controller-manager",
+        &options.namespace,
         "300s",
-        options.kubeconfig.as_deref(),
+        &KubeTarget::new(options.kubeconfig.as_deref()),
+        options.show_progress,
     )
     .context("Kueue controller-manager deployment did not become available")?;
 
@@ -336,36 +610,133 @@ pub fn deploy_helm(options: &HelmOptions) -> Result<()> {
     Ok(())
 }
 
+/// Check that each of `values` passed with helm's `flag` is `KEY=VALUE`, where KEY
+/// is a dotted path whose segments may be indexed, e.g. `tolerations[0].key`
+pub fn validate_set_values(flag: &str, values: &[String]) -> Result<()> {
+    static KEY_RE: OnceLock<Regex> = OnceLock::new();
+    let key_re = KEY_RE.get_or_init(|| {
+        Regex::new(r"^[^\s=\[\].]+(\[\d+\])*(\.[^\s=\[\].]+(\[\d+\])*)*$").expect("valid regex")
+    });
+
+    for value in values {
+        let valid = value
+            .split_once('=')
+            .is_some_and(|(key, _)| key_re.is_match(key));
+        if !valid {
+            return Err(anyhow!(
+                "Invalid {} value '{}'. Must be KEY=VALUE, e.g. controllerManager.replicas=2",
+                flag,
+                value
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// File paths in a `--set-file` value, which like helm's may hold several
+/// comma-separated KEY=PATH pairs
+fn set_file_paths(value: &str) -> Vec<&str> {
+    value
+        .split(',')
+        .filter_map(|pair| pair.split_once('=').map(|(_, path)| path))
+        .collect()
+}
+
+/// Arguments for `helm install` of the chart at `chart_path`
+fn helm_install_args(options: &HelmOptions, chart_path: &Path) -> Vec<String> {
+    let mut args = vec![
+        "install".to_string(),
+        options.release_name.clone(),
+        chart_path.to_string_lossy().to_string(),
+        "--namespace".to_string(),
+        options.namespace.clone(),
+        "--create-namespace".to_string(),
+    ];
+
+    // Add values file if specified
+    if let Some(ref values) = options.values_file {
+        args.push("-f".to_string());
+        args.push(values.to_string_lossy().to_string());
+    }
+
+    // Add --set, --set-string and --set-file values
+    for (flag, values) in [
+        ("--set", &options.set_values),
+        ("--set-string", &options.set_string_values),
+        ("--set-file", &options.set_file_values),
+    ] {
+        for value in values {
+            args.push(flag.to_string());
+            args.push(value.clone());
+        }
+    }
+
+    args
+}
+
 /// Default image registry for locally built kueue
 pub const DEFAULT_IMAGE_REGISTRY: &str = "localhost";
 
 /// Default image tag for locally built kueue
 pub const DEFAULT_IMAGE_TAG: &str = "dev";
 
-/// Build the upstream kueue image using make
+/// Make target that builds the kueue image for kind
+pub const DEFAULT_MAKE_TARGET: &str = "kind-image-build";
+
+/// How `build_image` builds the upstream kueue image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageBuildMethod {
+    /// `make <target>` with `IMAGE_REGISTRY` and `GIT_TAG`
+    Make(String),
+    /// Build a Dockerfile directly with the container runtime, using the source as context
+    Dockerfile(PathBuf),
+}
+
+impl Default for ImageBuildMethod {
+    fn default() -> Self {
+        ImageBuildMethod::Make(DEFAULT_MAKE_TARGET.to_string())
+    }
+}
+
+impl ImageBuildMethod {
+    /// A Dockerfile wins over a make target; neither keeps the default target
+    pub fn from_options(make_target: Option<String>, dockerfile: Option<String>) -> Self {
+        match (dockerfile, make_target) {
+            (Some(dockerfile), _) => ImageBuildMethod::Dockerfile(PathBuf::from(dockerfile)),
+            (None, Some(target)) => ImageBuildMethod::Make(target),
+            (None, None) => ImageBuildMethod::default(),
+        }
+    }
+}
+
+/// Kueue release of an upstream source tree, from the helm chart's `appVersion`
+pub fn source_kueue_version(source_path: &Path) -> Option<String> {
+    let chart = std::fs::read_to_string(source_path.join("charts/kueue/Chart.yaml")).ok()?;
+    chart.lines().find_map(|line| {
+        let value = line
+            .strip_prefix("appVersion:")?
+            .trim()
+            .trim_matches(['"', '\'']);
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+/// Build the upstream kueue image using make, or a Dockerfile directly
 /// Returns the full image tag that was built (e.g., localhost/kueue:dev)
 ///
 /// Note: The upstream Makefile uses IMAGE_REGISTRY and appends /kueue to create
 /// the full image name. So IMAGE_REGISTRY=localhost results in localhost/kueue:tag
-pub fn build_image(source_path: &Path, image_tag: Option<&str>) -> Result<String> {
+///
+/// `runtime` builds the image for the Dockerfile method, so it lands in the
+/// same image store it is loaded from
+pub fn build_image(
+    source_path: &Path,
+    image_tag: Option<&str>,
+    method: &ImageBuildMethod,
+    runtime: &crate::utils::ContainerRuntime,
+) -> Result<String> {
     crate::log_info!("Source: {}", source_path.display());
 
-    // Validate source has Makefile
-    let makefile_path = source_path.join("Makefile");
-    if !makefile_path.exists() {
-        return Err(anyhow!(
-            "Makefile not found at: {}",
-            makefile_path.display()
-        ));
-    }
-
-    // Check for make
-    if which::which("make").is_err() {
-        return Err(anyhow!(
-            "make is required to build images but not found in PATH"
-        ));
-    }
-
     // Parse image_tag into registry and tag components
     // If user provides "my-registry/kueue:v1.0", we extract registry="my-registry" and tag="v1.0"
     // If user provides "my-registry:v1.0", we use registry="my-registry" and tag="v1.0"
@@ -395,31 +766,71 @@ pub fn build_image(source_path: &Path, image_tag: Option<&str>) -> Result<String
     let full_image = format!("{}/kueue:{}", image_registry, git_tag);
     crate::log_info!("Building upstream kueue image: {}", full_image);
 
-    // Run make kind-image-build with IMAGE_REGISTRY and GIT_TAG
-    // This builds and loads the image for use with kind
+    match method {
+        ImageBuildMethod::Make(target) => {
+            run_make_target(source_path, target, &image_registry, &git_tag)?
+        }
+        ImageBuildMethod::Dockerfile(dockerfile) => {
+            // Relative Dockerfiles are relative to the upstream source
+            let dockerfile = source_path.join(dockerfile);
+            if !dockerfile.is_file() {
+                return Err(anyhow!("Dockerfile not found at: {}", dockerfile.display()));
+            }
+            crate::log_info!("Dockerfile: {}", dockerfile.display());
+            crate::commands::build::build_local_image(
+                runtime,
+                &dockerfile,
+                source_path,
+                &full_image,
+            )?;
+        }
+    }
+
+    crate::log_info!("Image built successfully: {}", full_image);
+
+    Ok(full_image)
+}
+
+/// Run `make <target>` with IMAGE_REGISTRY and GIT_TAG in the upstream source
+fn run_make_target(source_path: &Path, target: &str, registry: &str, tag: &str) -> Result<()> {
+    // Validate source has Makefile
+    let makefile_path = source_path.join("Makefile");
+    if !makefile_path.exists() {
+        return Err(anyhow!(
+            "Makefile not found at: {}",
+            makefile_path.display()
+        ));
+    }
+
+    // Check for make
+    if which::which("make").is_err() {
+        return Err(anyhow!(
+            "make is required to build images but not found in PATH"
+        ));
+    }
+
     crate::log_info!(
-        "Running: make kind-image-build IMAGE_REGISTRY={} GIT_TAG={}",
-        image_registry,
-        git_tag
+        "Running: make {} IMAGE_REGISTRY={} GIT_TAG={}",
+        target,
+        registry,
+        tag
     );
 
     let status = Command::new("make")
         .args([
-            "kind-image-build",
-            &format!("IMAGE_REGISTRY={}", image_registry),
-            &format!("GIT_TAG={}", git_tag),
+            target,
+            &format!("IMAGE_REGISTRY={}", registry),
+            &format!("GIT_TAG={}", tag),
         ])
         .current_dir(source_path)
         .status()
-        .context("Failed to run make kind-image-build")?;
+        .with_context(|| format!("Failed to run make {}", target))?;
 
     if !status.success() {
-        return Err(anyhow!("make kind-image-build failed"));
+        return Err(anyhow!("make {} failed", target));
     }
 
-    crate::log_info!("Image built successfully: {}", full_image);
-
-    Ok(full_image)
+    Ok(())
 }
 
 /// Load a docker image to a kind cluster
@@ -428,56 +839,7 @@ pub fn load_image_to_kind(
     image: &str,
     runtime: &crate::utils::ContainerRuntime,
 ) -> Result<()> {
-    crate::log_info!("Loading image {} to kind cluster {}", image, cluster_name);
-
-    // Use kind load docker-image or podman equivalent
-    let status = Command::new("kind")
-        .args(["load", "docker-image", image, "--name", cluster_name])
-        .status()
-        .context("Failed to run kind load docker-image")?;
-
-    if !status.success() {
-        // Try with podman if docker failed
-        if matches!(runtime, crate::utils::ContainerRuntime::Podman) {
-            crate::log_info!("Retrying with podman save/load...");
-            let save_output = Command::new("podman")
-                .args(["save", image])
-                .output()
-                .context("Failed to run podman save")?;
-
-            if !save_output.status.success() {
-                return Err(anyhow!("podman save failed"));
-            }
-
-            let mut kind_load = Command::new("kind")
-                .args([
-                    "load",
-                    "image-archive",
-                    "/dev/stdin",
-                    "--name",
-                    cluster_name,
-                ])
-                .stdin(std::process::Stdio::piped())
-                .spawn()
-                .context("Failed to spawn kind load")?;
-
-            {
-                use std::io::Write;
-                let stdin = kind_load.stdin.as_mut().expect("Failed to open stdin");
-                stdin
-                    .write_all(&save_output.stdout)
-                    .context("Failed to write to kind stdin")?;
-            }
-
-            let status = kind_load.wait().context("Failed to wait for kind load")?;
-            if !status.success() {
-                return Err(anyhow!("kind load image-archive failed"));
-            }
-        } else {
-            return Err(anyhow!("kind load docker-image failed"));
-        }
-    }
-
+    runtime.load_to_kind(image, cluster_name)?;
     crate::log_info!("Image loaded successfully");
 
     Ok(())
@@ -489,10 +851,11 @@ pub fn build_and_load_image(
     source_path: &Path,
     cluster_name: &str,
     image_tag: Option<&str>,
+    method: &ImageBuildMethod,
     runtime: &crate::utils::ContainerRuntime,
 ) -> Result<String> {
     // Build the image
-    let image = build_image(source_path, image_tag)?;
+    let image = build_image(source_path, image_tag, method, runtime)?;
 
     // Load to kind cluster
     load_image_to_kind(cluster_name, &image, runtime)?;
@@ -512,19 +875,10 @@ pub fn uninstall_helm(
         namespace
     );
 
-    let mut args = vec!["uninstall", release_name, "--namespace", namespace];
-
-    let kubeconfig_str;
-    if let Some(kc) = kubeconfig {
-        kubeconfig_str = kc.to_string_lossy().to_string();
-        args.push("--kubeconfig");
-        args.push(&kubeconfig_str);
-    }
+    let mut cmd = KubeTarget::new(kubeconfig).command("helm");
+    cmd.args(["uninstall", release_name, "--namespace", namespace]);
 
-    let status = Command::new("helm")
-        .args(&args)
-        .status()
-        .context("Failed to run helm uninstall")?;
+    let status = cmd.status().context("Failed to run helm uninstall")?;
 
     if !status.success() {
         crate::log_warn!("helm uninstall returned non-zero exit code");
@@ -551,26 +905,64 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Wait for all Kueue CRDs to be established
-/// This is important because the controller will fail to start if CRDs aren't ready
-fn wait_for_kueue_crds(kubeconfig: Option<&Path>) -> Result<()> {
-    // Key CRDs that must be established before the controller can start
-    let crds = [
-        "workloads.kueue.x-k8s.io",
-        "clusterqueues.kueue.x-k8s.io",
-        "localqueues.kueue.x-k8s.io",
-        "resourceflavors.kueue.x-k8s.io",
-        "admissionchecks.kueue.x-k8s.io",
-    ];
+/// Wait for all Kueue CRDs in [`KUEUE_CRDS`] to be established
+/// This is important because the controller will fail to start if CRDs aren't ready.
+pub fn wait_for_kueue_crds(
+    kubeconfig: Option<&Path>,
+    timeout: Duration,
+    show_progress: bool,
+) -> Result<()> {
+    wait_for_crds(KUEUE_CRDS, kubeconfig, timeout, show_progress)
+}
 
-    for crd in &crds {
+/// Wait for every CRD in `crds` to be established. `timeout` bounds the whole
+/// wait; on failure the error lists the CRDs that did not become established
+/// in time.
+fn wait_for_crds<S: AsRef<str>>(
+    crds: &[S],
+    kubeconfig: Option<&Path>,
+    timeout: Duration,
+    show_progress: bool,
+) -> Result<()> {
+    let deadline = Instant::now() + timeout;
+    let mut failed = Vec::new();
+
+    for crd in crds {
+        let crd = crd.as_ref();
         crate::log_info!("  Waiting for CRD: {}", crd);
+        // Keep at least one second so later CRDs are still checked after a timeout
+        let remaining = deadline
+            .saturating_duration_since(Instant::now())
+            .max(Duration::from_secs(1));
         let resource = format!("crd/{}", crd);
-        kubectl::wait_for_condition(&resource, "condition=Established", None, "120s", kubeconfig)
-            .with_context(|| format!("CRD {} did not become established", crd))?;
+
+        match kubectl::wait_for_condition_with_progress(
+            &resource,
+            "condition=Established",
+            None,
+            &format!("{}s", remaining.as_secs()),
+            &KubeTarget::new(kubeconfig),
+            show_progress,
+        ) {
+            Ok(()) => crate::log_info!("  CRD established: {}", crd),
+            Err(e) => {
+                crate::log_warn!("  CRD not established: {}: {:#}", crd, e);
+                failed.push(crd);
+            }
+        }
+    }
+
+    if !failed.is_empty() {
+        return Err(anyhow!(
+            "{} of {} CRDs did not become established within {}s: {}",
+            failed.len(),
+            crds.len(),
+            timeout.as_secs(),
+            failed.join(", ")
+        ));
     }
 
-    crate::log_info!("All Kueue CRDs are established");
+    crate::log_info!("All CRDs are established");
     Ok(())
 }
 
@@ -578,6 +970,230 @@ fn wait_for_kueue_crds(kubeconfig: Option<&Path>) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_split_crds() {
+        let yaml = r#"apiVersion: v1
+kind: Namespace
+metadata:
+  name: kueue-system
+---
+apiVersion: apiextensions.k8s.io/v1
+kind: CustomResourceDefinition
+metadata:
+  name: workloads.kueue.x-k8s.io
+spec:
+  versions:
+  - schema:
+      description: |
+        Literal text with a separator-looking line
+        ---
+        inside it
+---
+apiVersion: apps/v1
+kind: Deployment
+metadata:
+  name: kueue-controller-manager
+---
+apiVersion: apiextensions.k8s.io/v1
+kind: CustomResourceDefinition
+metadata:
+  name: clusterqueues.kueue.x-k8s.io
+"#;
+        let split = split_crds(yaml).unwrap();
+        assert_eq!(
+            split.crd_names,
+            vec!["workloads.kueue.x-k8s.io", "clusterqueues.kueue.x-k8s.io"]
+        );
+        assert!(split.crds.contains("        ---\n        inside it\n"));
+        assert!(!split.crds.contains("kind: Deployment"));
+        assert!(split.rest.contains("kind: Namespace"));
+        assert!(split.rest.contains("kind: Deployment"));
+        assert!(!split.rest.contains("CustomResourceDefinition"));
+        assert_eq!(split.rest.matches("---\n").count(), 2);
+
+        assert!(split_crds("kind: [unclosed").is_err());
+        assert!(split_crds("").unwrap().crd_names.is_empty());
+    }
+
+    #[test]
+    fn test_run_with_input() {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "cat; echo oops >&2; exit 3"]);
+        let output = run_with_input(cmd, b"applied\n".to_vec(), Duration::from_secs(10), |_| {})
+            .unwrap()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(output.stdout, b"applied\n");
+        assert_eq!(output.stderr, b"oops\n");
+
+        let mut cmd = Command::new("sleep");
+        cmd.arg("10");
+        let started = Instant::now();
+        let output = run_with_input(cmd, Vec::new(), Duration::from_millis(300), |_| {}).unwrap();
+        assert!(output.is_none());
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_image_build_method_from_options() {
+        assert_eq!(
+            ImageBuildMethod::from_options(None, None),
+            ImageBuildMethod::Make("kind-image-build".to_string())
+        );
+        assert_eq!(
+            ImageBuildMethod::from_options(Some("image-local-build".to_string()), None),
+            ImageBuildMethod::Make("image-local-build".to_string())
+        );
+        assert_eq!(
+            ImageBuildMethod::from_options(
+                Some("ignored".to_string()),
+                Some("Dockerfile.kind".to_string())
+            ),
+            ImageBuildMethod::Dockerfile(PathBuf::from("Dockerfile.kind"))
+        );
+    }
+
+    #[test]
+    fn test_source_kueue_version() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(source_kueue_version(dir.path()), None);
+
+        let chart_dir = dir.path().join("charts/kueue");
+        std::fs::create_dir_all(&chart_dir).unwrap();
+        std::fs::write(
+            chart_dir.join("Chart.yaml"),
+            "apiVersion: v2\nname: kueue\nversion: 0.14.2\nappVersion: \"v0.14.2\"\n",
+        )
+        .unwrap();
+        assert_eq!(source_kueue_version(dir.path()).as_deref(), Some("v0.14.2"));
+    }
+
+    #[test]
+    fn test_operator_tree_root() {
+        let operator = tempfile::tempdir().unwrap();
+        let vendored = operator.path().join("upstream/kueue/src");
+        std::fs::create_dir_all(&vendored).unwrap();
+        let upstream = tempfile::tempdir().unwrap();
+
+        assert_eq!(
+            operator_tree_root(operator.path(), Some(operator.path())),
+            Some(operator.path().to_path_buf())
+        );
+        assert_eq!(
+            operator_tree_root(upstream.path(), Some(operator.path())),
+            None
+        );
+        assert_eq!(operator_tree_root(upstream.path(), None), None);
+
+        // Without a configured operator source, its deploy/crd directory or go.mod gives it away
+        std::fs::create_dir_all(operator.path().join("deploy/crd")).unwrap();
+        assert_eq!(
+            operator_tree_root(operator.path(), None),
+            Some(operator.path().to_path_buf())
+        );
+        let go_operator = tempfile::tempdir().unwrap();
+        std::fs::write(
+            go_operator.path().join("go.mod"),
+            "module github.com/openshift/kueue-operator\n",
+        )
+        .unwrap();
+        assert!(operator_tree_root(go_operator.path(), None).is_some());
+
+        assert!(check_explicit_source(operator.path(), Some(operator.path()), false).is_ok());
+        assert!(check_explicit_source(operator.path(), Some(operator.path()), true).is_err());
+        assert!(check_explicit_source(upstream.path(), Some(operator.path()), true).is_ok());
+    }
+
+    #[test]
+    fn test_operator_tree_root_vendored_upstream() {
+        let operator = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(operator.path().join("deploy/crd")).unwrap();
+        let vendored = operator.path().join("upstream/kueue/src");
+        std::fs::create_dir_all(&vendored).unwrap();
+        std::fs::write(vendored.join("go.mod"), "module sigs.k8s.io/kueue\n").unwrap();
+
+        // The vendored upstream tree is the default upstream source, not the operator
+        assert_eq!(operator_tree_root(&vendored, Some(operator.path())), None);
+        assert_eq!(operator_tree_root(&vendored, None), None);
+        assert!(check_explicit_source(&vendored, Some(operator.path()), true).is_ok());
+    }
+
+    #[test]
+    fn test_validate_set_values() {
+        let valid = [
+            "replicas=2",
+            "controllerManager.manager.image.tag=v0.14.0",
+            "tolerations[0].key=gpu",
+            "matrix[1][2]=x",
+            "podAnnotations.prometheus\\.io/scrape=true",
+            "enableCertManager=",
+            "a=1,b=2",
+        ];
+        for value in valid {
+            assert!(
+                validate_set_values("--set", &[value.to_string()]).is_ok(),
+                "{}",
+                value
+            );
+        }
+
+        let invalid = [
+            "replicas", "=2", "a..b=1", ".a=1", "a.=1", "a[x]=1", "a b=1", "[0]=1",
+        ];
+        for value in invalid {
+            let err = validate_set_values("--set-string", &[value.to_string()]).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("--set-string value '{}'", value))
+            );
+        }
+
+        // --set-file takes helm's comma form too; each pair names its own file
+        assert!(validate_set_values("--set-file", &["a=ca.crt,b=key.pem".to_string()]).is_ok());
+        assert_eq!(set_file_paths("a=ca.crt,b=key.pem"), ["ca.crt", "key.pem"]);
+        assert_eq!(set_file_paths("webhook.caBundle=ca.crt"), ["ca.crt"]);
+    }
+
+    #[test]
+    fn test_helm_install_args() {
+        let options = HelmOptions {
+            source_path: PathBuf::from("/src/kueue"),
+            release_name: "kueue".to_string(),
+            namespace: "kueue-system".to_string(),
+            values_file: Some(PathBuf::from("values.yaml")),
+            set_values: vec!["replicas=2".to_string()],
+            set_string_values: vec!["controllerManager.manager.image.tag=0123".to_string()],
+            set_file_values: vec![
+                "webhook.caBundle=ca.crt".to_string(),
+                "a=ca.crt,b=key.pem".to_string(),
+            ],
+            kubeconfig: None,
+            wait: true,
+            show_progress: false,
+        };
+        assert_eq!(
+            helm_install_args(&options, Path::new("/src/kueue/charts/kueue")),
+            [
+                "install",
+                "kueue",
+                "/src/kueue/charts/kueue",
+                "--namespace",
+                "kueue-system",
+                "--create-namespace",
+                "-f",
+                "values.yaml",
+                "--set",
+                "replicas=2",
+                "--set-string",
+                "controllerManager.manager.image.tag=0123",
+                "--set-file",
+                "webhook.caBundle=ca.crt",
+                "--set-file",
+                "a=ca.crt,b=key.pem",
+            ]
+        );
+    }
+
     #[test]
     fn test_default_values() {
         assert_eq!(DEFAULT_OVERLAY, "default");
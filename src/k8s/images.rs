@@ -1,16 +1,30 @@
 //! Container image management and loading
 
 use crate::config::images::ImageConfig;
-use crate::utils::ContainerRuntime;
+use crate::k8s::kind::KindCluster;
+use crate::k8s::registry::LocalRegistry;
+use crate::utils::{ContainerRuntime, ImageLoadProgress};
 use anyhow::{Context, Result};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread::{self, JoinHandle};
 
+/// Default number of concurrent `kind load` operations
+pub const DEFAULT_MAX_PARALLEL_LOADS: usize = 4;
+
 /// Load images into kind cluster
+///
+/// Up to `max_parallel` images are loaded at once; loading is sequential when
+/// `parallel_operations` (normally `behavior.parallel_operations`) is unset.
 pub fn load_images_to_kind(
     cluster_name: &str,
     image_config: &ImageConfig,
     runtime: &ContainerRuntime,
     pull_if_missing: bool,
+    max_parallel: usize,
+    parallel_operations: bool,
 ) -> Result<()> {
     crate::log_info!("Loading prebuilt images into kind cluster...");
 
@@ -32,6 +46,24 @@ pub fn load_images_to_kind(
         ("workload", workload_image.as_str()),
     ];
 
+    // Nodes pull images in the local registry themselves when the cluster was created to use it
+    let local_registry = LocalRegistry::load().filter(|registry| {
+        KindCluster::recorded_registry_port(cluster_name) == Some(registry.port)
+    });
+    let (pulled, images): (Vec<_>, Vec<_>) = images
+        .into_iter()
+        .partition(|(_, image)| local_registry.is_some_and(|registry| registry.serves(image)));
+    for (name, image) in &pulled {
+        crate::log_info!(
+            "Skipping {} image {}: pulled from the local registry",
+            name,
+            image
+        );
+    }
+    if images.is_empty() {
+        return Ok(());
+    }
+
     // Verify and pull images if needed
     crate::log_info!(
         "Verifying images exist in local registry{}...",
@@ -53,17 +85,66 @@ pub fn load_images_to_kind(
     // Load images into kind cluster
     crate::log_info!("Loading images into kind cluster '{}'...", cluster_name);
 
-    for (name, image) in &images {
-        crate::log_info!("Loading {} image: {}", name, image);
-        runtime
-            .load_to_kind(image, cluster_name)
-            .with_context(|| format!("Failed to load {} image to kind", name))?;
+    let workers = worker_count(max_parallel, images.len(), parallel_operations);
+
+    let labels: Vec<String> = images
+        .iter()
+        .map(|(name, image)| format!("{}: {}", name, image))
+        .collect();
+    let progress = ImageLoadProgress::new(&labels);
+    let next = AtomicUsize::new(0);
+    let errors: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    thread::scope(|s| {
+        for _ in 0..workers {
+            s.spawn(|| {
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some((name, image)) = images.get(index) else {
+                        break;
+                    };
+
+                    let result = runtime.load_to_kind(image, cluster_name);
+                    progress.finish_image(index, result.is_ok());
+                    if let Err(e) = result {
+                        errors
+                            .lock()
+                            .unwrap()
+                            .push(format!("{} ({}): {:#}", name, image, e));
+                    }
+                }
+            });
+        }
+    });
+
+    let errors = errors.into_inner().unwrap();
+    crate::log_info!(
+        "Loaded {}/{} images into kind cluster '{}'",
+        images.len() - errors.len(),
+        images.len(),
+        cluster_name
+    );
+
+    if !errors.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Failed to load {} image(s) into kind:\n  {}",
+            errors.len(),
+            errors.join("\n  ")
+        ));
     }
 
     crate::log_info!("All images loaded successfully into kind cluster");
     Ok(())
 }
 
+/// Number of loader threads: at least one, never more than there are images
+fn worker_count(max_parallel: usize, images: usize, parallel_operations: bool) -> usize {
+    if !parallel_operations {
+        return 1;
+    }
+    max_parallel.min(images).max(1)
+}
+
 /// Load images into kind cluster in background thread
 /// Returns a JoinHandle that can be awaited to ensure images are loaded
 pub fn load_images_to_kind_background(
@@ -71,16 +152,203 @@ pub fn load_images_to_kind_background(
     image_config: ImageConfig,
     runtime: ContainerRuntime,
     pull_if_missing: bool,
+    max_parallel: usize,
+    parallel_operations: bool,
 ) -> JoinHandle<Result<()>> {
     thread::spawn(move || {
-        load_images_to_kind(&cluster_name, &image_config, &runtime, pull_if_missing)
+        load_images_to_kind(
+            &cluster_name,
+            &image_config,
+            &runtime,
+            pull_if_missing,
+            max_parallel,
+            parallel_operations,
+        )
     })
 }
 
+/// Load `.tar` image archives into a kind cluster. `path` is an archive or a
+/// directory whose `.tar` files are all loaded, in name order.
+pub fn load_archives_to_kind(
+    cluster_name: &str,
+    path: &Path,
+    runtime: &ContainerRuntime,
+) -> Result<()> {
+    let archives = find_archives(path)?;
+    crate::log_info!(
+        "Loading {} image archive(s) into kind cluster '{}'...",
+        archives.len(),
+        cluster_name
+    );
+
+    let mut errors = Vec::new();
+    for archive in &archives {
+        let images = archive_image_names(archive);
+        let images = if images.is_empty() {
+            "untagged images".to_string()
+        } else {
+            images.join(", ")
+        };
+
+        crate::log_info!("Loading {} ({})", archive.display(), images);
+        match runtime.load_archive_file_to_kind(archive, cluster_name) {
+            Ok(()) => crate::log_info!("Loaded {}", images),
+            Err(e) => errors.push(format!("{}: {:#}", archive.display(), e)),
+        }
+    }
+
+    crate::log_info!(
+        "Loaded {}/{} archives into kind cluster '{}'",
+        archives.len() - errors.len(),
+        archives.len(),
+        cluster_name
+    );
+    if !errors.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Failed to load {} archive(s) into kind:\n  {}",
+            errors.len(),
+            errors.join("\n  ")
+        ));
+    }
+    Ok(())
+}
+
+/// `path` itself if it is a file, otherwise the `.tar` files in the directory
+fn find_archives(path: &Path) -> Result<Vec<PathBuf>> {
+    if !path.exists() {
+        return Err(anyhow::anyhow!(
+            "Image archive path does not exist: {}",
+            path.display()
+        ));
+    }
+    if path.is_file() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut archives: Vec<PathBuf> = std::fs::read_dir(path)
+        .with_context(|| format!("Failed to read directory: {}", path.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "tar"))
+        .collect();
+    archives.sort();
+
+    if archives.is_empty() {
+        return Err(anyhow::anyhow!(
+            "No .tar image archives found in {}",
+            path.display()
+        ));
+    }
+    Ok(archives)
+}
+
+/// Image names recorded in an archive, read with `tar`. Empty when the
+/// archive has no tags or cannot be read.
+fn archive_image_names(archive: &Path) -> Vec<String> {
+    ["manifest.json", "index.json"]
+        .iter()
+        .find_map(|member| {
+            let output = Command::new("tar")
+                .arg("-xOf")
+                .arg(archive)
+                .arg(member)
+                .output()
+                .ok()
+                .filter(|output| output.status.success())?;
+            Some(parse_archive_index(&String::from_utf8_lossy(
+                &output.stdout,
+            )))
+        })
+        .unwrap_or_default()
+}
+
+/// Image names from a docker-archive `manifest.json` or an OCI `index.json`
+fn parse_archive_index(content: &str) -> Vec<String> {
+    let Ok(index) = serde_json::from_str::<serde_json::Value>(content) else {
+        return Vec::new();
+    };
+
+    // docker-archive: [{"RepoTags": ["image:tag"], ...}]
+    if let Some(manifests) = index.as_array() {
+        return manifests
+            .iter()
+            .flat_map(|manifest| manifest["RepoTags"].as_array().cloned().unwrap_or_default())
+            .filter_map(|tag| tag.as_str().map(str::to_string))
+            .collect();
+    }
+
+    // OCI: {"manifests": [{"annotations": {...}}]}
+    index["manifests"]
+        .as_array()
+        .map(|manifests| {
+            manifests
+                .iter()
+                .filter_map(|manifest| {
+                    let annotations = &manifest["annotations"];
+                    annotations["io.containerd.image.name"]
+                        .as_str()
+                        .or_else(|| annotations["org.opencontainers.image.ref.name"].as_str())
+                        .map(str::to_string)
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test_images_module() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_worker_count() {
+        assert_eq!(worker_count(4, 5, true), 4);
+        assert_eq!(worker_count(8, 5, true), 5);
+        assert_eq!(worker_count(0, 5, true), 1);
+        assert_eq!(worker_count(4, 5, false), 1);
+        assert_eq!(worker_count(4, 0, true), 1);
+    }
+
+    #[test]
+    fn test_parse_archive_index() {
+        let docker =
+            r#"[{"Config":"abc.json","RepoTags":["quay.io/me/kueue-operator:dev"],"Layers":[]}]"#;
+        assert_eq!(
+            parse_archive_index(docker),
+            vec!["quay.io/me/kueue-operator:dev"]
+        );
+
+        let oci = r#"{"schemaVersion":2,"manifests":[
+            {"annotations":{"io.containerd.image.name":"quay.io/me/kueue:dev","org.opencontainers.image.ref.name":"dev"}},
+            {"annotations":{"org.opencontainers.image.ref.name":"quay.io/me/must-gather:dev"}},
+            {"mediaType":"application/vnd.oci.image.manifest.v1+json"}]}"#;
+        assert_eq!(
+            parse_archive_index(oci),
+            vec!["quay.io/me/kueue:dev", "quay.io/me/must-gather:dev"]
+        );
+
+        assert!(parse_archive_index("not json").is_empty());
+    }
+
+    #[test]
+    fn test_find_archives() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(find_archives(&dir.path().join("missing.tar")).is_err());
+        assert!(find_archives(dir.path()).is_err());
+
+        for name in ["b.tar", "a.tar", "notes.txt"] {
+            std::fs::write(dir.path().join(name), "").unwrap();
+        }
+        assert_eq!(
+            find_archives(dir.path()).unwrap(),
+            vec![dir.path().join("a.tar"), dir.path().join("b.tar")]
+        );
+        assert_eq!(
+            find_archives(&dir.path().join("notes.txt")).unwrap(),
+            vec![dir.path().join("notes.txt")]
+        );
+    }
 }
@@ -1,17 +1,38 @@
 //! Kind cluster management operations
 
 use anyhow::{Context, Result, anyhow};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::str::FromStr;
 
+use crate::k8s::registry::LocalRegistry;
+use crate::k8s::target::KubeTarget;
+use crate::utils::ContainerRuntime;
+
+/// Directory containerd reads per-registry `hosts.toml` files from on each node
+const CONTAINERD_CERTS_DIR: &str = "/etc/containerd/certs.d";
+
+/// Check whether the current kubeconfig context points at a kind cluster
+pub fn is_kind_context(kubeconfig: Option<&Path>) -> Result<bool> {
+    let context = crate::k8s::kubectl::current_context(&KubeTarget::new(kubeconfig))?;
+    Ok(context.starts_with("kind-"))
+}
+
 #[derive(Debug, Clone)]
 pub struct KindCluster {
     pub name: String,
     pub cni_provider: CniProvider,
+    /// `host:port` registries containerd pulls from over plain HTTP
+    pub insecure_registries: Vec<String>,
+    /// Local registry the nodes pull `localhost:<port>/...` images from
+    pub local_registry: Option<LocalRegistry>,
+    /// Kubernetes feature gates enabled or disabled on every component
+    pub feature_gates: Vec<(String, bool)>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CniProvider {
     Calico,
     Default,
@@ -41,11 +62,105 @@ impl std::fmt::Display for CniProvider {
     }
 }
 
+/// What kueue-dev records about a cluster when it creates it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ClusterMetadata {
+    cni: CniProvider,
+    /// Port of the local registry the cluster was configured to pull from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    registry_port: Option<u16>,
+}
+
+/// Directory holding per-cluster metadata files
+fn metadata_dir() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("kueue-dev").join("clusters"))
+}
+
 impl KindCluster {
     pub fn new(name: impl Into<String>, cni_provider: CniProvider) -> Self {
         Self {
             name: name.into(),
             cni_provider,
+            insecure_registries: Vec::new(),
+            local_registry: None,
+            feature_gates: Vec::new(),
+        }
+    }
+
+    /// Configure the nodes to pull from the local registry
+    pub fn with_local_registry(mut self, registry: Option<LocalRegistry>) -> Self {
+        self.local_registry = registry;
+        self
+    }
+
+    /// Trust these `host:port` registries over plain HTTP
+    pub fn with_insecure_registries(mut self, registries: Vec<String>) -> Self {
+        self.insecure_registries = registries;
+        self
+    }
+
+    /// Set these feature gates on the API server, controller manager, scheduler and kubelets
+    pub fn with_feature_gates(mut self, feature_gates: Vec<(String, bool)>) -> Self {
+        self.feature_gates = feature_gates;
+        self
+    }
+
+    /// CNI the cluster was created with by `cluster create`, if recorded
+    pub fn recorded_cni(name: &str) -> Option<CniProvider> {
+        Self::recorded_cni_in(&metadata_dir()?, name)
+    }
+
+    /// Port of the local registry the cluster was created to pull from, if any
+    pub fn recorded_registry_port(name: &str) -> Option<u16> {
+        Self::recorded_metadata_in(&metadata_dir()?, name)?.registry_port
+    }
+
+    fn recorded_cni_in(dir: &Path, name: &str) -> Option<CniProvider> {
+        Self::recorded_metadata_in(dir, name).map(|metadata| metadata.cni)
+    }
+
+    fn recorded_metadata_in(dir: &Path, name: &str) -> Option<ClusterMetadata> {
+        let content = std::fs::read_to_string(dir.join(format!("{}.toml", name))).ok()?;
+        match toml::from_str::<ClusterMetadata>(&content) {
+            Ok(metadata) => Some(metadata),
+            Err(e) => {
+                crate::log_warn!("Ignoring unreadable metadata for cluster '{}': {}", name, e);
+                None
+            }
+        }
+    }
+
+    /// Record the cluster's CNI so later commands know how it was created
+    fn save_metadata(&self) {
+        let Some(dir) = metadata_dir() else {
+            return;
+        };
+        if let Err(e) = self.save_metadata_in(&dir) {
+            crate::log_warn!(
+                "Could not record metadata for cluster '{}': {:#}",
+                self.name,
+                e
+            );
+        }
+    }
+
+    fn save_metadata_in(&self, dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(dir)
+            .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+        let metadata = ClusterMetadata {
+            cni: self.cni_provider,
+            registry_port: self.local_registry.map(|registry| registry.port),
+        };
+        std::fs::write(
+            dir.join(format!("{}.toml", self.name)),
+            toml::to_string(&metadata)?,
+        )
+        .context("Failed to write cluster metadata")
+    }
+
+    fn remove_metadata(&self) {
+        if let Some(dir) = metadata_dir() {
+            std::fs::remove_file(dir.join(format!("{}.toml", self.name))).ok();
         }
     }
 
@@ -113,6 +228,7 @@ impl KindCluster {
 
         let mut child = cmd
             .stdin(std::process::Stdio::piped())
+            .stdout(crate::utils::child_stdout())
             .spawn()
             .context("Failed to spawn kind create cluster")?;
 
@@ -133,6 +249,7 @@ impl KindCluster {
         }
 
         crate::log_info!("Cluster '{}' created successfully", self.name);
+        self.save_metadata();
 
         // Export kubeconfig only if path was provided
         if kubeconfig.is_some() {
@@ -157,10 +274,32 @@ impl KindCluster {
             return Err(anyhow!("Failed to delete kind cluster '{}'", self.name));
         }
 
+        self.remove_metadata();
         crate::log_info!("Cluster '{}' deleted successfully", self.name);
         Ok(())
     }
 
+    /// Names of the cluster's node containers
+    pub fn nodes(&self) -> Result<Vec<String>> {
+        let output = Command::new("kind")
+            .args(["get", "nodes", "--name", &self.name])
+            .output()
+            .context("Failed to list kind nodes")?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Failed to list nodes of kind cluster '{}'",
+                self.name
+            ));
+        }
+
+        Ok(String::from_utf8(output.stdout)?
+            .lines()
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect())
+    }
+
     /// List all kind clusters
     pub fn list_all() -> Result<Vec<String>> {
         let output = Command::new("kind")
@@ -181,8 +320,34 @@ impl KindCluster {
         Ok(clusters)
     }
 
+    /// Context kind creates for this cluster
+    pub fn context_name(&self) -> String {
+        format!("kind-{}", self.name)
+    }
+
+    /// Merge this cluster's context into the default kubeconfig (`$KUBECONFIG` or
+    /// `~/.kube/config`) and make it the current context. Returns the context name.
+    pub fn merge_kubeconfig(&self) -> Result<String> {
+        crate::log_info!("Merging kubeconfig into the default kubeconfig...");
+
+        let status = Command::new("kind")
+            .args(["export", "kubeconfig", "--name", &self.name])
+            .stdout(crate::utils::child_stdout())
+            .status()
+            .context("Failed to export kind kubeconfig")?;
+
+        if !status.success() {
+            return Err(anyhow!(
+                "Failed to export kubeconfig for cluster '{}'",
+                self.name
+            ));
+        }
+
+        Ok(self.context_name())
+    }
+
     /// Export kubeconfig to file with optional custom path
-    fn export_kubeconfig_with_custom(&self, custom_path: Option<PathBuf>) -> Result<PathBuf> {
+    pub fn export_kubeconfig_with_custom(&self, custom_path: Option<PathBuf>) -> Result<PathBuf> {
         let kubeconfig_path =
             custom_path.unwrap_or_else(|| crate::utils::operator_source_join("kube.kubeconfig"));
 
@@ -212,19 +377,103 @@ impl KindCluster {
         Ok(final_path)
     }
 
-    /// Generate kind cluster config YAML
-    fn generate_config(&self) -> String {
-        let disable_cni = matches!(self.cni_provider, CniProvider::Calico);
+    /// containerd config patch pointing registry configuration at
+    /// [`CONTAINERD_CERTS_DIR`], where [`Self::configure_registry_hosts`]
+    /// writes a `hosts.toml` per registry once the nodes exist
+    fn containerd_config_patches(&self) -> String {
+        if self.registry_hosts().is_empty() {
+            return String::new();
+        }
 
         format!(
-            r#"kind: Cluster
-apiVersion: kind.x-k8s.io/v1alpha4
-networking:
-  disableDefaultCNI: {}
-  podSubnet: "10.244.0.0/16"
-  serviceSubnet: "10.96.0.0/16"
-nodes:
-- role: control-plane
+            r#"containerdConfigPatches:
+- |-
+  [plugins."io.containerd.grpc.v1.cri".registry]
+    config_path = "{}"
+"#,
+            CONTAINERD_CERTS_DIR
+        )
+    }
+
+    /// `hosts.toml` contents for each registry the nodes need configured, by host
+    fn registry_hosts(&self) -> Vec<(String, String)> {
+        // Nodes reach the local registry over the kind network, as in kind's
+        // local-registry docs
+        let local = self.local_registry.map(|registry| {
+            let hosts = format!("[host.\"{}\"]\n", LocalRegistry::NODE_ENDPOINT);
+            (registry.address(), hosts)
+        });
+        let insecure = self.insecure_registries.iter().map(|registry| {
+            let hosts = format!(
+                "server = \"http://{registry}\"\n\n\
+                 [host.\"http://{registry}\"]\n  skip_verify = true\n"
+            );
+            (registry.clone(), hosts)
+        });
+        local.into_iter().chain(insecure).collect()
+    }
+
+    /// Write each registry's `hosts.toml` under [`CONTAINERD_CERTS_DIR`] on every node
+    pub fn configure_registry_hosts(&self, runtime: &ContainerRuntime) -> Result<()> {
+        let hosts = self.registry_hosts();
+        if hosts.is_empty() {
+            return Ok(());
+        }
+
+        for node in self.nodes()? {
+            for (host, config) in &hosts {
+                let dir = format!("{}/{}", CONTAINERD_CERTS_DIR, host);
+                let mut child = Command::new(runtime.command())
+                    .args(["exec", "-i", &node, "sh", "-c"])
+                    .arg(format!("mkdir -p '{dir}' && cat > '{dir}/hosts.toml'"))
+                    .stdin(std::process::Stdio::piped())
+                    .spawn()
+                    .with_context(|| format!("Failed to configure {} on node {}", host, node))?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    use std::io::Write;
+                    stdin.write_all(config.as_bytes())?;
+                }
+                if !child.wait()?.success() {
+                    return Err(anyhow!(
+                        "Failed to write {}/hosts.toml on node {}",
+                        dir,
+                        node
+                    ));
+                }
+            }
+        }
+
+        crate::log_info!(
+            "Configured {} registry host(s) on the cluster nodes",
+            hosts.len()
+        );
+        Ok(())
+    }
+
+    /// Generate kind cluster config YAML
+    /// `featureGates` for kind, which sets them in each node's kubelet config
+    fn feature_gates_config(&self) -> String {
+        if self.feature_gates.is_empty() {
+            return String::new();
+        }
+        let mut config = "featureGates:\n".to_string();
+        for (name, enabled) in &self.feature_gates {
+            config.push_str(&format!("  {}: {}\n", name, enabled));
+        }
+        config
+    }
+
+    /// Node entries. With feature gates, the control-plane components get them as
+    /// `--feature-gates` and the workers' kubelets through a JoinConfiguration patch.
+    fn nodes_config(&self) -> String {
+        let gates = self
+            .feature_gates
+            .iter()
+            .map(|(name, enabled)| format!("{}={}", name, enabled))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut control_plane = r#"- role: control-plane
   kubeadmConfigPatches:
   - |
     apiVersion: kubeadm.k8s.io/v1beta3
@@ -232,22 +481,104 @@ nodes:
     apiServer:
       extraArgs:
         v: "4"
-- role: control-plane
-  kubeadmConfigPatches:
+"#
+        .to_string();
+        let mut worker = "- role: worker\n".to_string();
+        if !gates.is_empty() {
+            control_plane.push_str(&format!(
+                r#"        feature-gates: "{gates}"
+    controllerManager:
+      extraArgs:
+        feature-gates: "{gates}"
+    scheduler:
+      extraArgs:
+        feature-gates: "{gates}"
+"#
+            ));
+            worker.push_str(&format!(
+                r#"  kubeadmConfigPatches:
   - |
     apiVersion: kubeadm.k8s.io/v1beta3
-    kind: ClusterConfiguration
-    apiServer:
-      extraArgs:
-        v: "4"
-- role: worker
-- role: worker
-"#,
-            disable_cni
+    kind: JoinConfiguration
+    nodeRegistration:
+      kubeletExtraArgs:
+        feature-gates: "{gates}"
+"#
+            ));
+        }
+
+        format!("{control_plane}{control_plane}{worker}{worker}")
+    }
+
+    fn generate_config(&self) -> String {
+        let disable_cni = matches!(self.cni_provider, CniProvider::Calico);
+
+        format!(
+            r#"kind: Cluster
+apiVersion: kind.x-k8s.io/v1alpha4
+{}{}networking:
+  disableDefaultCNI: {}
+  podSubnet: "10.244.0.0/16"
+  serviceSubnet: "10.96.0.0/16"
+nodes:
+{}"#,
+            self.containerd_config_patches(),
+            self.feature_gates_config(),
+            disable_cni,
+            self.nodes_config()
         )
     }
 }
 
+/// Parse a feature gate given as `Name=true` or `Name=false`
+pub fn parse_feature_gate(value: &str) -> Result<(String, bool)> {
+    let invalid = || {
+        anyhow!(
+            "Invalid feature gate '{}': expected Name=true or Name=false, e.g. TopologyAwareHints=true",
+            value
+        )
+    };
+
+    let (name, enabled) = value.split_once('=').ok_or_else(invalid)?;
+    let valid_name = name.starts_with(|c: char| c.is_ascii_uppercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric());
+    let enabled = match enabled.to_lowercase().as_str() {
+        "true" => true,
+        "false" => false,
+        _ => return Err(invalid()),
+    };
+    if !valid_name {
+        return Err(invalid());
+    }
+
+    Ok((name.to_string(), enabled))
+}
+
+/// Validate an insecure registry given as `host:port`
+pub fn parse_registry(value: &str) -> Result<String> {
+    let invalid = || {
+        anyhow!(
+            "Invalid registry '{}': expected host:port, e.g. localhost:5000",
+            value
+        )
+    };
+
+    let (host, port) = value.rsplit_once(':').ok_or_else(invalid)?;
+    let valid_host = !host.is_empty()
+        && host.split('.').all(|label| {
+            !label.is_empty()
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        });
+    let valid_port = port.parse::<u16>().is_ok_and(|port| port > 0);
+    if !valid_host || !valid_port {
+        return Err(invalid());
+    }
+
+    Ok(value.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +606,39 @@ mod tests {
         assert_eq!(format!("{}", CniProvider::Default), "default");
     }
 
+    #[test]
+    fn test_cluster_metadata_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+
+        KindCluster::new("plain", CniProvider::Default)
+            .save_metadata_in(dir.path())
+            .unwrap();
+        KindCluster::new("calico", CniProvider::Calico)
+            .save_metadata_in(dir.path())
+            .unwrap();
+
+        assert_eq!(
+            KindCluster::recorded_cni_in(dir.path(), "plain"),
+            Some(CniProvider::Default)
+        );
+        assert_eq!(
+            KindCluster::recorded_cni_in(dir.path(), "calico"),
+            Some(CniProvider::Calico)
+        );
+        assert_eq!(KindCluster::recorded_cni_in(dir.path(), "missing"), None);
+
+        KindCluster::new("registry", CniProvider::Default)
+            .with_local_registry(Some(LocalRegistry::new(5001)))
+            .save_metadata_in(dir.path())
+            .unwrap();
+        let metadata = |name| KindCluster::recorded_metadata_in(dir.path(), name).unwrap();
+        assert_eq!(metadata("registry").registry_port, Some(5001));
+        assert_eq!(metadata("plain").registry_port, None);
+
+        std::fs::write(dir.path().join("broken.toml"), "cni = \"flannel\"").unwrap();
+        assert_eq!(KindCluster::recorded_cni_in(dir.path(), "broken"), None);
+    }
+
     #[test]
     fn test_generate_config() {
         let cluster = KindCluster::new("test", CniProvider::Calico);
@@ -285,5 +649,124 @@ mod tests {
         let cluster = KindCluster::new("test", CniProvider::Default);
         let config = cluster.generate_config();
         assert!(config.contains("disableDefaultCNI: false"));
+        assert!(!config.contains("containerdConfigPatches"));
+    }
+
+    #[test]
+    fn test_generate_config_insecure_registries() {
+        let cluster = KindCluster::new("test", CniProvider::Calico).with_insecure_registries(vec![
+            "localhost:5000".to_string(),
+            "10.0.0.5:5001".to_string(),
+        ]);
+        let config = cluster.generate_config();
+
+        assert!(config.starts_with(
+            "kind: Cluster\napiVersion: kind.x-k8s.io/v1alpha4\ncontainerdConfigPatches:\n- |-\n"
+        ));
+        assert!(config.contains(
+            r#"  [plugins."io.containerd.grpc.v1.cri".registry]
+    config_path = "/etc/containerd/certs.d"
+"#
+        ));
+        assert!(!config.contains("registry.mirrors"));
+        assert!(config.contains("\nnetworking:\n  disableDefaultCNI: true"));
+
+        let hosts = cluster.registry_hosts();
+        assert_eq!(hosts.len(), 2);
+        assert_eq!(hosts[0].0, "localhost:5000");
+        assert_eq!(
+            hosts[1].1,
+            "server = \"http://10.0.0.5:5001\"\n\n[host.\"http://10.0.0.5:5001\"]\n  skip_verify = true\n"
+        );
+    }
+
+    #[test]
+    fn test_generate_config_local_registry() {
+        let cluster = KindCluster::new("test", CniProvider::Default)
+            .with_local_registry(Some(LocalRegistry::new(5001)));
+        let config = cluster.generate_config();
+
+        assert!(config.contains(r#"config_path = "/etc/containerd/certs.d""#));
+        assert_eq!(config.matches("containerdConfigPatches:").count(), 1);
+        assert_eq!(
+            cluster.registry_hosts(),
+            vec![(
+                "localhost:5001".to_string(),
+                "[host.\"http://kind-registry:5000\"]\n".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_generate_config_feature_gates() {
+        let cluster = KindCluster::new("test", CniProvider::Calico).with_feature_gates(vec![
+            ("TopologyAwareHints".to_string(), true),
+            ("SidecarContainers".to_string(), false),
+        ]);
+        let config = cluster.generate_config();
+
+        assert!(config.contains(
+            "\nfeatureGates:\n  TopologyAwareHints: true\n  SidecarContainers: false\nnetworking:\n"
+        ));
+        let gates = r#"feature-gates: "TopologyAwareHints=true,SidecarContainers=false""#;
+        // API server, controller manager and scheduler on both control-plane nodes
+        assert_eq!(config.matches(gates).count(), 2 * 3 + 2);
+        assert_eq!(config.matches("kind: JoinConfiguration").count(), 2);
+
+        let parsed: serde_yaml::Value = serde_yaml::from_str(&config).unwrap();
+        let nodes = parsed["nodes"].as_sequence().unwrap();
+        assert_eq!(nodes.len(), 4);
+        let worker_patch: serde_yaml::Value =
+            serde_yaml::from_str(nodes[2]["kubeadmConfigPatches"][0].as_str().unwrap()).unwrap();
+        assert_eq!(
+            worker_patch["nodeRegistration"]["kubeletExtraArgs"]["feature-gates"],
+            "TopologyAwareHints=true,SidecarContainers=false"
+        );
+        let control_plane_patch: serde_yaml::Value =
+            serde_yaml::from_str(nodes[0]["kubeadmConfigPatches"][0].as_str().unwrap()).unwrap();
+        assert_eq!(control_plane_patch["apiServer"]["extraArgs"]["v"], "4");
+        assert_eq!(
+            control_plane_patch["scheduler"]["extraArgs"]["feature-gates"],
+            "TopologyAwareHints=true,SidecarContainers=false"
+        );
+
+        let config = KindCluster::new("test", CniProvider::Calico).generate_config();
+        assert!(!config.contains("featureGates"));
+        assert!(!config.contains("feature-gates"));
+        assert!(!config.contains("JoinConfiguration"));
+    }
+
+    #[test]
+    fn test_parse_feature_gate() {
+        assert_eq!(
+            parse_feature_gate("TopologyAwareHints=true").unwrap(),
+            ("TopologyAwareHints".to_string(), true)
+        );
+        assert_eq!(
+            parse_feature_gate("SidecarContainers=False").unwrap(),
+            ("SidecarContainers".to_string(), false)
+        );
+        for invalid in [
+            "TopologyAwareHints",
+            "TopologyAwareHints=yes",
+            "=true",
+            "lower=true",
+            "Bad-Name=true",
+        ] {
+            assert!(parse_feature_gate(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_parse_registry() {
+        assert_eq!(parse_registry("localhost:5000").unwrap(), "localhost:5000");
+        assert!(parse_registry("registry.local:443").is_ok());
+        assert!(parse_registry("10.0.0.5:5001").is_ok());
+        assert!(parse_registry("localhost").is_err());
+        assert!(parse_registry(":5000").is_err());
+        assert!(parse_registry("localhost:0").is_err());
+        assert!(parse_registry("localhost:99999").is_err());
+        assert!(parse_registry("http://localhost:5000").is_err());
+        assert!(parse_registry("bad_host:5000").is_err());
     }
 }
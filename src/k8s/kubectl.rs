@@ -1,18 +1,27 @@
 //! Kubectl wrapper utilities
 
+use crate::k8s::target::KubeTarget;
+use crate::k8s::version::extract_version_from_log;
+use crate::utils::{KueueDevError, WaitProgress};
 use anyhow::{Context, Result, anyhow};
-use std::path::Path;
-use std::process::Command;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
-/// Run a kubectl command with optional kubeconfig
-pub fn run_kubectl(args: &[&str], kubeconfig: Option<&Path>) -> Result<()> {
-    let mut cmd = Command::new("kubectl");
+/// Set by `--emit-events-on-timeout`
+static EMIT_EVENTS_ON_TIMEOUT: AtomicBool = AtomicBool::new(false);
 
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
+/// Make [`wait_for_condition`] attach the waited-on object's status and recent
+/// events to its error when the wait fails
+pub fn set_emit_events_on_timeout(enabled: bool) {
+    EMIT_EVENTS_ON_TIMEOUT.store(enabled, Ordering::Relaxed);
+}
 
-    cmd.args(args);
+/// Run a kubectl command with optional target
+pub fn run_kubectl(args: &[&str], target: &KubeTarget) -> Result<()> {
+    let mut cmd = target.kubectl();
+
+    cmd.args(args).stdout(crate::utils::child_stdout());
 
     let status = cmd.status().context("Failed to run kubectl command")?;
 
@@ -23,13 +32,30 @@ pub fn run_kubectl(args: &[&str], kubeconfig: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-/// Run kubectl and capture output
-pub fn run_kubectl_output(args: &[&str], kubeconfig: Option<&Path>) -> Result<String> {
-    let mut cmd = Command::new("kubectl");
-
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
+/// Arguments that print the context a command will use, honoring `--context`
+/// flags (unlike `config current-context`, which only reads the kubeconfig)
+pub const CURRENT_CONTEXT_ARGS: [&str; 5] = [
+    "config",
+    "view",
+    "--minify",
+    "-o",
+    "jsonpath={.current-context}",
+];
+
+/// The context `target` resolves to: its explicit context when set, otherwise
+/// the kubeconfig's current context
+pub fn current_context(target: &KubeTarget) -> Result<String> {
+    if let Some(context) = &target.context {
+        return Ok(context.clone());
     }
+    Ok(run_kubectl_output(&CURRENT_CONTEXT_ARGS, target)?
+        .trim()
+        .to_string())
+}
+
+/// Run kubectl and capture output
+pub fn run_kubectl_output(args: &[&str], target: &KubeTarget) -> Result<String> {
+    let mut cmd = target.kubectl();
 
     cmd.args(args);
 
@@ -47,19 +73,63 @@ pub fn run_kubectl_output(args: &[&str], kubeconfig: Option<&Path>) -> Result<St
     Ok(String::from_utf8(output.stdout)?)
 }
 
-/// Apply a YAML manifest from string
-pub fn apply_yaml(yaml: &str, kubeconfig: Option<&Path>) -> Result<()> {
-    let mut cmd = Command::new("kubectl");
-
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
+/// Run a kubectl command, retrying with exponential backoff on transient failures
+/// (connection refused/timeout) such as those seen right after cluster creation.
+/// Non-transient failures are returned immediately.
+pub fn run_kubectl_retry(
+    args: &[&str],
+    target: &KubeTarget,
+    attempts: u32,
+    base_delay: Duration,
+) -> Result<()> {
+    let mut attempt = 0;
+    loop {
+        match run_kubectl_output(args, target) {
+            Ok(output) => {
+                if !output.trim().is_empty() {
+                    println!("{}", output.trim_end());
+                }
+                return Ok(());
+            }
+            Err(e) => {
+                attempt += 1;
+                if !should_retry(&e.to_string(), attempt, attempts) {
+                    return Err(e);
+                }
+
+                let delay = retry_delay(base_delay, attempt);
+                crate::log_warn!(
+                    "kubectl {} failed with a transient error (attempt {}/{}), retrying in {}s...",
+                    args.join(" "),
+                    attempt,
+                    attempts,
+                    delay.as_secs_f32()
+                );
+                std::thread::sleep(delay);
+            }
+        }
     }
+}
+
+/// Decide whether a failed kubectl call should be retried
+fn should_retry(err_str: &str, attempt: u32, attempts: u32) -> bool {
+    attempt < attempts && crate::utils::is_transient_error(err_str)
+}
+
+/// Backoff delay before the given retry attempt (1-based): base_delay * 2^(attempt - 1)
+pub(crate) fn retry_delay(base_delay: Duration, attempt: u32) -> Duration {
+    base_delay * 2u32.saturating_pow(attempt.saturating_sub(1))
+}
+
+/// Apply a YAML manifest from string
+pub fn apply_yaml(yaml: &str, target: &KubeTarget) -> Result<()> {
+    let mut cmd = target.kubectl();
 
-    cmd.args(["apply", "-f", "-"]);
+    cmd.args(["apply", "-f", "-"])
+        .stdout(crate::utils::child_stdout());
 
     let mut child = cmd
         .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
         .spawn()
         .context("Failed to spawn kubectl apply")?;
@@ -81,18 +151,14 @@ pub fn apply_yaml(yaml: &str, kubeconfig: Option<&Path>) -> Result<()> {
 }
 
 /// Apply a YAML manifest using server-side apply (for large CRDs)
-pub fn apply_yaml_server_side(yaml: &str, kubeconfig: Option<&Path>) -> Result<()> {
-    let mut cmd = Command::new("kubectl");
-
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
+pub fn apply_yaml_server_side(yaml: &str, target: &KubeTarget) -> Result<()> {
+    let mut cmd = target.kubectl();
 
-    cmd.args(["apply", "--server-side", "-f", "-"]);
+    cmd.args(["apply", "--server-side", "-f", "-"])
+        .stdout(crate::utils::child_stdout());
 
     let mut child = cmd
         .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
         .spawn()
         .context("Failed to spawn kubectl apply --server-side")?;
@@ -116,18 +182,14 @@ pub fn apply_yaml_server_side(yaml: &str, kubeconfig: Option<&Path>) -> Result<(
 }
 
 /// Create a resource from YAML manifest
-pub fn create_yaml(yaml: &str, kubeconfig: Option<&Path>) -> Result<()> {
-    let mut cmd = Command::new("kubectl");
+pub fn create_yaml(yaml: &str, target: &KubeTarget) -> Result<()> {
+    let mut cmd = target.kubectl();
 
-    if let Some(kc) = kubeconfig {
-        cmd.env("KUBECONFIG", kc);
-    }
-
-    cmd.args(["create", "-f", "-"]);
+    cmd.args(["create", "-f", "-"])
+        .stdout(crate::utils::child_stdout());
 
     let mut child = cmd
         .stdin(std::process::Stdio::piped())
-        .stdout(std::process::Stdio::inherit())
         .stderr(std::process::Stdio::inherit())
         .spawn()
         .context("Failed to spawn kubectl create")?;
@@ -148,14 +210,188 @@ pub fn create_yaml(yaml: &str, kubeconfig: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
+/// Render a JSON (or YAML) manifest as YAML via a client-side dry run
+pub fn render_yaml(manifest: &str, target: &KubeTarget) -> Result<String> {
+    let mut cmd = target.kubectl();
+
+    let mut child = cmd
+        .args(["create", "--dry-run=client", "-o", "yaml", "-f", "-"])
+        .stdin(std::process::Stdio::piped())
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .context("Failed to spawn kubectl create --dry-run")?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        use std::io::Write;
+        stdin
+            .write_all(manifest.as_bytes())
+            .context("Failed to write manifest to kubectl")?;
+    }
+
+    let output = child
+        .wait_with_output()
+        .context("Failed to wait for kubectl create --dry-run")?;
+
+    if !output.status.success() {
+        return Err(anyhow!(
+            "kubectl create --dry-run failed:\n{}",
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8(output.stdout)?)
+}
+
 /// Wait for a resource to be ready
+///
+/// With `--emit-events-on-timeout`, a failed wait includes the object's status
+/// and recent events (pod statuses for deployments) in the returned error.
 pub fn wait_for_condition(
     resource: &str,
     condition: &str,
     namespace: Option<&str>,
     timeout: &str,
-    kubeconfig: Option<&Path>,
+    target: &KubeTarget,
+) -> Result<()> {
+    let result = run_wait(resource, condition, namespace, timeout, target);
+    with_wait_diagnostics(result, resource, namespace, target)
+}
+
+/// [`wait_for_condition`] with a spinner showing the resource's current status.
+/// Falls back to the plain wait when `show_progress` (normally
+/// `behavior.show_progress`) is unset or stderr is not a terminal.
+pub fn wait_for_condition_with_progress(
+    resource: &str,
+    condition: &str,
+    namespace: Option<&str>,
+    timeout: &str,
+    target: &KubeTarget,
+    show_progress: bool,
+) -> Result<()> {
+    let result = run_wait_with_progress(
+        resource,
+        condition,
+        namespace,
+        timeout,
+        target,
+        show_progress,
+    );
+    with_wait_diagnostics(result, resource, namespace, target)
+}
+
+fn with_wait_diagnostics(
+    result: Result<()>,
+    resource: &str,
+    namespace: Option<&str>,
+    target: &KubeTarget,
+) -> Result<()> {
+    if result.is_ok() || !EMIT_EVENTS_ON_TIMEOUT.load(Ordering::Relaxed) {
+        return result;
+    }
+
+    let diagnostics = collect_wait_diagnostics(resource, namespace, target);
+    result.map_err(|e| {
+        if diagnostics.is_empty() {
+            e
+        } else {
+            anyhow!("{:#}\n{}", e, diagnostics)
+        }
+    })
+}
+
+fn run_wait(
+    resource: &str,
+    condition: &str,
+    namespace: Option<&str>,
+    timeout: &str,
+    target: &KubeTarget,
+) -> Result<()> {
+    let args = wait_args(resource, condition, namespace, timeout);
+    run_kubectl(&args, target)
+}
+
+/// How often the progress spinner refreshes the resource status
+const STATUS_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+fn run_wait_with_progress(
+    resource: &str,
+    condition: &str,
+    namespace: Option<&str>,
+    timeout: &str,
+    target: &KubeTarget,
+    show_progress: bool,
 ) -> Result<()> {
+    if !progress_enabled(show_progress) {
+        return run_wait(resource, condition, namespace, timeout, target);
+    }
+
+    let progress = WaitProgress::new(resource, condition.trim_start_matches("condition="));
+    let args = wait_args(resource, condition, namespace, timeout);
+
+    // Output is captured so kubectl's "condition met" line doesn't break the spinner
+    let result = std::thread::scope(|s| {
+        let handle = s.spawn(|| run_kubectl_output(&args, target).map(|_| ()));
+
+        let mut last_poll = Instant::now();
+        while !handle.is_finished() {
+            if last_poll.elapsed() >= STATUS_POLL_INTERVAL {
+                if let Some(status) = resource_status(resource, namespace, target) {
+                    progress.update(&status);
+                }
+                last_poll = Instant::now();
+            }
+            std::thread::sleep(Duration::from_millis(200));
+        }
+
+        handle
+            .join()
+            .unwrap_or_else(|_| Err(anyhow!("kubectl wait thread panicked")))
+    });
+
+    match &result {
+        Ok(()) => progress.finish_success(),
+        Err(e) => progress.finish_error(e.to_string().lines().next().unwrap_or_default()),
+    }
+    result
+}
+
+/// Whether to draw spinners: `show_progress` (normally `behavior.show_progress`)
+/// is set, `--quiet` isn't, and stderr is a terminal
+pub(crate) fn progress_enabled(show_progress: bool) -> bool {
+    show_progress && !crate::utils::is_quiet() && std::io::stderr().is_terminal()
+}
+
+/// Current `kubectl get` row(s) for the resource, condensed to one line
+fn resource_status(resource: &str, namespace: Option<&str>, target: &KubeTarget) -> Option<String> {
+    let mut args = vec!["get", resource, "--no-headers"];
+    if let Some(ns) = namespace {
+        args.extend(["-n", ns]);
+    }
+    let output = run_kubectl_output(&args, target).ok()?;
+    status_line(&output)
+}
+
+/// First row with its columns joined by single spaces, plus a count of the other rows
+fn status_line(output: &str) -> Option<String> {
+    let mut rows = output.lines().filter(|line| !line.trim().is_empty());
+    let first = rows
+        .next()?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    match rows.count() {
+        0 => Some(first),
+        more => Some(format!("{} (+{} more)", first, more)),
+    }
+}
+
+fn wait_args<'a>(
+    resource: &'a str,
+    condition: &'a str,
+    namespace: Option<&'a str>,
+    timeout: &'a str,
+) -> Vec<&'a str> {
     let mut args = vec!["wait", "--for", condition, "--timeout", timeout];
 
     if let Some(ns) = namespace {
@@ -171,49 +407,308 @@ pub fn wait_for_condition(
         args.push("--all");
     }
 
-    run_kubectl(&args, kubeconfig)
+    args
 }
 
-/// Get nodes with custom output
-pub fn get_nodes(output_format: &str, kubeconfig: Option<&Path>) -> Result<String> {
-    run_kubectl_output(&["get", "nodes", "-o", output_format], kubeconfig)
+/// Describe the object(s) a failed wait was watching: deployments get their pod
+/// summary, anything else its `.status` and recent events
+fn collect_wait_diagnostics(
+    resource: &str,
+    namespace: Option<&str>,
+    target: &KubeTarget,
+) -> String {
+    if let (Some(name), Some(ns)) = (deployment_name(resource), namespace) {
+        return collect_deployment_diagnostics(name, ns, target);
+    }
+
+    let mut scope = vec![resource];
+    if let Some(ns) = namespace {
+        scope.extend(["-n", ns]);
+    }
+
+    let mut lines = Vec::new();
+
+    // A bare kind waits on every object of that kind; list them instead
+    let status_args = if resource.contains('/') {
+        ["-o", "jsonpath={.status}"].as_slice()
+    } else {
+        ["-o", "wide"].as_slice()
+    };
+    let get_args: Vec<&str> = ["get"]
+        .iter()
+        .chain(&scope)
+        .chain(status_args)
+        .copied()
+        .collect();
+    match run_kubectl_output(&get_args, target) {
+        Ok(status) if !status.trim().is_empty() => {
+            lines.push(format!("Status of {}:", resource));
+            lines.extend(status.trim().lines().map(|line| format!("  {}", line)));
+        }
+        Ok(_) => {}
+        Err(e) => lines.push(format!("Failed to get {}: {}", resource, e)),
+    }
+
+    let describe_args: Vec<&str> = ["describe"].iter().chain(&scope).copied().collect();
+    if let Ok(describe) = run_kubectl_output(&describe_args, target) {
+        let events = recent_events(&describe, 10);
+        if !events.is_empty() {
+            lines.push("Recent events:".to_string());
+            lines.extend(events.into_iter().map(|event| format!("  {}", event)));
+        }
+    }
+
+    lines.join("\n")
 }
 
-/// Label a node
-pub fn label_node(node_name: &str, label: &str, kubeconfig: Option<&Path>) -> Result<()> {
+/// Name of the deployment in a `deployment/<name>` style resource reference
+fn deployment_name(resource: &str) -> Option<&str> {
+    let (kind, name) = resource.split_once('/')?;
+    let kind = kind.split('.').next().unwrap_or(kind);
+    matches!(kind, "deployment" | "deployments" | "deploy").then_some(name)
+}
+
+/// Restart `deployment` in `namespace` and wait up to `timeout` for the new
+/// pods to roll out, so pods pick up images reloaded under the same tag
+pub fn rollout_restart(
+    deployment: &str,
+    namespace: &str,
+    timeout: &str,
+    target: &KubeTarget,
+) -> Result<()> {
+    let resource = format!("deployment/{}", deployment);
+    crate::log_info!("Restarting {} in {}...", resource, namespace);
+    run_kubectl(&["rollout", "restart", &resource, "-n", namespace], target)
+        .with_context(|| format!("Failed to restart {} in {}", resource, namespace))?;
     run_kubectl(
-        &["label", "nodes", node_name, label, "--overwrite"],
-        kubeconfig,
+        &[
+            "rollout",
+            "status",
+            &resource,
+            "-n",
+            namespace,
+            "--timeout",
+            timeout,
+        ],
+        target,
+    )
+    .with_context(|| format!("{} did not finish rolling out", resource))
+}
+
+/// Wait for a deployment to become Available, reporting pod diagnostics on timeout
+///
+/// Progress is shown as with [`wait_for_condition_with_progress`].
+///
+/// On failure the pods matching the deployment's selector are inspected and their
+/// phase, restart count, and waiting/last-terminated state (e.g. CrashLoopBackOff)
+/// are included in the returned error along with recent events.
+pub fn wait_for_deployment_available(
+    name: &str,
+    namespace: &str,
+    timeout: &str,
+    target: &KubeTarget,
+    show_progress: bool,
+) -> Result<()> {
+    let resource = format!("deployment/{}", name);
+    if run_wait_with_progress(
+        &resource,
+        "condition=Available",
+        Some(namespace),
+        timeout,
+        target,
+        show_progress,
+    )
+    .is_ok()
+    {
+        return Ok(());
+    }
+
+    let diagnostics = collect_deployment_diagnostics(name, namespace, target);
+    Err(KueueDevError::deployment_not_ready(name, namespace)
+        .with_details(diagnostics)
+        .into())
+}
+
+/// Collect pod status and recent events for a deployment's pods
+fn collect_deployment_diagnostics(name: &str, namespace: &str, target: &KubeTarget) -> String {
+    let selector = match run_kubectl_output(
+        &[
+            "get",
+            "deployment",
+            name,
+            "-n",
+            namespace,
+            "-o",
+            "jsonpath={.spec.selector.matchLabels}",
+        ],
+        target,
     )
+    .ok()
+    .and_then(|labels| selector_from_match_labels(&labels))
+    {
+        Some(selector) => selector,
+        None => return format!("Could not determine pod selector for deployment/{}", name),
+    };
+
+    let mut lines = vec![format!("Pods matching {}:", selector)];
+
+    match run_kubectl_output(
+        &[
+            "get", "pods", "-n", namespace, "-l", &selector, "-o", "json",
+        ],
+        target,
+    ) {
+        Ok(json) => {
+            let pods = summarize_pods(&json);
+            if pods.is_empty() {
+                lines.push("  (no pods found)".to_string());
+            }
+            lines.extend(pods.into_iter().map(|pod| format!("  {}", pod)));
+        }
+        Err(e) => lines.push(format!("  Failed to get pods: {}", e)),
+    }
+
+    if let Ok(describe) = run_kubectl_output(
+        &["describe", "pods", "-n", namespace, "-l", &selector],
+        target,
+    ) {
+        let events = recent_events(&describe, 10);
+        if !events.is_empty() {
+            lines.push("Recent events:".to_string());
+            lines.extend(events.into_iter().map(|event| format!("  {}", event)));
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// Build a label selector (`k=v,k2=v2`) from a matchLabels JSON object
+fn selector_from_match_labels(json: &str) -> Option<String> {
+    let labels: serde_json::Map<String, serde_json::Value> = serde_json::from_str(json).ok()?;
+    let mut pairs: Vec<String> = labels
+        .iter()
+        .filter_map(|(k, v)| v.as_str().map(|v| format!("{}={}", k, v)))
+        .collect();
+    if pairs.is_empty() {
+        return None;
+    }
+    pairs.sort();
+    Some(pairs.join(","))
+}
+
+/// Summarize `kubectl get pods -o json` output as one line per pod with phase,
+/// restart count, and container waiting/last-terminated reasons
+fn summarize_pods(json: &str) -> Vec<String> {
+    let Ok(list) = serde_json::from_str::<serde_json::Value>(json) else {
+        return Vec::new();
+    };
+    let Some(items) = list["items"].as_array() else {
+        return Vec::new();
+    };
+
+    items
+        .iter()
+        .map(|pod| {
+            let name = pod["metadata"]["name"].as_str().unwrap_or("unknown");
+            let phase = pod["status"]["phase"].as_str().unwrap_or("Unknown");
+            let statuses = pod["status"]["containerStatuses"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default();
+
+            let restarts: u64 = statuses
+                .iter()
+                .filter_map(|c| c["restartCount"].as_u64())
+                .sum();
+            let mut summary = format!("pod/{}: phase={} restarts={}", name, phase, restarts);
+
+            for container in &statuses {
+                let container_name = container["name"].as_str().unwrap_or("unknown");
+                if let Some(reason) = container["state"]["waiting"]["reason"].as_str() {
+                    summary.push_str(&format!(" {}:waiting={}", container_name, reason));
+                }
+                let last = &container["lastState"]["terminated"];
+                if let Some(reason) = last["reason"].as_str() {
+                    let exit_code = last["exitCode"].as_i64().unwrap_or_default();
+                    summary.push_str(&format!(
+                        " {}:last-terminated={}(exit {})",
+                        container_name, reason, exit_code
+                    ));
+                }
+            }
+
+            summary
+        })
+        .collect()
+}
+
+/// Return the last `limit` lines from the Events sections of `kubectl describe` output
+fn recent_events(describe: &str, limit: usize) -> Vec<String> {
+    let mut events = Vec::new();
+    let mut in_events = false;
+
+    for line in describe.lines() {
+        if line.starts_with("Events:") {
+            in_events = true;
+            continue;
+        }
+        if in_events {
+            // A new unindented section (or the next pod) ends the events block
+            if !line.starts_with(' ') && !line.is_empty() {
+                in_events = false;
+                continue;
+            }
+            let line = line.trim();
+            if !line.is_empty() && !line.starts_with("Type ") && !line.starts_with("----") {
+                events.push(line.to_string());
+            }
+        }
+    }
+
+    let skip = events.len().saturating_sub(limit);
+    events.split_off(skip)
 }
 
-/// Get resources with jsonpath
+/// Get nodes with custom output
+pub fn get_nodes(output_format: &str, target: &KubeTarget) -> Result<String> {
+    run_kubectl_output(&["get", "nodes", "-o", output_format], target)
+}
+
+/// Label a node
+pub fn label_node(node_name: &str, label: &str, target: &KubeTarget) -> Result<()> {
+    run_kubectl(&["label", "nodes", node_name, label, "--overwrite"], target)
+}
+
+/// Get resources with jsonpath, optionally in a specific namespace
 pub fn get_with_jsonpath(
     resource: &str,
+    namespace: Option<&str>,
     jsonpath: &str,
-    kubeconfig: Option<&Path>,
+    target: &KubeTarget,
 ) -> Result<String> {
-    run_kubectl_output(
-        &["get", resource, "-o", &format!("jsonpath={}", jsonpath)],
-        kubeconfig,
-    )
+    let output = format!("jsonpath={}", jsonpath);
+    let mut args = vec!["get", resource, "-o", &output];
+    if let Some(ns) = namespace {
+        args.extend(["-n", ns]);
+    }
+    run_kubectl_output(&args, target)
 }
 
 /// Get operator version from pod logs
-pub fn get_operator_version(kubeconfig: Option<&Path>) -> Result<String> {
+pub fn get_operator_version(namespace: &str, target: &KubeTarget) -> Result<String> {
     // Get the pod name
     let pod_name = run_kubectl_output(
         &[
             "get",
             "pods",
             "-n",
-            "openshift-kueue-operator",
+            namespace,
             "-l",
             "name=openshift-kueue-operator",
             "-o",
             "jsonpath={.items[0].metadata.name}",
         ],
-        kubeconfig,
+        target,
     )?;
 
     if pod_name.is_empty() {
@@ -221,16 +716,7 @@ pub fn get_operator_version(kubeconfig: Option<&Path>) -> Result<String> {
     }
 
     // Get first 10 lines of logs
-    let logs = run_kubectl_output(
-        &[
-            "logs",
-            &pod_name,
-            "-n",
-            "openshift-kueue-operator",
-            "--tail=10",
-        ],
-        kubeconfig,
-    )?;
+    let logs = run_kubectl_output(&["logs", &pod_name, "-n", namespace, "--tail=10"], target)?;
 
     // Look for version in logs (common patterns: "version", "Version", "v=")
     for line in logs.lines() {
@@ -243,7 +729,7 @@ pub fn get_operator_version(kubeconfig: Option<&Path>) -> Result<String> {
 }
 
 /// Get kueue-controller-manager version from pod logs
-pub fn get_kueue_manager_version(namespace: &str, kubeconfig: Option<&Path>) -> Result<String> {
+pub fn get_kueue_manager_version(namespace: &str, target: &KubeTarget) -> Result<String> {
     // Get the pod name
     let pod_name = run_kubectl_output(
         &[
@@ -256,7 +742,7 @@ pub fn get_kueue_manager_version(namespace: &str, kubeconfig: Option<&Path>) ->
             "-o",
             "jsonpath={.items[0].metadata.name}",
         ],
-        kubeconfig,
+        target,
     )?;
 
     if pod_name.is_empty() {
@@ -264,10 +750,7 @@ pub fn get_kueue_manager_version(namespace: &str, kubeconfig: Option<&Path>) ->
     }
 
     // Get first 10 lines of logs
-    let logs = run_kubectl_output(
-        &["logs", &pod_name, "-n", namespace, "--tail=10"],
-        kubeconfig,
-    )?;
+    let logs = run_kubectl_output(&["logs", &pod_name, "-n", namespace, "--tail=10"], target)?;
 
     // Look for version in logs
     for line in logs.lines() {
@@ -281,86 +764,119 @@ pub fn get_kueue_manager_version(namespace: &str, kubeconfig: Option<&Path>) ->
     ))
 }
 
-/// Extract version from a log line
-fn extract_version_from_log(line: &str) -> Option<String> {
-    // Look for common version patterns in logs
-    // Examples:
-    // - openshift-kueue-operator version v0.0.0-unknown-78aa1392-78aa1392
-    // - "gitVersion":"v0.15.0-rc.0-51-g8e20b4c71-dirty"
-    // - "version": "v1.2.3"
-
-    // Check for gitVersion in JSON format (kueue-controller-manager)
-    if let Some(pos) = line.find("\"gitVersion\"") {
-        let after = &line[pos..];
-        if let Some(start) = after.find(':') {
-            let value_start = &after[start + 1..].trim_start();
-            if let Some(quote_start) = value_start.find('"')
-                && let Some(quote_end) = value_start[quote_start + 1..].find('"')
-            {
-                return Some(value_start[quote_start + 1..quote_start + 1 + quote_end].to_string());
-            }
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kubectl_module_exists() {
+        // Basic compile test
     }
 
-    // Check for "openshift-kueue-operator version X" format
-    if let Some(pos) = line.find("openshift-kueue-operator version") {
-        let after = &line[pos + "openshift-kueue-operator version".len()..].trim();
-        if let Some(end) = after.find(|c: char| c.is_whitespace()) {
-            return Some(after[..end].to_string());
-        } else if !after.is_empty() {
-            return Some(after.to_string());
-        }
+    #[test]
+    fn test_should_retry_transient_errors() {
+        let refused = "kubectl command failed: get nodes\nThe connection to the server 127.0.0.1:6443 was refused - did you specify the right host or port?\ndial tcp: connection refused";
+        assert!(should_retry(refused, 1, 3));
+        assert!(should_retry("net/http: TLS handshake timeout", 2, 3));
     }
 
-    // Generic version extraction
-    if let Some(pos) = line.to_lowercase().find("version") {
-        let after_version = &line[pos..];
-        // Try to extract quoted version
-        if let Some(start) = after_version.find('"')
-            && let Some(end) = after_version[start + 1..].find('"')
-        {
-            return Some(after_version[start + 1..start + 1 + end].to_string());
-        }
-        // Try to extract version after colon or equals
-        if let Some(start) = after_version.find(':').or_else(|| after_version.find('=')) {
-            let version_part = after_version[start + 1..].trim();
-            if let Some(end) = version_part.find(|c: char| c.is_whitespace() || c == ',') {
-                return Some(version_part[..end].to_string());
-            } else if !version_part.is_empty() {
-                return Some(version_part.to_string());
-            }
-        }
+    #[test]
+    fn test_should_not_retry_permanent_errors() {
+        let not_found = "kubectl command failed: delete lease foo\nError from server (NotFound): leases \"foo\" not found";
+        assert!(!should_retry(not_found, 1, 3));
+        assert!(!should_retry("error: unknown flag: --bogus", 1, 3));
     }
 
-    None
-}
+    #[test]
+    fn test_should_not_retry_when_attempts_exhausted() {
+        assert!(!should_retry("connection refused", 3, 3));
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_retry_delay_backoff() {
+        let base = Duration::from_secs(1);
+        assert_eq!(retry_delay(base, 1), Duration::from_secs(1));
+        assert_eq!(retry_delay(base, 2), Duration::from_secs(2));
+        assert_eq!(retry_delay(base, 3), Duration::from_secs(4));
+    }
 
     #[test]
-    fn test_kubectl_module_exists() {
-        // Basic compile test
+    fn test_selector_from_match_labels() {
+        assert_eq!(
+            selector_from_match_labels(r#"{"name":"openshift-kueue-operator"}"#),
+            Some("name=openshift-kueue-operator".to_string())
+        );
+        assert_eq!(
+            selector_from_match_labels(
+                r#"{"control-plane":"controller-manager","app.kubernetes.io/name":"kueue"}"#
+            ),
+            Some("app.kubernetes.io/name=kueue,control-plane=controller-manager".to_string())
+        );
+        assert_eq!(selector_from_match_labels("{}"), None);
+        assert_eq!(selector_from_match_labels(""), None);
+    }
+
+    #[test]
+    fn test_summarize_pods_crashloop() {
+        let json = r#"{"items":[{"metadata":{"name":"kueue-controller-manager-abc"},
+            "status":{"phase":"Running","containerStatuses":[{"name":"manager","restartCount":7,
+            "state":{"waiting":{"reason":"CrashLoopBackOff"}},
+            "lastState":{"terminated":{"reason":"Error","exitCode":1}}}]}}]}"#;
+        assert_eq!(
+            summarize_pods(json),
+            vec![
+                "pod/kueue-controller-manager-abc: phase=Running restarts=7 manager:waiting=CrashLoopBackOff manager:last-terminated=Error(exit 1)"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_summarize_pods_pending_and_invalid() {
+        let json = r#"{"items":[{"metadata":{"name":"p"},"status":{"phase":"Pending"}}]}"#;
+        assert_eq!(
+            summarize_pods(json),
+            vec!["pod/p: phase=Pending restarts=0"]
+        );
+        assert!(summarize_pods("not json").is_empty());
+        assert!(summarize_pods(r#"{"items":[]}"#).is_empty());
+    }
+
+    #[test]
+    fn test_status_line() {
+        let output = "kueue-controller-manager   0/1     1            0           12s\n";
+        assert_eq!(
+            status_line(output).as_deref(),
+            Some("kueue-controller-manager 0/1 1 0 12s")
+        );
+        assert_eq!(
+            status_line("a Ready 1m\nb NotReady 1m\n\nc Ready 1m\n").as_deref(),
+            Some("a Ready 1m (+2 more)")
+        );
+        assert_eq!(status_line("\n"), None);
     }
 
     #[test]
-    fn test_extract_operator_version() {
-        let log_line = "I1120 21:25:34.555797       1 builder.go:304] openshift-kueue-operator version v0.0.0-unknown-78aa1392-78aa1392";
-        let version = extract_version_from_log(log_line);
+    fn test_deployment_name() {
+        assert_eq!(deployment_name("deployment/kueue"), Some("kueue"));
+        assert_eq!(deployment_name("deploy/kueue"), Some("kueue"));
+        assert_eq!(deployment_name("deployments.apps/kueue"), Some("kueue"));
         assert_eq!(
-            version,
-            Some("v0.0.0-unknown-78aa1392-78aa1392".to_string())
+            deployment_name("crd/installations.operator.tigera.io"),
+            None
         );
+        assert_eq!(deployment_name("deployment"), None);
     }
 
     #[test]
-    fn test_extract_kueue_version_json() {
-        let log_line = r#"{"level":"info","ts":"2025-11-20T21:26:00.770553599Z","logger":"setup","caller":"kueue/main.go:155","msg":"Initializing","gitVersion":"v0.15.0-rc.0-51-g8e20b4c71-dirty","gitCommit":"8e20b4c71caa998bd11d1d27a52d4e8d0982a341","buildDate":"2025-11-18T18:14:49Z"}"#;
-        let version = extract_version_from_log(log_line);
+    fn test_recent_events() {
+        let describe = "Name: p\nEvents:\n  Type     Reason   Age  From  Message\n  ----     ------   ---  ----  -------\n  Normal   Pulled   1m   kubelet  Pulled image\n  Warning  BackOff  10s  kubelet  Back-off restarting failed container\n\n\nName: q\nEvents:  <none>\n";
         assert_eq!(
-            version,
-            Some("v0.15.0-rc.0-51-g8e20b4c71-dirty".to_string())
+            recent_events(describe, 10),
+            vec![
+                "Normal   Pulled   1m   kubelet  Pulled image",
+                "Warning  BackOff  10s  kubelet  Back-off restarting failed container"
+            ]
         );
+        assert_eq!(recent_events(describe, 1).len(), 1);
     }
 }
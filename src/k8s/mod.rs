@@ -4,6 +4,9 @@ pub mod images;
 pub mod kind;
 pub mod kubectl;
 pub mod nodes;
+pub mod registry;
+pub mod target;
+pub mod version;
 
 // Placeholder modules - will be implemented in later phases
 // pub mod resources;
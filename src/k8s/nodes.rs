@@ -1,22 +1,232 @@
 //! Node management operations
 
+use crate::config::settings::TestSettings;
 use crate::k8s::kubectl;
-use anyhow::{Context, Result};
+use crate::k8s::target::KubeTarget;
+use anyhow::{Context, Result, anyhow};
+use regex::Regex;
+use std::collections::HashSet;
 use std::path::Path;
+use std::str::FromStr;
 
-/// Label worker nodes with instance-type for e2e tests
+/// How extra worker labels are applied to the worker nodes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelMode {
+    /// Every worker gets every label
+    #[default]
+    Uniform,
+    /// The values given for a key are assigned to the workers in turn
+    Distribute,
+}
+
+impl FromStr for LabelMode {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "uniform" => Ok(LabelMode::Uniform),
+            "distribute" => Ok(LabelMode::Distribute),
+            _ => Err(anyhow!(
+                "Invalid label mode: {}. Must be 'uniform' or 'distribute'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for LabelMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LabelMode::Uniform => write!(f, "uniform"),
+            LabelMode::Distribute => write!(f, "distribute"),
+        }
+    }
+}
+
+/// Parse a `key=value` worker node label
+pub fn parse_node_label(entry: &str) -> Result<(String, String), String> {
+    let (key, value) = entry
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got '{}'", entry))?;
+    let valid_key = !key.is_empty() && !key.contains(char::is_whitespace);
+    let valid_value = value.len() <= 63
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid_key || !valid_value {
+        return Err(format!(
+            "invalid node label '{}': values are at most 63 characters of [A-Za-z0-9-_.]",
+            entry
+        ));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// Labels applied to the worker nodes on top of the `instance-type` ones
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkerLabels {
+    labels: Vec<(String, String)>,
+    mode: LabelMode,
+}
+
+impl WorkerLabels {
+    /// `tests.node_labels` plus `extra` (`--node-label`). A key given in `extra`
+    /// replaces the configured values for that key. `mode` defaults to
+    /// `tests.node_label_mode`.
+    pub fn new(
+        tests: &TestSettings,
+        extra: Vec<(String, String)>,
+        mode: Option<LabelMode>,
+    ) -> Result<Self> {
+        let mode = match mode {
+            Some(mode) => mode,
+            None => LabelMode::from_str(&tests.node_label_mode)
+                .context("Invalid tests.node_label_mode")?,
+        };
+
+        let mut labels = tests
+            .node_labels
+            .iter()
+            .map(|entry| {
+                parse_node_label(entry).map_err(|e| anyhow!("Invalid tests.node_labels: {}", e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        labels.retain(|(key, _)| !extra.iter().any(|(k, _)| k == key));
+        labels.extend(extra);
+        Self::from_labels(labels, mode)
+    }
+
+    /// Exactly `labels`, applied per `mode`
+    pub fn from_labels(labels: Vec<(String, String)>, mode: LabelMode) -> Result<Self> {
+        if mode == LabelMode::Uniform {
+            let mut keys = HashSet::new();
+            if let Some((key, _)) = labels.iter().find(|(key, _)| !keys.insert(key)) {
+                return Err(anyhow!(
+                    "Node label '{}' is given more than once; use --label-mode distribute \
+                     to spread its values across the nodes",
+                    key
+                ));
+            }
+        }
+
+        Ok(Self { labels, mode })
+    }
+
+    /// `key=value` labels for the node at `index` (in labeling order)
+    fn for_worker(&self, index: usize) -> Vec<String> {
+        let mut keys: Vec<&str> = Vec::new();
+        for (key, _) in &self.labels {
+            if !keys.contains(&key.as_str()) {
+                keys.push(key);
+            }
+        }
+
+        keys.into_iter()
+            .flat_map(|key| {
+                let values: Vec<&str> = self
+                    .labels
+                    .iter()
+                    .filter(|(k, _)| k == key)
+                    .map(|(_, v)| v.as_str())
+                    .collect();
+                match self.mode {
+                    LabelMode::Uniform => values,
+                    LabelMode::Distribute => vec![values[index % values.len()]],
+                }
+                .into_iter()
+                .map(move |value| format!("{}={}", key, value))
+            })
+            .collect()
+    }
+}
+
+/// Which nodes [`label_nodes`] labels
+#[derive(Debug, Clone)]
+pub enum NodeSelector {
+    All,
+    /// Nodes without the control-plane role
+    Workers,
+    /// Nodes with the control-plane role
+    ControlPlane,
+    /// Nodes whose name matches the pattern
+    Name(Regex),
+}
+
+impl NodeSelector {
+    /// Selector for nodes whose name matches the regex `pattern`
+    pub fn name(pattern: &str) -> Result<Self> {
+        Regex::new(pattern)
+            .map(NodeSelector::Name)
+            .with_context(|| format!("Invalid node name pattern: {}", pattern))
+    }
+}
+
+impl std::fmt::Display for NodeSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NodeSelector::All => write!(f, "all nodes"),
+            NodeSelector::Workers => write!(f, "worker nodes"),
+            NodeSelector::ControlPlane => write!(f, "control-plane nodes"),
+            NodeSelector::Name(pattern) => write!(f, "nodes matching '{}'", pattern),
+        }
+    }
+}
+
+/// Label the nodes picked by `selector`. With [`LabelMode::Distribute`], values
+/// are assigned in node name order.
+///
+/// Safe to re-run: the node list is fetched fresh on every call, labels are
+/// applied with `--overwrite`, and nodes that disappear mid-operation are skipped.
+pub fn label_nodes(
+    selector: &NodeSelector,
+    labels: &WorkerLabels,
+    kubeconfig: Option<&Path>,
+) -> Result<()> {
+    crate::log_info!("Labeling {}...", selector);
+    let target = KubeTarget::new(kubeconfig);
+    let nodes = find_nodes(selector, &target)?;
+    if nodes.is_empty() {
+        crate::log_warn!("No {} found to label", selector);
+        return Ok(());
+    }
+
+    label_each(&nodes, &target, |index| labels.for_worker(index))?;
+    crate::log_info!("Labeled {} node(s)", nodes.len());
+    Ok(())
+}
+
+/// Label worker nodes with instance-type for e2e tests, plus `extra` labels
 /// First worker node gets "instance-type=on-demand"
 /// Second worker node gets "instance-type=spot"
-pub fn label_worker_nodes(kubeconfig: Option<&Path>) -> Result<()> {
+///
+/// Safe to re-run, like [`label_nodes`].
+pub fn label_worker_nodes(target: &KubeTarget, extra: &WorkerLabels) -> Result<()> {
     crate::log_info!("Labeling worker nodes with instance-type...");
+    let worker_nodes = find_nodes(&NodeSelector::Workers, target)?;
+    if worker_nodes.is_empty() {
+        crate::log_warn!("No worker nodes found to label");
+        return Ok(());
+    }
+
+    label_each(&worker_nodes, target, |index| {
+        let instance_type = if index == 0 { "on-demand" } else { "spot" };
+        let mut labels = vec![format!("instance-type={}", instance_type)];
+        labels.extend(extra.for_worker(index));
+        labels
+    })?;
+
+    crate::log_info!("Worker nodes labeled successfully");
+    Ok(())
+}
 
-    // Get all worker nodes (nodes without the control-plane role)
+/// Names of the nodes picked by `selector`, sorted
+fn find_nodes(selector: &NodeSelector, target: &KubeTarget) -> Result<Vec<String>> {
     // We can't use JSONPath to check for missing labels, so we'll get all nodes and filter
-    let all_nodes = kubectl::run_kubectl_output(&["get", "nodes", "-o", "name"], kubeconfig)
+    let all_nodes = kubectl::run_kubectl_output(&["get", "nodes", "-o", "name"], target)
         .context("Failed to get nodes")?;
 
     // Get control-plane nodes using label selector
-    let control_plane_nodes_output = kubectl::run_kubectl_output(
+    let control_plane_nodes = kubectl::run_kubectl_output(
         &[
             "get",
             "nodes",
@@ -25,62 +235,187 @@ pub fn label_worker_nodes(kubeconfig: Option<&Path>) -> Result<()> {
             "-o",
             "name",
         ],
-        kubeconfig,
+        target,
     )
     .unwrap_or_default();
 
-    let control_plane_nodes: std::collections::HashSet<String> = control_plane_nodes_output
-        .lines()
-        .map(|line| {
-            line.trim()
-                .strip_prefix("node/")
-                .unwrap_or(line.trim())
-                .to_string()
-        })
-        .collect();
+    Ok(select_nodes(selector, &all_nodes, &control_plane_nodes))
+}
 
-    let mut worker_nodes = Vec::new();
-    for node in all_nodes.lines() {
-        let node_name = node.trim().strip_prefix("node/").unwrap_or(node.trim());
+/// Apply `labels_for(index)` to each node. `index` counts the nodes actually
+/// labeled, so a node that disappeared doesn't shift the assignment.
+fn label_each(
+    nodes: &[String],
+    target: &KubeTarget,
+    labels_for: impl Fn(usize) -> Vec<String>,
+) -> Result<()> {
+    let mut labeled = 0;
+    for node in nodes {
+        let labels = labels_for(labeled);
+        if labels.is_empty() {
+            continue;
+        }
 
-        // Check if this node is a control-plane node
-        if !control_plane_nodes.contains(node_name) {
-            worker_nodes.push(node_name.to_string());
+        crate::log_info!("Labeling node {} with {}", node, labels.join(", "));
+        if let Err(e) = labels
+            .iter()
+            .try_for_each(|label| kubectl::label_node(node, label, target))
+        {
+            if !node_exists(node, target) {
+                crate::log_warn!("Node {} no longer exists, skipping", node);
+                continue;
+            }
+            return Err(e).with_context(|| format!("Failed to label node {}", node));
         }
+        labeled += 1;
     }
+    Ok(())
+}
 
-    if worker_nodes.is_empty() {
-        crate::log_warn!("No worker nodes found to label");
-        return Ok(());
-    }
+/// Check whether a node still exists
+fn node_exists(node: &str, target: &KubeTarget) -> bool {
+    kubectl::run_kubectl_output(&["get", "node", node, "-o", "name"], target).is_ok()
+}
 
-    // Sort worker nodes by name for consistent labeling
-    worker_nodes.sort();
+/// Select nodes from `kubectl get nodes -o name` output, given the output of the
+/// same query for control-plane nodes. Returns bare node names sorted for
+/// consistent labeling.
+fn select_nodes(
+    selector: &NodeSelector,
+    all_nodes: &str,
+    control_plane_nodes: &str,
+) -> Vec<String> {
+    let control_plane: HashSet<&str> = control_plane_nodes.lines().filter_map(node_name).collect();
 
-    for (index, node) in worker_nodes.iter().enumerate() {
-        let instance_type = if index == 0 { "on-demand" } else { "spot" };
+    let mut nodes: Vec<String> = all_nodes
+        .lines()
+        .filter_map(node_name)
+        .filter(|name| match selector {
+            NodeSelector::All => true,
+            NodeSelector::Workers => !control_plane.contains(name),
+            NodeSelector::ControlPlane => control_plane.contains(name),
+            NodeSelector::Name(pattern) => pattern.is_match(name),
+        })
+        .map(str::to_string)
+        .collect();
 
-        crate::log_info!(
-            "Labeling node {} with instance-type={}",
-            node,
-            instance_type
-        );
-        kubectl::label_node(
-            node,
-            &format!("instance-type={}", instance_type),
-            kubeconfig,
-        )
-        .with_context(|| format!("Failed to label node {}", node))?;
-    }
+    nodes.sort();
+    nodes.dedup();
+    nodes
+}
 
-    crate::log_info!("Worker nodes labeled successfully");
-    Ok(())
+/// Strip the `node/` prefix from a `-o name` line, ignoring blank lines
+fn node_name(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let name = line.strip_prefix("node/").unwrap_or(line);
+    (!name.is_empty()).then_some(name)
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    fn select_worker_nodes(all_nodes: &str, control_plane_nodes: &str) -> Vec<String> {
+        select_nodes(&NodeSelector::Workers, all_nodes, control_plane_nodes)
+    }
+
     #[test]
     fn test_node_module_exists() {
         // Basic compile test
     }
+
+    #[test]
+    fn test_select_worker_nodes_excludes_control_plane() {
+        let all = "node/kueue-test-control-plane\nnode/kueue-test-control-plane2\nnode/kueue-test-worker2\nnode/kueue-test-worker\n";
+        let cp = "node/kueue-test-control-plane\nnode/kueue-test-control-plane2\n";
+        assert_eq!(
+            select_worker_nodes(all, cp),
+            vec!["kueue-test-worker", "kueue-test-worker2"]
+        );
+    }
+
+    #[test]
+    fn test_select_worker_nodes_handles_blank_and_unprefixed_lines() {
+        let all = "\nnode/worker-b\n  worker-a  \n\n";
+        assert_eq!(select_worker_nodes(all, ""), vec!["worker-a", "worker-b"]);
+    }
+
+    #[test]
+    fn test_parse_node_label() {
+        assert_eq!(
+            parse_node_label("topology.kubernetes.io/zone=zone-a"),
+            Ok((
+                "topology.kubernetes.io/zone".to_string(),
+                "zone-a".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_node_label("rack="),
+            Ok(("rack".to_string(), String::new()))
+        );
+        assert!(parse_node_label("rack").is_err());
+        assert!(parse_node_label("=a").is_err());
+        assert!(parse_node_label("rack=a b").is_err());
+        assert!(parse_node_label(&format!("rack={}", "a".repeat(64))).is_err());
+    }
+
+    #[test]
+    fn test_worker_labels() {
+        let mut tests = TestSettings {
+            node_labels: vec!["rack=r1".to_string(), "zone=a".to_string()],
+            ..Default::default()
+        };
+
+        // --node-label replaces the configured values of the same key
+        let labels =
+            WorkerLabels::new(&tests, vec![("rack".to_string(), "r2".to_string())], None).unwrap();
+        assert_eq!(labels.for_worker(0), ["zone=a", "rack=r2"]);
+        assert_eq!(labels.for_worker(1), ["zone=a", "rack=r2"]);
+
+        let zones = vec![
+            ("zone".to_string(), "b".to_string()),
+            ("zone".to_string(), "c".to_string()),
+        ];
+        assert!(WorkerLabels::new(&tests, zones.clone(), None).is_err());
+        let labels = WorkerLabels::new(&tests, zones.clone(), Some(LabelMode::Distribute)).unwrap();
+        assert_eq!(labels.for_worker(0), ["rack=r1", "zone=b"]);
+        assert_eq!(labels.for_worker(1), ["rack=r1", "zone=c"]);
+        assert_eq!(labels.for_worker(2), ["rack=r1", "zone=b"]);
+
+        tests.node_labels = vec!["zone".to_string()];
+        assert!(WorkerLabels::new(&tests, vec![], None).is_err());
+        assert!(
+            WorkerLabels::new(&TestSettings::default(), vec![], None)
+                .unwrap()
+                .for_worker(0)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_select_nodes() {
+        let all = "node/kueue-test-control-plane\nnode/kueue-test-worker2\nnode/kueue-test-worker\nnode/gpu-a\n";
+        let cp = "node/kueue-test-control-plane\n";
+
+        assert_eq!(select_nodes(&NodeSelector::All, all, cp).len(), 4);
+        assert_eq!(
+            select_nodes(&NodeSelector::ControlPlane, all, cp),
+            vec!["kueue-test-control-plane"]
+        );
+        assert_eq!(
+            select_nodes(&NodeSelector::name("worker[0-9]*$").unwrap(), all, cp),
+            vec!["kueue-test-worker", "kueue-test-worker2"]
+        );
+        assert_eq!(
+            select_nodes(&NodeSelector::name("^gpu-").unwrap(), all, cp),
+            vec!["gpu-a"]
+        );
+        assert!(NodeSelector::name("(").is_err());
+    }
+
+    #[test]
+    fn test_select_worker_nodes_none() {
+        assert!(select_worker_nodes("node/cp\n", "node/cp\n").is_empty());
+        assert!(select_worker_nodes("", "").is_empty());
+    }
 }
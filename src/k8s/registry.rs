@@ -0,0 +1,264 @@
+//! Local image registry container shared by kind clusters
+//! (kind's local-registry pattern: https://kind.sigs.k8s.io/docs/user/local-registry/)
+
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::utils::ContainerRuntime;
+
+/// Name of the registry container
+pub const CONTAINER_NAME: &str = "kind-registry";
+
+/// Host port the registry is published on when none is given
+pub const DEFAULT_PORT: u16 = 5001;
+
+/// Registry image run by `registry start`
+const IMAGE: &str = "registry:2";
+
+/// Container network kind creates for its nodes
+const KIND_NETWORK: &str = "kind";
+
+/// The local registry, as recorded by `registry start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LocalRegistry {
+    /// Host port published on 127.0.0.1
+    pub port: u16,
+}
+
+/// File recording the running registry's port
+fn state_path() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("kueue-dev").join("registry.toml"))
+}
+
+impl LocalRegistry {
+    /// Endpoint the kind nodes reach the registry at over the kind network
+    pub const NODE_ENDPOINT: &str = "http://kind-registry:5000";
+
+    pub fn new(port: u16) -> Self {
+        Self { port }
+    }
+
+    /// Address images are pushed to from the host, e.g. `localhost:5001`
+    pub fn address(&self) -> String {
+        format!("localhost:{}", self.port)
+    }
+
+    /// Whether `image` is pulled from this registry
+    pub fn serves(&self, image: &str) -> bool {
+        image
+            .strip_prefix(&self.address())
+            .is_some_and(|rest| rest.starts_with('/'))
+    }
+
+    /// Registry recorded by `registry start`, whether or not it is still running
+    pub fn load() -> Option<Self> {
+        Self::load_from(&state_path()?)
+    }
+
+    fn load_from(path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(path).ok()?;
+        match toml::from_str(&content) {
+            Ok(registry) => Some(registry),
+            Err(e) => {
+                crate::log_warn!("Ignoring unreadable local registry state: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Registry recorded by `registry start`, if its container is running
+    pub fn active(runtime: &ContainerRuntime) -> Option<Self> {
+        Self::load().filter(|_| Self::is_running(runtime))
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = state_path().ok_or_else(|| anyhow!("Could not determine data directory"))?;
+        self.save_to(&path)
+    }
+
+    fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .with_context(|| format!("Failed to create directory: {}", dir.display()))?;
+        }
+        std::fs::write(path, toml::to_string(self)?).context("Failed to write local registry state")
+    }
+
+    /// Whether the registry container exists and is running
+    pub fn is_running(runtime: &ContainerRuntime) -> bool {
+        container_state(runtime).as_deref() == Some("true")
+    }
+
+    /// Run the registry container and record its port. A stopped container is
+    /// replaced, since it may publish a different port.
+    pub fn start(&self, runtime: &ContainerRuntime) -> Result<()> {
+        match container_state(runtime).as_deref() {
+            Some("true") => {
+                crate::log_info!("Registry container '{}' is already running", CONTAINER_NAME);
+                return self.save();
+            }
+            Some(_) => remove_container(runtime)?,
+            None => {}
+        }
+
+        crate::log_info!(
+            "Running {} as '{}' on {}...",
+            IMAGE,
+            CONTAINER_NAME,
+            self.address()
+        );
+        let publish = format!("127.0.0.1:{}:5000", self.port);
+        let status = Command::new(runtime.command())
+            .args(["run", "-d", "--restart=always", "-p", &publish])
+            .args(["--network", "bridge", "--name", CONTAINER_NAME, IMAGE])
+            .stdout(std::process::Stdio::null())
+            .status()
+            .context("Failed to start registry container")?;
+        if !status.success() {
+            return Err(anyhow!(
+                "Failed to start registry container '{}'",
+                CONTAINER_NAME
+            ));
+        }
+
+        self.save()
+    }
+
+    /// Remove the registry container and forget its port
+    pub fn stop(runtime: &ContainerRuntime) -> Result<()> {
+        if container_state(runtime).is_some() {
+            remove_container(runtime)?;
+            crate::log_info!("Removed registry container '{}'", CONTAINER_NAME);
+        } else {
+            crate::log_info!("Registry container '{}' is not running", CONTAINER_NAME);
+        }
+
+        if let Some(path) = state_path() {
+            std::fs::remove_file(path).ok();
+        }
+        Ok(())
+    }
+
+    /// Attach the registry to the kind network so nodes can reach it by name.
+    /// Returns false when the network doesn't exist yet (no kind cluster has been created).
+    pub fn connect_to_kind_network(runtime: &ContainerRuntime) -> Result<bool> {
+        let network_exists = Command::new(runtime.command())
+            .args(["network", "inspect", KIND_NETWORK])
+            .output()
+            .is_ok_and(|output| output.status.success());
+        if !network_exists {
+            return Ok(false);
+        }
+
+        let networks = Command::new(runtime.command())
+            .args([
+                "inspect",
+                "-f",
+                "{{json .NetworkSettings.Networks}}",
+                CONTAINER_NAME,
+            ])
+            .output()
+            .context("Failed to inspect registry container")?;
+        let networks: serde_json::Value =
+            serde_json::from_slice(&networks.stdout).unwrap_or_default();
+        if networks.get(KIND_NETWORK).is_some() {
+            return Ok(true);
+        }
+
+        let status = Command::new(runtime.command())
+            .args(["network", "connect", KIND_NETWORK, CONTAINER_NAME])
+            .status()
+            .context("Failed to connect registry to the kind network")?;
+        if !status.success() {
+            return Err(anyhow!(
+                "Failed to connect registry container '{}' to the '{}' network",
+                CONTAINER_NAME,
+                KIND_NETWORK
+            ));
+        }
+
+        crate::log_info!("Connected '{}' to the kind network", CONTAINER_NAME);
+        Ok(true)
+    }
+
+    /// ConfigMap advertising the registry to cluster tooling (KEP-1755)
+    pub fn hosting_configmap(&self) -> String {
+        format!(
+            r#"apiVersion: v1
+kind: ConfigMap
+metadata:
+  name: local-registry-hosting
+  namespace: kube-public
+data:
+  localRegistryHosting.v1: |
+    host: "{}"
+    help: "https://kind.sigs.k8s.io/docs/user/local-registry/"
+"#,
+            self.address()
+        )
+    }
+}
+
+fn remove_container(runtime: &ContainerRuntime) -> Result<()> {
+    let status = Command::new(runtime.command())
+        .args(["rm", "-f", CONTAINER_NAME])
+        .stdout(std::process::Stdio::null())
+        .status()
+        .context("Failed to remove registry container")?;
+    if !status.success() {
+        return Err(anyhow!(
+            "Failed to remove registry container '{}'",
+            CONTAINER_NAME
+        ));
+    }
+    Ok(())
+}
+
+/// `.State.Running` of the registry container, or None if it doesn't exist
+fn container_state(runtime: &ContainerRuntime) -> Option<String> {
+    let output = Command::new(runtime.command())
+        .args(["inspect", "-f", "{{.State.Running}}", CONTAINER_NAME])
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_serves() {
+        let registry = LocalRegistry::new(5001);
+        assert_eq!(registry.address(), "localhost:5001");
+        assert!(registry.serves("localhost:5001/me/kueue-operator:dev"));
+        assert!(!registry.serves("localhost:50010/me/kueue-operator:dev"));
+        assert!(!registry.serves("quay.io/me/kueue-operator:dev"));
+        assert!(
+            registry
+                .hosting_configmap()
+                .contains("host: \"localhost:5001\"")
+        );
+    }
+
+    #[test]
+    fn test_state_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("kueue-dev").join("registry.toml");
+
+        assert_eq!(LocalRegistry::load_from(&path), None);
+        LocalRegistry::new(5002).save_to(&path).unwrap();
+        assert_eq!(
+            LocalRegistry::load_from(&path),
+            Some(LocalRegistry::new(5002))
+        );
+
+        std::fs::write(&path, "port = \"high\"").unwrap();
+        assert_eq!(LocalRegistry::load_from(&path), None);
+    }
+}
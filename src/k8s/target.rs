@@ -0,0 +1,183 @@
+//! Cluster connection settings shared by kubectl, oc and helm calls
+
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Set by `--context`
+static CONTEXT: OnceLock<Option<String>> = OnceLock::new();
+
+/// Use the named kubeconfig context for kubectl, oc and helm calls
+pub fn set_context(context: Option<String>) {
+    CONTEXT.get_or_init(|| context);
+}
+
+/// Context given with `--context`, if any
+pub fn context() -> Option<&'static str> {
+    CONTEXT.get().and_then(|context| context.as_deref())
+}
+
+/// Binary used for kubectl calls
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KubeCli {
+    Kubectl,
+    /// OpenShift CLI, which embeds kubectl
+    Oc,
+}
+
+impl FromStr for KubeCli {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "kubectl" => Ok(KubeCli::Kubectl),
+            "oc" => Ok(KubeCli::Oc),
+            _ => Err(anyhow::anyhow!(
+                "Invalid kube CLI: {}. Must be 'kubectl' or 'oc'",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for KubeCli {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.command())
+    }
+}
+
+impl KubeCli {
+    /// kubectl if it is installed, otherwise oc if that is
+    pub fn detect() -> Self {
+        if which::which("kubectl").is_err() && which::which("oc").is_ok() {
+            KubeCli::Oc
+        } else {
+            KubeCli::Kubectl
+        }
+    }
+
+    pub fn command(&self) -> &'static str {
+        match self {
+            KubeCli::Kubectl => "kubectl",
+            KubeCli::Oc => "oc",
+        }
+    }
+}
+
+/// Set by `--kube-cli`; detected on first use when not given
+static KUBE_CLI: OnceLock<KubeCli> = OnceLock::new();
+
+/// Use `cli` for kubectl calls instead of detecting it
+pub fn set_kube_cli(cli: Option<KubeCli>) {
+    if let Some(cli) = cli {
+        KUBE_CLI.get_or_init(|| cli);
+    }
+}
+
+/// Binary used for kubectl calls
+pub fn kube_cli() -> KubeCli {
+    *KUBE_CLI.get_or_init(KubeCli::detect)
+}
+
+/// The cluster a command talks to: kubeconfig file(s), context and default namespace
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KubeTarget {
+    /// Kubeconfig path, possibly a colon-separated list
+    pub kubeconfig: Option<PathBuf>,
+    /// Context to use instead of the kubeconfig's current context
+    pub context: Option<String>,
+    /// Namespace used when the command doesn't name one
+    pub namespace: Option<String>,
+}
+
+impl KubeTarget {
+    /// Target for `kubeconfig`, using the `--context` option if given
+    pub fn new(kubeconfig: Option<&Path>) -> Self {
+        Self {
+            kubeconfig: kubeconfig.map(Path::to_path_buf),
+            context: context().map(str::to_string),
+            namespace: None,
+        }
+    }
+
+    pub fn kubeconfig(&self) -> Option<&Path> {
+        self.kubeconfig.as_deref()
+    }
+
+    /// `program` (kubectl, oc or helm) pointed at this target
+    pub fn command(&self, program: &str) -> Command {
+        let mut cmd = Command::new(program);
+        self.apply_to(&mut cmd);
+        cmd
+    }
+
+    /// The selected kubectl binary (see `--kube-cli`) pointed at this target
+    pub fn kubectl(&self) -> Command {
+        self.command(kube_cli().command())
+    }
+
+    /// Point `cmd` at this target. Call before adding the command's own arguments,
+    /// so an explicit `-n` given later overrides the default namespace.
+    pub fn apply_to(&self, cmd: &mut Command) {
+        // Pass kubeconfig via the environment so colon-separated lists are merged
+        if let Some(kc) = &self.kubeconfig {
+            cmd.env("KUBECONFIG", kc);
+        }
+        if let Some(context) = &self.context {
+            let flag = if cmd.get_program() == "helm" {
+                "--kube-context"
+            } else {
+                "--context"
+            };
+            cmd.args([flag, context]);
+        }
+        if let Some(namespace) = &self.namespace {
+            cmd.args(["--namespace", namespace]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_to() {
+        let target = KubeTarget {
+            kubeconfig: Some(PathBuf::from("/tmp/kubeconfig")),
+            context: Some("kind-dev".to_string()),
+            namespace: None,
+        };
+        let cmd = target.command("kubectl");
+        assert_eq!(
+            cmd.get_args().collect::<Vec<_>>(),
+            ["--context", "kind-dev"]
+        );
+        assert!(cmd.get_envs().any(|(key, value)| {
+            key == "KUBECONFIG" && value == Some(Path::new("/tmp/kubeconfig").as_os_str())
+        }));
+
+        let cmd = KubeTarget {
+            namespace: Some("kueue-system".to_string()),
+            ..target.clone()
+        }
+        .command("helm");
+        assert_eq!(
+            cmd.get_args().collect::<Vec<_>>(),
+            ["--kube-context", "kind-dev", "--namespace", "kueue-system"]
+        );
+
+        let cmd = KubeTarget::default().command("oc");
+        assert_eq!(cmd.get_args().count(), 0);
+        assert_eq!(cmd.get_envs().count(), 0);
+    }
+
+    #[test]
+    fn test_kube_cli_from_str() {
+        assert_eq!(KubeCli::from_str("OC").unwrap(), KubeCli::Oc);
+        assert_eq!(KubeCli::Kubectl.to_string(), "kubectl");
+        assert!(KubeCli::from_str("k9s").is_err());
+    }
+}
@@ -0,0 +1,167 @@
+//! Version string extraction from component logs
+
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// `"gitVersion":"v0.15.0-rc.0"` as logged by kueue-controller-manager
+fn git_version_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r#""gitVersion"\s*:\s*"([^"]+)""#).expect("valid regex"))
+}
+
+/// `openshift-kueue-operator version v0.0.0-unknown` as logged by the operator
+fn operator_version_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"openshift-kueue-operator version\s+(v?[0-9A-Za-z](?:[0-9A-Za-z.+\-]*[0-9A-Za-z])?)",
+        )
+        .expect("valid regex")
+    })
+}
+
+/// Any semver-like `v1.2.3[-pre][+build]` token
+fn generic_version_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"\bv\d+\.\d+\.\d+(?:[-+.][0-9A-Za-z]+)*").expect("valid regex"))
+}
+
+/// Extract a version from a log line
+///
+/// Recognized formats, in priority order:
+/// - `"gitVersion":"v0.15.0-rc.0-51-g8e20b4c71-dirty"` (JSON, kueue-controller-manager)
+/// - `openshift-kueue-operator version v0.0.0-unknown-78aa1392` (operator)
+/// - any `v1.2.3`-style token; the first one on the line wins
+pub fn extract_version_from_log(line: &str) -> Option<String> {
+    if let Some(caps) = git_version_regex().captures(line) {
+        return Some(caps[1].to_string());
+    }
+
+    if let Some(caps) = operator_version_regex().captures(line) {
+        return Some(caps[1].to_string());
+    }
+
+    generic_version_regex()
+        .find(line)
+        .map(|m| m.as_str().to_string())
+}
+
+/// Parse the `major.minor.patch` core of a version like `v1.34.1-ocp`
+pub fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let core = version
+        .trim()
+        .trim_start_matches('v')
+        .split(['-', '+'])
+        .next()?;
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().ok());
+    let major = parts.next()??;
+    let minor = parts.next()??;
+    let patch = parts.next().unwrap_or(Some(0))?;
+    Some((major, minor, patch))
+}
+
+/// Whether `version` is at least `minimum`; None if either cannot be parsed
+pub fn version_at_least(version: &str, minimum: &str) -> Option<bool> {
+    Some(parse_semver(version)? >= parse_semver(minimum)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_operator_version() {
+        let log_line = "I1120 21:25:34.555797       1 builder.go:304] openshift-kueue-operator version v0.0.0-unknown-78aa1392-78aa1392";
+        let version = extract_version_from_log(log_line);
+        assert_eq!(
+            version,
+            Some("v0.0.0-unknown-78aa1392-78aa1392".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_kueue_version_json() {
+        let log_line = r#"{"level":"info","ts":"2025-11-20T21:26:00.770553599Z","logger":"setup","caller":"kueue/main.go:155","msg":"Initializing","gitVersion":"v0.15.0-rc.0-51-g8e20b4c71-dirty","gitCommit":"8e20b4c71caa998bd11d1d27a52d4e8d0982a341","buildDate":"2025-11-18T18:14:49Z"}"#;
+        let version = extract_version_from_log(log_line);
+        assert_eq!(
+            version,
+            Some("v0.15.0-rc.0-51-g8e20b4c71-dirty".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_generic_version() {
+        let log_line = r#"{"msg":"starting","version":"v1.2.3"}"#;
+        assert_eq!(
+            extract_version_from_log(log_line),
+            Some("v1.2.3".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_no_version() {
+        assert_eq!(extract_version_from_log(""), None);
+        assert_eq!(
+            extract_version_from_log(
+                "I1120 21:25:34.555797 1 leaderelection.go:257] attempting to acquire leader lease"
+            ),
+            None
+        );
+        // IP addresses and timestamps are not versions
+        assert_eq!(
+            extract_version_from_log("listening on 10.244.0.1:8443 at 2025-11-20T21:26:00.77Z"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_extract_first_of_multiple_versions() {
+        let log_line = "upgrading from v0.14.2 to v0.15.0";
+        assert_eq!(
+            extract_version_from_log(log_line),
+            Some("v0.14.2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_version_trailing_punctuation() {
+        assert_eq!(
+            extract_version_from_log("running kueue v0.15.0."),
+            Some("v0.15.0".to_string())
+        );
+        assert_eq!(
+            extract_version_from_log("running kueue v0.15.0-rc.1, commit abc"),
+            Some("v0.15.0-rc.1".to_string())
+        );
+        assert_eq!(
+            extract_version_from_log("openshift-kueue-operator version v1.0.0;"),
+            Some("v1.0.0".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_semver() {
+        assert_eq!(parse_semver("v1.34.1"), Some((1, 34, 1)));
+        assert_eq!(parse_semver("1.25.0-ocp+abc"), Some((1, 25, 0)));
+        assert_eq!(parse_semver("v2.1"), Some((2, 1, 0)));
+        assert_eq!(parse_semver("unknown"), None);
+        assert_eq!(parse_semver("v1"), None);
+    }
+
+    #[test]
+    fn test_version_at_least() {
+        assert_eq!(version_at_least("v1.34.1", "v1.25.0"), Some(true));
+        assert_eq!(version_at_least("v1.25.0", "v1.25.0"), Some(true));
+        assert_eq!(version_at_least("v1.9.0", "v1.25.0"), Some(false));
+        assert_eq!(version_at_least("devel", "v1.25.0"), None);
+    }
+
+    #[test]
+    fn test_git_version_takes_priority() {
+        let log_line = r#"{"msg":"kueue v0.1.0","gitVersion":"v0.15.0"}"#;
+        assert_eq!(
+            extract_version_from_log(log_line),
+            Some("v0.15.0".to_string())
+        );
+    }
+}
@@ -1,7 +1,7 @@
 //! Kueue-dev CLI - Development tool for kueue-operator
 
 use anyhow::Result;
-use clap::{CommandFactory, Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
 use clap_complete::{Shell, generate};
 use kueue_dev::config::settings::Settings;
 use kueue_dev::utils::{CommonPrereqs, ContainerRuntime, Prerequisite};
@@ -43,6 +43,10 @@ struct Cli {
     #[arg(short, long, global = true, action = clap::ArgAction::Count)]
     verbose: u8,
 
+    /// Only log warnings and errors. Machine-readable output still goes to stdout.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
     /// Path to kueue-operator source directory
     #[arg(
         short = 's',
@@ -52,6 +56,22 @@ struct Cli {
     )]
     operator_source: Option<String>,
 
+    /// On wait timeouts, print the object's status and recent events
+    #[arg(long, global = true, env = "KUEUE_DEV_EMIT_EVENTS_ON_TIMEOUT")]
+    emit_events_on_timeout: bool,
+
+    /// Kubeconfig context to use for kubectl, oc and helm calls
+    #[arg(long, global = true, env = "KUEUE_DEV_CONTEXT")]
+    context: Option<String>,
+
+    /// Binary for kubectl calls (default: kubectl if installed, otherwise oc)
+    #[arg(long, global = true, value_parser = ["kubectl", "oc"], env = "KUEUE_DEV_KUBE_CLI")]
+    kube_cli: Option<String>,
+
+    /// Disable colored output (also disabled by NO_COLOR or `colors.enabled = false`)
+    #[arg(long, global = true)]
+    no_color: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -81,6 +101,46 @@ enum Commands {
         /// Path to kubeconfig file
         #[arg(short, long, env = "KUBECONFIG")]
         kubeconfig: Option<String>,
+
+        /// What to remove: operator (uninstall it and delete its namespace),
+        /// tests (e2e test resources), or all (both). Defaults to tests, which
+        /// is what cleanup removed before --scope existed
+        #[arg(long, value_parser = ["all", "operator", "tests"], default_value = "tests")]
+        scope: String,
+
+        /// List the resources that would be deleted without changing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Additional test namespace prefix to clean up (repeatable)
+        #[arg(long = "namespace-prefix", value_name = "PREFIX")]
+        namespace_prefixes: Vec<String>,
+
+        /// Also delete Kueue workloads in this namespace, keeping the namespace (repeatable)
+        #[arg(short, long = "namespace", value_name = "NAMESPACE")]
+        namespaces: Vec<String>,
+
+        /// Skip the target cluster confirmation (for CI)
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Read or change configuration file settings
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Back up and restore NetworkPolicies
+    Netpol {
+        #[command(subcommand)]
+        command: NetpolCommands,
+    },
+
+    /// Wait for cluster resources to become ready
+    Wait {
+        #[command(subcommand)]
+        command: WaitCommands,
     },
 
     /// Manage container images
@@ -89,14 +149,62 @@ enum Commands {
         command: ImagesCommands,
     },
 
+    /// Manage the local image registry kind clusters pull from
+    Registry {
+        #[command(subcommand)]
+        command: RegistryCommands,
+    },
+
+    /// Install or list individual dependencies
+    Deps {
+        #[command(subcommand)]
+        command: DepsCommands,
+    },
+
     /// Check prerequisites
-    Check,
+    Check {
+        /// Only print the summary counts and overall verdict
+        #[arg(long)]
+        summary_only: bool,
+    },
 
     /// Interactive debugging menu
     Interactive {
         /// Path to kubeconfig file
         #[arg(short, long, env = "KUBECONFIG")]
         kubeconfig: Option<String>,
+
+        /// Kueue namespace for the menu actions (prompted for when not given)
+        #[arg(short, long)]
+        namespace: Option<String>,
+    },
+
+    /// Follow the logs of a kueue or Prometheus component
+    Logs {
+        /// Component: operator, controller-manager, prometheus or prometheus-operator
+        target: kueue_dev::commands::interactive::LogTarget,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+
+        /// Namespace of the kueue-operator or kueue install (default: operator.namespace,
+        /// or kueue-system when the operator isn't installed)
+        #[arg(short, long)]
+        namespace: Option<String>,
+    },
+
+    /// Forward a local port to a cluster service
+    PortForward {
+        #[command(subcommand)]
+        command: PortForwardCommands,
+    },
+
+    /// Show Prometheus and kueue deployment status
+    Info {
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
     },
 
     /// Generate shell completion scripts
@@ -104,10 +212,22 @@ enum Commands {
         /// Shell type
         #[arg(value_enum)]
         shell: Shell,
+
+        /// Write the script to the shell's per-user completions directory instead of stdout
+        #[arg(long)]
+        install: bool,
     },
 
     /// Show version information
-    Version,
+    Version {
+        /// Also query the deployed operator and kueue-controller-manager versions
+        #[arg(long)]
+        operator: bool,
+
+        /// Path to kubeconfig file (used with --operator)
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -125,6 +245,59 @@ enum ClusterCommands {
         /// Path to save kubeconfig file (if not specified, kubeconfig won't be saved)
         #[arg(short, long)]
         kubeconfig: Option<String>,
+
+        /// Merge the cluster's context into the default kubeconfig ($KUBECONFIG or
+        /// ~/.kube/config) instead of saving a separate kubeconfig file
+        #[arg(long, visible_alias = "export-kubeconfig", conflicts_with_all = ["kubeconfig", "print_export"])]
+        merge_kubeconfig: bool,
+
+        /// Print a command that sets KUBECONFIG for the new cluster, for use with eval
+        #[arg(long, visible_alias = "set-default-kubeconfig")]
+        print_export: bool,
+
+        /// Shell syntax for --print-export
+        #[arg(long, value_parser = ["bash", "zsh", "sh", "fish", "csh", "tcsh"], default_value = "bash")]
+        shell: String,
+
+        /// Let the nodes pull from this host:port registry over plain HTTP (repeatable)
+        #[arg(long = "insecure-registry", value_name = "HOST:PORT", value_parser = kueue_dev::k8s::kind::parse_registry)]
+        insecure_registries: Vec<String>,
+
+        /// Enable or disable a Kubernetes feature gate on every component, as
+        /// Name=true or Name=false (repeatable)
+        #[arg(long = "feature-gate", value_name = "NAME=BOOL", value_parser = kueue_dev::k8s::kind::parse_feature_gate)]
+        feature_gates: Vec<(String, bool)>,
+
+        #[command(flatten)]
+        node_labels: NodeLabelArgs,
+    },
+
+    /// Label a subset of the cluster's nodes
+    LabelNodes {
+        /// Label as KEY=VALUE (repeatable)
+        #[arg(long = "node-label", value_name = "KEY=VALUE", value_parser = kueue_dev::k8s::nodes::parse_node_label, required = true)]
+        node_labels: Vec<(String, String)>,
+
+        /// How labels are applied: uniform (every node gets every label) or
+        /// distribute (the values given for a key are assigned to the nodes in turn)
+        #[arg(long, value_parser = ["uniform", "distribute"])]
+        label_mode: Option<String>,
+
+        /// Only label nodes without the control-plane role
+        #[arg(long, conflicts_with_all = ["only_control_plane", "name_pattern"])]
+        only_worker: bool,
+
+        /// Only label control-plane nodes
+        #[arg(long, conflicts_with = "name_pattern")]
+        only_control_plane: bool,
+
+        /// Only label nodes whose name matches this regex
+        #[arg(long, value_name = "REGEX")]
+        name_pattern: Option<String>,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
     },
 
     /// Delete a kind cluster
@@ -138,8 +311,52 @@ enum ClusterCommands {
         force: bool,
     },
 
+    /// Delete the kind cluster if it exists and create it fresh
+    Recreate {
+        /// Cluster name
+        #[arg(short, long, default_value = "kueue-test")]
+        name: String,
+
+        /// CNI provider (default or calico)
+        #[arg(long)]
+        cni: Option<String>,
+
+        /// Path to save kubeconfig file (default: kubeconfig_path from config)
+        #[arg(short, long)]
+        kubeconfig: Option<String>,
+
+        /// Merge the cluster's context into the default kubeconfig ($KUBECONFIG or
+        /// ~/.kube/config) instead of saving a separate kubeconfig file
+        #[arg(
+            long,
+            visible_alias = "export-kubeconfig",
+            conflicts_with = "kubeconfig"
+        )]
+        merge_kubeconfig: bool,
+
+        /// Delete an existing cluster without confirmation
+        #[arg(short, long)]
+        force: bool,
+
+        /// Let the nodes pull from this host:port registry over plain HTTP (repeatable)
+        #[arg(long = "insecure-registry", value_name = "HOST:PORT", value_parser = kueue_dev::k8s::kind::parse_registry)]
+        insecure_registries: Vec<String>,
+
+        /// Enable or disable a Kubernetes feature gate on every component, as
+        /// Name=true or Name=false (repeatable)
+        #[arg(long = "feature-gate", value_name = "NAME=BOOL", value_parser = kueue_dev::k8s::kind::parse_feature_gate)]
+        feature_gates: Vec<(String, bool)>,
+
+        #[command(flatten)]
+        node_labels: NodeLabelArgs,
+    },
+
     /// List kind clusters
-    List,
+    List {
+        /// Output format: text or json
+        #[arg(short, long, default_value = "text")]
+        output: kueue_dev::commands::cluster::OutputFormat,
+    },
 }
 
 #[derive(Subcommand)]
@@ -158,6 +375,7 @@ enum DeployCommands {
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum DeployOperatorCommands {
     /// Deploy to kind cluster with prebuilt images
     Kind {
@@ -190,10 +408,75 @@ enum DeployOperatorCommands {
         #[arg(long)]
         kueue_namespace: Option<String>,
 
+        /// Extra label for the Kueue CR as KEY=VALUE (repeatable)
+        #[arg(long = "cr-label", value_name = "KEY=VALUE", value_parser = kueue_dev::config::kueue::parse_metadata_entry)]
+        cr_labels: Vec<(String, String)>,
+
+        /// Extra annotation for the Kueue CR as KEY=VALUE (repeatable)
+        #[arg(long = "cr-annotation", value_name = "KEY=VALUE", value_parser = kueue_dev::config::kueue::parse_metadata_entry)]
+        cr_annotations: Vec<(String, String)>,
+
         /// Deploy without OLM bundle (use direct manifest deployment)
         #[arg(long)]
         no_bundle: bool,
 
+        /// Deploy method: auto (bundle if operator-sdk is installed), bundle, or manifest
+        #[arg(long, value_parser = ["auto", "bundle", "manifest"], conflicts_with = "no_bundle")]
+        deploy_method: Option<String>,
+
+        /// Skip OLM installation (assume OLM is already present on the cluster)
+        #[arg(long, conflicts_with = "no_bundle")]
+        skip_olm_install: bool,
+
+        /// Skip installing cert-manager, JobSet, LeaderWorkerSet and Prometheus Operator
+        /// (reuse the ones already on the cluster)
+        #[arg(long, conflicts_with_all = ["cert_manager_version", "jobset_version", "leaderworkerset_version", "prometheus_version"])]
+        skip_deps: bool,
+
+        /// Skip installing cert-manager (reuse the one already on the cluster)
+        #[arg(long, conflicts_with_all = ["skip_deps", "cert_manager_version"])]
+        skip_cert_manager: bool,
+
+        /// Skip installing JobSet (reuse the one already on the cluster)
+        #[arg(long, conflicts_with_all = ["skip_deps", "jobset_version"])]
+        skip_jobset: bool,
+
+        /// Skip installing LeaderWorkerSet (reuse the one already on the cluster)
+        #[arg(long, conflicts_with_all = ["skip_deps", "leaderworkerset_version"])]
+        skip_leaderworkerset: bool,
+
+        /// Return right after applying manifests instead of waiting for readiness
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Write a JSON summary of what was deployed to this file
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<String>,
+
+        /// Number of operator replicas, e.g. to test leader election (manifest deployments only)
+        #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+        operator_replicas: Option<u32>,
+
+        /// Namespace to deploy the operator into (default: operator.namespace from config)
+        #[arg(long, value_name = "NAMESPACE")]
+        operator_namespace: Option<String>,
+
+        /// Deploy the operator into <operator namespace>-<SUFFIX> (manifest deployments only)
+        #[arg(long, value_name = "SUFFIX", conflicts_with = "namespace_per_run")]
+        namespace_suffix: Option<String>,
+
+        /// Deploy the operator into a namespace with a generated per-run suffix
+        #[arg(long)]
+        namespace_per_run: bool,
+
+        /// Operator imagePullPolicy (manifest deployments only; default: IfNotPresent)
+        #[arg(long, value_name = "POLICY", ignore_case = true)]
+        image_pull_policy: Option<kueue_dev::install::operator::ImagePullPolicy>,
+
+        /// Retry the whole deploy up to N times on transient (network/timeout) failures
+        #[arg(long, default_value_t = 0)]
+        deploy_retries: u32,
+
         /// Override cert-manager version (e.g., v1.18.0)
         #[arg(long)]
         cert_manager_version: Option<String>,
@@ -211,15 +494,41 @@ enum DeployOperatorCommands {
         prometheus_version: Option<String>,
     },
 
-    /// Deploy via OLM bundle
+    /// Deploy via OLM bundle or catalog image
     Olm {
-        /// Bundle image
-        #[arg(short, long)]
-        bundle: String,
+        /// Bundle image (installed with operator-sdk)
+        #[arg(short, long, required_unless_present = "catalog_image")]
+        bundle: Option<String>,
+
+        /// Catalog (index) image to install from with a CatalogSource and
+        /// Subscription, used when operator-sdk is missing or disabled
+        #[arg(long)]
+        catalog_image: Option<String>,
+
+        /// Install from --catalog-image even if operator-sdk is available
+        #[arg(long, requires = "catalog_image")]
+        no_operator_sdk: bool,
+
+        /// Package to subscribe to when installing from a catalog image
+        #[arg(long, default_value = "kueue-operator")]
+        package: String,
+
+        /// Subscription channel when installing from a catalog image
+        /// (default: the package's default channel)
+        #[arg(long)]
+        channel: Option<String>,
+
+        /// Namespace to install the operator into (default: operator.namespace from config)
+        #[arg(long, value_name = "NAMESPACE")]
+        operator_namespace: Option<String>,
 
         /// Cluster name
         #[arg(short = 'n', long, default_value = "kueue-test")]
         name: String,
+
+        /// Path to kubeconfig file (default: ~/.kube/config-<name>)
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
     },
 
     /// Deploy to OpenShift cluster
@@ -231,6 +540,34 @@ enum DeployOperatorCommands {
         /// Skip tests after deployment
         #[arg(long)]
         skip_tests: bool,
+
+        /// Return right after applying manifests instead of waiting for readiness
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Operator imagePullPolicy (default: the manifest's policy)
+        #[arg(long, value_name = "POLICY", ignore_case = true)]
+        image_pull_policy: Option<kueue_dev::install::operator::ImagePullPolicy>,
+
+        /// Namespace to deploy the operator into (default: operator.namespace from config)
+        #[arg(long, value_name = "NAMESPACE")]
+        operator_namespace: Option<String>,
+
+        /// Write a JSON summary of what was deployed to this file
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<String>,
+
+        /// Override cert-manager version (e.g., v1.18.0)
+        #[arg(long)]
+        cert_manager_version: Option<String>,
+
+        /// Override JobSet version (e.g., v0.10.1)
+        #[arg(long)]
+        jobset_version: Option<String>,
+
+        /// Override LeaderWorkerSet version (e.g., v0.7.0)
+        #[arg(long)]
+        leaderworkerset_version: Option<String>,
     },
 }
 
@@ -242,6 +579,10 @@ enum DeployUpstreamCommands {
         #[arg(long = "upstream-source", env = "KUEUE_UPSTREAM_SOURCE")]
         source: Option<String>,
 
+        /// Fail if the upstream source is the kueue-operator source root
+        #[arg(long)]
+        strict: bool,
+
         /// Kustomize overlay to use (default, dev, alpha-enabled)
         #[arg(short, long, default_value = "default")]
         overlay: String,
@@ -258,6 +599,14 @@ enum DeployUpstreamCommands {
         #[arg(long)]
         image_tag: Option<String>,
 
+        /// Make target that builds the image (default: kind-image-build)
+        #[arg(long, requires = "build_image")]
+        make_target: Option<String>,
+
+        /// Build this Dockerfile with docker/podman instead of running make (relative to the upstream source)
+        #[arg(long, requires = "build_image", conflicts_with = "make_target")]
+        dockerfile: Option<String>,
+
         /// Namespace to deploy to
         #[arg(short, long, default_value = "kueue-system")]
         namespace: String,
@@ -274,6 +623,19 @@ enum DeployUpstreamCommands {
         #[arg(long)]
         skip_deps: bool,
 
+        /// Fail instead of warning when an overridden dependency version is untested with this kueue release,
+        /// or when the kueue release has no compatibility data to check against
+        #[arg(long, conflicts_with = "skip_deps")]
+        strict_versions: bool,
+
+        /// Return right after applying manifests instead of waiting for readiness
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Write a JSON summary of what was deployed to this file
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<String>,
+
         /// Override cert-manager version (e.g., v1.18.0)
         #[arg(long)]
         cert_manager_version: Option<String>,
@@ -301,6 +663,10 @@ enum DeployUpstreamCommands {
         #[arg(long = "upstream-source", env = "KUEUE_UPSTREAM_SOURCE")]
         source: Option<String>,
 
+        /// Fail if the upstream source is the kueue-operator source root
+        #[arg(long)]
+        strict: bool,
+
         /// Helm release name
         #[arg(short, long, default_value = "kueue")]
         release_name: String,
@@ -317,6 +683,14 @@ enum DeployUpstreamCommands {
         #[arg(long = "set", value_name = "KEY=VALUE")]
         set_values: Vec<String>,
 
+        /// Set helm values that stay strings, e.g. numeric-looking image tags (can be repeated)
+        #[arg(long = "set-string", value_name = "KEY=VALUE")]
+        set_string_values: Vec<String>,
+
+        /// Set helm values from file contents, e.g. certificates (can be repeated)
+        #[arg(long = "set-file", value_name = "KEY=PATH")]
+        set_file_values: Vec<String>,
+
         /// Build kueue image from source and load to kind cluster
         #[arg(long)]
         build_image: bool,
@@ -325,6 +699,14 @@ enum DeployUpstreamCommands {
         #[arg(long)]
         image_tag: Option<String>,
 
+        /// Make target that builds the image (default: kind-image-build)
+        #[arg(long, requires = "build_image")]
+        make_target: Option<String>,
+
+        /// Build this Dockerfile with docker/podman instead of running make (relative to the upstream source)
+        #[arg(long, requires = "build_image", conflicts_with = "make_target")]
+        dockerfile: Option<String>,
+
         /// Cluster name (for kind clusters)
         #[arg(short = 'c', long, default_value = "kueue-test")]
         cluster_name: String,
@@ -337,6 +719,19 @@ enum DeployUpstreamCommands {
         #[arg(long)]
         skip_deps: bool,
 
+        /// Fail instead of warning when an overridden dependency version is untested with this kueue release,
+        /// or when the kueue release has no compatibility data to check against
+        #[arg(long, conflicts_with = "skip_deps")]
+        strict_versions: bool,
+
+        /// Return right after applying manifests instead of waiting for readiness
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Write a JSON summary of what was deployed to this file
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<String>,
+
         /// Override cert-manager version (e.g., v1.18.0)
         #[arg(long)]
         cert_manager_version: Option<String>,
@@ -361,6 +756,24 @@ enum DeployUpstreamCommands {
 
 #[derive(Subcommand)]
 enum TestCommands {
+    /// List the specs that would run (ginkgo --dry-run) without executing them
+    List {
+        /// Test focus pattern
+        #[arg(short, long)]
+        focus: Option<String>,
+
+        /// Label filter for tests (e.g., "!disruptive", "network-policy")
+        #[arg(short = 'l', long)]
+        label_filter: Option<String>,
+
+        #[command(flatten)]
+        ginkgo: GinkgoArgs,
+
+        /// Ginkgo package path to run (default: ./test/e2e/...)
+        #[arg(long, value_name = "PATH")]
+        test_path: Option<String>,
+    },
+
     /// Run tests on existing cluster
     Run {
         /// Test focus pattern
@@ -374,6 +787,25 @@ enum TestCommands {
         /// Path to kubeconfig
         #[arg(short, long, env = "KUBECONFIG")]
         kubeconfig: Option<String>,
+
+        #[command(flatten)]
+        ginkgo: GinkgoArgs,
+
+        /// Ginkgo package path to run (default: ./test/e2e/...)
+        #[arg(long, value_name = "PATH")]
+        test_path: Option<String>,
+
+        /// Directory to write JUnit (junit.xml) and JSON (e2e.json) reports into
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<String>,
+
+        /// Run the tests this many times without prompting between failures
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u32).range(1..))]
+        repeat: u32,
+
+        /// Write per-spec pass/fail counts and flaky specs across all iterations to this file
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<String>,
     },
 
     /// Deploy operator and run tests
@@ -386,6 +818,15 @@ enum TestCommands {
         #[arg(short, long, default_value = "kueue-test")]
         name: String,
 
+        /// Deploy onto the kind cluster if it already exists instead of recreating it
+        /// (kind only)
+        #[arg(long)]
+        reuse: bool,
+
+        /// Override Calico version (e.g., v3.28.2) (kind only)
+        #[arg(long)]
+        calico_version: Option<String>,
+
         /// Test focus pattern
         #[arg(short, long)]
         focus: Option<String>,
@@ -414,6 +855,28 @@ enum TestCommands {
         /// Kueue CR namespace (default: openshift-kueue-operator)
         #[arg(long)]
         kueue_namespace: Option<String>,
+
+        /// Extra label for the Kueue CR as KEY=VALUE (repeatable)
+        #[arg(long = "cr-label", value_name = "KEY=VALUE", value_parser = kueue_dev::config::kueue::parse_metadata_entry)]
+        cr_labels: Vec<(String, String)>,
+
+        /// Extra annotation for the Kueue CR as KEY=VALUE (repeatable)
+        #[arg(long = "cr-annotation", value_name = "KEY=VALUE", value_parser = kueue_dev::config::kueue::parse_metadata_entry)]
+        cr_annotations: Vec<(String, String)>,
+
+        #[command(flatten)]
+        ginkgo: GinkgoArgs,
+
+        #[command(flatten)]
+        node_labels: NodeLabelArgs,
+
+        /// Directory to write JUnit (junit.xml) and JSON (e2e.json) reports into
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<String>,
+
+        /// Write one JUnit report covering the deploy phases (kind only) and the specs
+        #[arg(long, value_name = "PATH")]
+        junit_out: Option<String>,
     },
 
     /// Run upstream kueue tests (requires OpenShift cluster)
@@ -433,6 +896,204 @@ enum TestCommands {
         /// E2E target folder (default: singlecluster)
         #[arg(long, default_value = "singlecluster")]
         target: String,
+
+        /// How kueue was installed: operator or upstream (default: detected from the cluster)
+        #[arg(long)]
+        flavor: Option<kueue_dev::commands::test::TestFlavor>,
+
+        /// On kind, leave the operator running instead of scaling it to 0
+        #[arg(long)]
+        no_scale_down_operator: bool,
+
+        /// On kind, leave the operator scaled down after the tests
+        #[arg(long, conflicts_with = "no_scale_down_operator")]
+        no_restore_operator: bool,
+
+        /// On kind, keep NetworkPolicies instead of deleting them
+        #[arg(long)]
+        keep_network_policies: bool,
+
+        #[command(flatten)]
+        ginkgo: GinkgoArgs,
+
+        #[command(flatten)]
+        node_labels: NodeLabelArgs,
+
+        /// Directory to write JUnit (junit.xml) and JSON (e2e.json) reports into
+        #[arg(long, value_name = "DIR")]
+        report_dir: Option<String>,
+    },
+}
+
+/// Ginkgo options shared by the test subcommands
+#[derive(Args)]
+struct GinkgoArgs {
+    /// Use a shared prebuilt ginkgo binary instead of installing one per checkout
+    #[arg(long, visible_alias = "reuse-ginkgo-from", env = "GINKGO_BIN")]
+    ginkgo_from: Option<String>,
+
+    /// Additional test pattern to skip (repeatable)
+    #[arg(long = "skip-add", value_name = "PATTERN")]
+    skip_add: Vec<String>,
+
+    /// Configured skip pattern to run anyway (repeatable)
+    #[arg(long = "skip-remove", value_name = "PATTERN")]
+    skip_remove: Vec<String>,
+}
+
+/// Extra worker node labels for the commands that label nodes
+#[derive(Args)]
+struct NodeLabelArgs {
+    /// Extra worker node label as KEY=VALUE (repeatable), added to tests.node_labels
+    #[arg(long = "node-label", value_name = "KEY=VALUE", value_parser = kueue_dev::k8s::nodes::parse_node_label)]
+    node_labels: Vec<(String, String)>,
+
+    /// How node labels are applied: uniform (every worker gets every label) or
+    /// distribute (the values given for a key are assigned to the workers in turn)
+    #[arg(long, value_parser = ["uniform", "distribute"])]
+    label_mode: Option<String>,
+}
+
+impl NodeLabelArgs {
+    fn into_worker_labels(self) -> Result<kueue_dev::k8s::nodes::WorkerLabels> {
+        use kueue_dev::k8s::nodes::{LabelMode, WorkerLabels};
+        use std::str::FromStr;
+
+        let mode = self
+            .label_mode
+            .as_deref()
+            .map(LabelMode::from_str)
+            .transpose()?;
+        WorkerLabels::new(&Settings::load()?.tests, self.node_labels, mode)
+    }
+}
+
+impl GinkgoArgs {
+    fn into_options(
+        self,
+        test_path: Option<String>,
+        report_dir: Option<String>,
+    ) -> kueue_dev::commands::test::GinkgoOptions {
+        use kueue_dev::commands::test::{GinkgoOptions, SkipOverrides};
+        use std::path::PathBuf;
+
+        GinkgoOptions {
+            ginkgo_from: self.ginkgo_from.map(PathBuf::from),
+            skip_overrides: SkipOverrides {
+                add: self.skip_add,
+                remove: self.skip_remove,
+            },
+            test_path,
+            report_dir: report_dir.map(PathBuf::from),
+            junit_out: None,
+            worker_labels: Default::default(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum RegistryCommands {
+    /// Run a registry:2 container and connect it to the kind network
+    Start {
+        /// Host port to publish the registry on
+        #[arg(long, default_value_t = kueue_dev::k8s::registry::DEFAULT_PORT)]
+        port: u16,
+    },
+
+    /// Remove the registry container
+    Stop,
+}
+
+#[derive(Subcommand)]
+enum DepsCommands {
+    /// Install a single dependency
+    Install {
+        /// Dependency name (cert-manager, jobset, leaderworkerset, appwrapper, training-operator, prometheus, calico)
+        dependency: String,
+
+        /// Override the configured version (e.g., v1.18.0)
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Cluster name (used to locate the kubeconfig)
+        #[arg(short, long, default_value = "kueue-test")]
+        name: String,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
+
+    /// List dependencies and their configured versions
+    List,
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Print the effective value of a setting (e.g. versions.cert_manager)
+    Get {
+        /// Dotted setting path
+        key: String,
+    },
+
+    /// Set a setting in the config file, creating the file if needed
+    Set {
+        /// Dotted setting path (e.g. versions.cert_manager)
+        key: String,
+
+        /// New value; parsed as TOML unless the setting is a string
+        value: String,
+
+        /// Config file to edit (default: the active config file, or ~/.config/kueue-dev/config.toml)
+        #[arg(long)]
+        file: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum NetpolCommands {
+    /// Save all NetworkPolicies to YAML files in a directory
+    Backup {
+        /// Directory to write the NetworkPolicy manifests to
+        dir: String,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
+
+    /// Re-apply NetworkPolicies saved by `netpol backup`
+    Restore {
+        /// Directory containing the saved NetworkPolicy manifests
+        dir: String,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum PortForwardCommands {
+    /// Port-forward the Prometheus UI to http://localhost:9090
+    Prometheus {
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum WaitCommands {
+    /// Wait for all Kueue CRDs to be Established
+    Crds {
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+
+        /// Seconds to wait for all CRDs
+        #[arg(long, default_value_t = kueue_dev::install::upstream::DEFAULT_CRD_TIMEOUT_SECS)]
+        timeout: u64,
     },
 }
 
@@ -451,6 +1112,83 @@ enum ImagesCommands {
         /// Build components in parallel
         #[arg(short, long)]
         parallel: bool,
+
+        /// Also retag each built image under this registry and push it
+        #[arg(long, value_name = "REGISTRY")]
+        push_to: Option<String>,
+
+        /// Also push a copy of each image to the local registry (see `registry start`)
+        #[arg(long, conflicts_with = "push_to")]
+        local_registry: bool,
+
+        /// Target platforms for a multi-arch build (e.g., linux/amd64,linux/arm64)
+        #[arg(long, value_delimiter = ',', value_name = "PLATFORMS")]
+        platform: Vec<String>,
+
+        /// Append this suffix to every image tag from the images file (e.g., :dev -> :dev-<SUFFIX>)
+        #[arg(long, value_name = "SUFFIX", conflicts_with = "tag_sha")]
+        tag_suffix: Option<String>,
+
+        /// Use the operator source's short git SHA as the tag suffix
+        #[arg(long)]
+        tag_sha: bool,
+
+        /// Only build the images locally; do not push them
+        #[arg(long, conflicts_with_all = ["push_to", "local_registry", "platform"])]
+        no_push: bool,
+    },
+
+    /// Build an OLM catalog (index) image containing the bundle image, using opm
+    Catalog {
+        /// Path to images configuration file (defaults to config file setting)
+        #[arg(short, long = "related-images")]
+        images: Option<String>,
+
+        /// Catalog image to build (defaults to the images file's "catalog" entry)
+        #[arg(short, long, value_name = "IMAGE")]
+        tag: Option<String>,
+
+        /// Existing index image to add the bundle to
+        #[arg(long, value_name = "IMAGE")]
+        from_index: Option<String>,
+
+        /// Only build the catalog image locally; do not push it
+        #[arg(long)]
+        no_push: bool,
+    },
+
+    /// Build the operand image, load it into a kind cluster and restart kueue-controller-manager
+    RebuildOperand {
+        /// Kind cluster to load the image into
+        #[arg(short, long, default_value = "kueue-test")]
+        cluster: String,
+
+        /// Path to images configuration file (defaults to config file setting)
+        #[arg(short, long = "related-images")]
+        images: Option<String>,
+
+        /// Namespace of the kueue-controller-manager deployment (defaults to kueue.namespace)
+        #[arg(long)]
+        namespace: Option<String>,
+
+        /// Path to kubeconfig file
+        #[arg(short, long, env = "KUBECONFIG")]
+        kubeconfig: Option<String>,
+    },
+
+    /// Remove locally-built kueue-dev images not in use by any kind cluster
+    Prune {
+        /// Path to images configuration file whose repositories are pruned (defaults to config file setting)
+        #[arg(short, long = "related-images")]
+        images: Option<String>,
+
+        /// Only remove images older than this age (e.g. 12h, 7d, 2w)
+        #[arg(long, value_parser = kueue_dev::commands::prune::parse_age)]
+        older_than: Option<std::time::Duration>,
+
+        /// List the images that would be removed without removing them
+        #[arg(long)]
+        dry_run: bool,
     },
 
     /// List images from config
@@ -460,6 +1198,16 @@ enum ImagesCommands {
         file: String,
     },
 
+    /// Show added, removed and changed images between two images files.
+    /// Exits non-zero when they differ; with --quiet only the exit status is set.
+    Diff {
+        /// Original images file
+        old: String,
+
+        /// Updated images file
+        new: String,
+    },
+
     /// Load images to kind cluster
     Load {
         /// Cluster name
@@ -469,6 +1217,19 @@ enum ImagesCommands {
         /// Path to related images JSON file
         #[arg(long = "related-images")]
         images: Option<String>,
+
+        /// Load the .tar image archive at this path, or every .tar archive in this directory,
+        /// instead of the images from the images file
+        #[arg(long, value_name = "PATH", conflicts_with = "images")]
+        from_archive: Option<String>,
+
+        /// Maximum number of images to load concurrently
+        #[arg(
+            long,
+            default_value_t = kueue_dev::k8s::images::DEFAULT_MAX_PARALLEL_LOADS as u32,
+            value_parser = clap::value_parser!(u32).range(1..)
+        )]
+        max_parallel: u32,
     },
 }
 
@@ -477,25 +1238,43 @@ fn main() -> Result<()> {
 
     // Set up logging based on verbosity level
     let log_level = match cli.verbose {
-        0 => "info",  // Default: info level
-        1 => "debug", // -v: debug level
-        2 => "trace", // -vv: trace level
-        _ => "trace", // -vvv: trace level
+        _ if cli.quiet => "warn", // -q: warnings and errors only
+        0 => "info",              // Default: info level
+        1 => "debug",             // -v: debug level
+        2 => "trace",             // -vv: trace level
+        _ => "trace",             // -vvv: trace level
     };
 
     // Initialize tracing subscriber with custom formatting
     // Format matches the old style: [LEVEL] message
     // Use EnvFilter::try_new to set the log level without modifying environment variables
-    let env_filter = EnvFilter::try_from_default_env()
-        .or_else(|_| EnvFilter::try_new(log_level))
-        .unwrap_or_else(|_| EnvFilter::new("info"));
+    // --quiet wins over RUST_LOG so scripts get only warnings and errors
+    let env_filter = if cli.quiet {
+        EnvFilter::new(log_level)
+    } else {
+        EnvFilter::try_from_default_env()
+            .or_else(|_| EnvFilter::try_new(log_level))
+            .unwrap_or_else(|_| EnvFilter::new("info"))
+    };
+    kueue_dev::utils::logger::set_quiet(cli.quiet);
+
+    // Fail fast on malformed configuration instead of silently using defaults.
+    let settings = Settings::load()?;
+    let colors = kueue_dev::utils::colors_enabled(
+        cli.no_color,
+        std::env::var_os("NO_COLOR").as_deref(),
+        settings.colors.enabled,
+    );
+    if !colors {
+        kueue_dev::utils::disable_colors();
+    }
 
     tracing_subscriber::registry()
         .with(
             fmt::layer()
                 .with_target(false)
                 .with_level(true)
-                .with_ansi(true)
+                .with_ansi(colors)
                 .without_time()
                 .with_writer(std::io::stderr),
         )
@@ -504,21 +1283,69 @@ fn main() -> Result<()> {
 
     // Set the operator source path from CLI if provided
     kueue_dev::utils::set_cli_operator_source(cli.operator_source);
+    kueue_dev::k8s::kubectl::set_emit_events_on_timeout(cli.emit_events_on_timeout);
+    kueue_dev::k8s::target::set_context(cli.context);
+    kueue_dev::k8s::target::set_kube_cli(
+        cli.kube_cli
+            .as_deref()
+            .map(str::parse::<kueue_dev::k8s::target::KubeCli>)
+            .transpose()?,
+    );
 
-    // Fail fast on malformed configuration instead of silently using defaults.
-    let _ = Settings::load()?;
-
-    match cli.command {
+    let result = match cli.command {
         Commands::Cluster { command } => handle_cluster_command(command),
         Commands::Deploy { command } => handle_deploy_command(command),
         Commands::Test { command } => handle_test_command(command),
-        Commands::Cleanup { kubeconfig } => handle_cleanup_command(kubeconfig),
+        Commands::Cleanup {
+            kubeconfig,
+            scope,
+            dry_run,
+            namespace_prefixes,
+            namespaces,
+            yes,
+        } => handle_cleanup_command(
+            kubeconfig,
+            scope,
+            dry_run,
+            namespace_prefixes,
+            namespaces,
+            yes,
+        ),
+        Commands::Config { command } => handle_config_command(command),
+        Commands::Netpol { command } => handle_netpol_command(command),
+        Commands::Wait { command } => handle_wait_command(command),
         Commands::Images { command } => handle_images_command(command),
-        Commands::Check => handle_check_command(),
-        Commands::Interactive { kubeconfig } => handle_interactive_command(kubeconfig),
-        Commands::Completion { shell } => handle_completion_command(shell),
-        Commands::Version => handle_version_command(),
+        Commands::Registry { command } => handle_registry_command(command),
+        Commands::Deps { command } => handle_deps_command(command),
+        Commands::Check { summary_only } => handle_check_command(summary_only),
+        Commands::Interactive {
+            kubeconfig,
+            namespace,
+        } => handle_interactive_command(kubeconfig, namespace),
+        Commands::Logs {
+            target,
+            kubeconfig,
+            namespace,
+        } => handle_logs_command(target, kubeconfig, namespace),
+        Commands::PortForward { command } => handle_port_forward_command(command),
+        Commands::Info { kubeconfig } => handle_info_command(kubeconfig),
+        Commands::Completion { shell, install } => handle_completion_command(shell, install),
+        Commands::Version {
+            operator,
+            kubeconfig,
+        } => handle_version_command(operator, kubeconfig),
+    };
+
+    // Show the suggestions attached to a KueueDevError anywhere in the chain
+    if let Err(e) = &result
+        && let Some(err) = e.downcast_ref::<kueue_dev::utils::KueueDevError>()
+    {
+        eprintln!("Error: {:?}", e);
+        err.print_suggestions();
+        std::process::exit(1);
     }
+
+    result
 }
 
 fn handle_cluster_command(command: ClusterCommands) -> Result<()> {
@@ -527,15 +1354,85 @@ fn handle_cluster_command(command: ClusterCommands) -> Result<()> {
             name,
             cni,
             kubeconfig,
+            merge_kubeconfig,
+            print_export,
+            shell,
+            insecure_registries,
+            feature_gates,
+            node_labels,
         } => {
             let settings = Settings::load()?;
-            let cni = cni.unwrap_or(settings.defaults.cni_provider);
-            kueue_dev::commands::cluster::create(name, cni, kubeconfig)
+            kueue_dev::commands::cluster::create(kueue_dev::commands::cluster::CreateOptions {
+                name,
+                cni: cni.unwrap_or(settings.defaults.cni_provider),
+                kubeconfig,
+                merge_kubeconfig,
+                export_shell: print_export.then_some(shell),
+                insecure_registries,
+                feature_gates,
+                worker_labels: node_labels.into_worker_labels()?,
+            })
+        }
+        ClusterCommands::LabelNodes {
+            node_labels,
+            label_mode,
+            only_worker,
+            only_control_plane,
+            name_pattern,
+            kubeconfig,
+        } => {
+            use kueue_dev::k8s::nodes::{LabelMode, NodeSelector, WorkerLabels, label_nodes};
+            use std::str::FromStr;
+
+            let selector = if only_worker {
+                NodeSelector::Workers
+            } else if only_control_plane {
+                NodeSelector::ControlPlane
+            } else if let Some(pattern) = &name_pattern {
+                NodeSelector::name(pattern)?
+            } else {
+                NodeSelector::All
+            };
+            let mode = label_mode
+                .as_deref()
+                .map(LabelMode::from_str)
+                .transpose()?
+                .unwrap_or_default();
+            label_nodes(
+                &selector,
+                &WorkerLabels::from_labels(node_labels, mode)?,
+                kubeconfig.as_deref().map(std::path::Path::new),
+            )
         }
         ClusterCommands::Delete { name, force } => {
             kueue_dev::commands::cluster::delete(name, force)
         }
-        ClusterCommands::List => kueue_dev::commands::cluster::list(),
+        ClusterCommands::Recreate {
+            name,
+            cni,
+            kubeconfig,
+            merge_kubeconfig,
+            force,
+            insecure_registries,
+            feature_gates,
+            node_labels,
+        } => {
+            let settings = Settings::load()?;
+            kueue_dev::commands::cluster::recreate(
+                kueue_dev::commands::cluster::CreateOptions {
+                    name,
+                    cni: cni.unwrap_or(settings.defaults.cni_provider),
+                    kubeconfig,
+                    merge_kubeconfig,
+                    insecure_registries,
+                    feature_gates,
+                    worker_labels: node_labels.into_worker_labels()?,
+                    ..Default::default()
+                },
+                force,
+            )
+        }
+        ClusterCommands::List { output } => kueue_dev::commands::cluster::list(output),
     }
 }
 
@@ -556,20 +1453,43 @@ fn handle_deploy_operator_command(command: DeployOperatorCommands) -> Result<()>
             skip_kueue_cr,
             kueue_frameworks,
             kueue_namespace,
+            cr_labels,
+            cr_annotations,
             no_bundle,
+            deploy_method,
+            skip_olm_install,
+            skip_deps,
+            skip_cert_manager,
+            skip_jobset,
+            skip_leaderworkerset,
+            no_wait,
+            summary_json,
+            operator_replicas,
+            operator_namespace,
+            namespace_suffix,
+            namespace_per_run,
+            image_pull_policy,
+            deploy_retries,
             cert_manager_version,
             jobset_version,
             leaderworkerset_version,
             prometheus_version,
         } => {
-            use kueue_dev::commands::deploy::DeployKindOptions;
+            use kueue_dev::commands::deploy::{DeployKindOptions, DeployMethod};
             use kueue_dev::config::settings::Settings;
+            use std::str::FromStr;
 
             // Use provided images file or fall back to config file setting
             let settings = Settings::load()?;
             let images_file = images.unwrap_or(settings.defaults.images_file);
 
-            kueue_dev::commands::deploy::deploy_kind(DeployKindOptions {
+            let deploy_method = if no_bundle {
+                DeployMethod::Manifest
+            } else {
+                DeployMethod::from_str(&deploy_method.unwrap_or(settings.defaults.deploy_method))?
+            };
+
+            let options = DeployKindOptions {
                 cluster_name: name,
                 images_file,
                 kubeconfig,
@@ -577,37 +1497,115 @@ fn handle_deploy_operator_command(command: DeployOperatorCommands) -> Result<()>
                 skip_kueue_cr,
                 kueue_frameworks,
                 kueue_namespace,
-                use_bundle: !no_bundle,
+                kueue_cr_labels: cr_labels,
+                kueue_cr_annotations: cr_annotations,
+                operator_replicas,
+                operator_namespace,
+                operator_namespace_suffix: if namespace_per_run {
+                    Some(kueue_dev::install::operator::generate_namespace_suffix())
+                } else {
+                    namespace_suffix
+                },
+                image_pull_policy,
+                deploy_method,
+                skip_olm_install,
+                skip_deps,
+                skip_cert_manager,
+                skip_jobset,
+                skip_leaderworkerset,
+                wait: !no_wait,
+                summary_json: output_path(summary_json)?,
                 cert_manager_version,
                 jobset_version,
                 leaderworkerset_version,
                 prometheus_version,
-            })
+            };
+
+            kueue_dev::commands::deploy::deploy_kind_with_retries(options, deploy_retries)
         }
-        DeployOperatorCommands::Olm { bundle, name } => {
+        DeployOperatorCommands::Olm {
+            bundle,
+            catalog_image,
+            no_operator_sdk,
+            package,
+            channel,
+            operator_namespace,
+            name,
+            kubeconfig,
+        } => {
             use kueue_dev::install::olm;
             use std::env;
             use std::path::PathBuf;
 
-            log_info!("Deploying via OLM to cluster: {}", name);
-            log_info!("Bundle image: {}", bundle);
-
-            // Get kubeconfig for kind cluster
-            let home_dir = env::var("HOME").expect("HOME environment variable not set");
-            let kubeconfig = PathBuf::from(format!("{}/.kube/config-{}", home_dir, name));
+            let operator_sdk_available = which::which("operator-sdk").is_ok();
+            let bundle = match bundle {
+                Some(bundle) if operator_sdk_available && !no_operator_sdk => Some(bundle),
+                Some(_) if catalog_image.is_some() => {
+                    if !no_operator_sdk {
+                        log_info!("operator-sdk not found in PATH; installing from catalog image");
+                    }
+                    None
+                }
+                None => None,
+                Some(_) => {
+                    log_error!(
+                        "operator-sdk is required for bundle deployment but not found in PATH."
+                    );
+                    log_error!(
+                        "Install operator-sdk, or install from a catalog image with --catalog-image <IMAGE>"
+                    );
+                    std::process::exit(1);
+                }
+            };
 
-            if !kubeconfig.exists() {
-                log_error!("Kubeconfig not found: {}", kubeconfig.display());
-                log_error!("Cluster {} may not exist. Create it first with:", name);
-                log_error!("  kueue-dev cluster create --name {}", name);
-                std::process::exit(1);
+            log_info!("Deploying via OLM to cluster: {}", name);
+            match (&bundle, &catalog_image) {
+                (Some(bundle), _) => log_info!("Bundle image: {}", bundle),
+                (None, Some(catalog_image)) => log_info!("Catalog image: {}", catalog_image),
+                (None, None) => unreachable!("bundle or catalog image is required"),
             }
 
+            let kubeconfig = if let Some(kc) = kubeconfig {
+                kueue_dev::utils::resolve_kubeconfig_value(std::ffi::OsStr::new(&kc))?
+            } else {
+                // Fall back to the kubeconfig saved for the kind cluster
+                let home_dir = env::var("HOME").expect("HOME environment variable not set");
+                let kubeconfig = PathBuf::from(format!("{}/.kube/config-{}", home_dir, name));
+
+                if !kubeconfig.exists() {
+                    log_error!("Kubeconfig not found: {}", kubeconfig.display());
+                    log_error!("Cluster {} may not exist. Create it first with:", name);
+                    log_error!("  kueue-dev cluster create --name {}", name);
+                    log_error!("Or pass --kubeconfig for an existing cluster");
+                    std::process::exit(1);
+                }
+                kubeconfig
+            };
+            log_info!("Using kubeconfig: {}", kubeconfig.display());
+
             // Install OLM
-            olm::install_olm(Some(&kubeconfig))?;
+            let settings = Settings::load()?;
+            olm::install_olm(settings.behavior.olm_ready_timeout, Some(&kubeconfig))?;
+
+            let operator_namespace = operator_namespace.unwrap_or(settings.operator.namespace);
+            log_info!("Operator namespace: {}", operator_namespace);
 
-            // Install operator bundle
-            olm::install_bundle(&bundle, &name, Some(&kubeconfig))?;
+            // Install operator bundle, or subscribe to it from the catalog
+            match (bundle, catalog_image) {
+                (Some(bundle), _) => {
+                    olm::install_bundle(&bundle, &operator_namespace, &name, Some(&kubeconfig))?
+                }
+                (None, Some(catalog_image)) => {
+                    let options = olm::CatalogInstallOptions {
+                        catalog_image,
+                        package,
+                        channel,
+                        namespace: operator_namespace.clone(),
+                    };
+                    olm::install_catalog(&options, Some(&kubeconfig))?
+                }
+                (None, None) => unreachable!("bundle or catalog image is required"),
+            }
 
             log_info!("");
             log_info!("==========================================");
@@ -616,21 +1614,43 @@ fn handle_deploy_operator_command(command: DeployOperatorCommands) -> Result<()>
             log_info!("");
             log_info!("To view operator logs:");
             log_info!(
-                "  kubectl logs -n openshift-kueue-operator -l name=openshift-kueue-operator -f --kubeconfig={}",
+                "  kubectl logs -n {} -l name=openshift-kueue-operator -f --kubeconfig={}",
+                operator_namespace,
                 kubeconfig.display()
             );
             log_info!("");
 
             Ok(())
         }
-        DeployOperatorCommands::Openshift { images, skip_tests } => {
+        DeployOperatorCommands::Openshift {
+            images,
+            skip_tests,
+            no_wait,
+            image_pull_policy,
+            operator_namespace,
+            summary_json,
+            cert_manager_version,
+            jobset_version,
+            leaderworkerset_version,
+        } => {
+            use kueue_dev::commands::openshift::DeployOpenshiftOptions;
             use kueue_dev::config::settings::Settings;
 
             // Use provided images file or fall back to config file setting
             let settings = Settings::load()?;
             let images_file = images.unwrap_or(settings.defaults.images_file);
 
-            kueue_dev::commands::openshift::deploy_openshift(images_file, skip_tests)
+            kueue_dev::commands::openshift::deploy_openshift(DeployOpenshiftOptions {
+                images_file,
+                skip_tests,
+                wait: !no_wait,
+                image_pull_policy,
+                operator_namespace,
+                summary_json: output_path(summary_json)?,
+                cert_manager_version,
+                jobset_version,
+                leaderworkerset_version,
+            })
         }
     }
 }
@@ -639,14 +1659,20 @@ fn handle_deploy_upstream_command(command: DeployUpstreamCommands) -> Result<()>
     match command {
         DeployUpstreamCommands::Kustomize {
             source,
+            strict,
             overlay,
             image,
             build_image,
             image_tag,
+            make_target,
+            dockerfile,
             namespace,
             cluster_name,
             kubeconfig,
             skip_deps,
+            strict_versions,
+            no_wait,
+            summary_json,
             cert_manager_version,
             jobset_version,
             leaderworkerset_version,
@@ -657,14 +1683,22 @@ fn handle_deploy_upstream_command(command: DeployUpstreamCommands) -> Result<()>
 
             kueue_dev::commands::deploy::deploy_upstream_kustomize(DeployUpstreamKustomizeOptions {
                 source,
+                strict,
                 overlay,
                 image,
                 build_image,
                 image_tag,
+                build_method: kueue_dev::install::upstream::ImageBuildMethod::from_options(
+                    make_target,
+                    dockerfile,
+                ),
                 namespace,
                 kubeconfig,
                 cluster_name,
                 skip_deps,
+                strict_versions,
+                wait: !no_wait,
+                summary_json: output_path(summary_json)?,
                 cert_manager_version,
                 jobset_version,
                 leaderworkerset_version,
@@ -674,15 +1708,23 @@ fn handle_deploy_upstream_command(command: DeployUpstreamCommands) -> Result<()>
         }
         DeployUpstreamCommands::Helm {
             source,
+            strict,
             release_name,
             namespace,
             values_file,
             set_values,
+            set_string_values,
+            set_file_values,
             build_image,
             image_tag,
+            make_target,
+            dockerfile,
             cluster_name,
             kubeconfig,
             skip_deps,
+            strict_versions,
+            no_wait,
+            summary_json,
             cert_manager_version,
             jobset_version,
             leaderworkerset_version,
@@ -693,15 +1735,25 @@ fn handle_deploy_upstream_command(command: DeployUpstreamCommands) -> Result<()>
 
             kueue_dev::commands::deploy::deploy_upstream_helm(DeployUpstreamHelmOptions {
                 source,
+                strict,
                 release_name,
                 namespace,
                 values_file,
                 set_values,
+                set_string_values,
+                set_file_values,
                 build_image,
                 image_tag,
+                build_method: kueue_dev::install::upstream::ImageBuildMethod::from_options(
+                    make_target,
+                    dockerfile,
+                ),
                 kubeconfig,
                 cluster_name,
                 skip_deps,
+                strict_versions,
+                wait: !no_wait,
+                summary_json: output_path(summary_json)?,
                 cert_manager_version,
                 jobset_version,
                 leaderworkerset_version,
@@ -716,17 +1768,46 @@ fn handle_test_command(command: TestCommands) -> Result<()> {
     use std::path::PathBuf;
 
     match command {
+        TestCommands::List {
+            focus,
+            label_filter,
+            ginkgo,
+            test_path,
+        } => {
+            let ginkgo = ginkgo.into_options(test_path, None);
+            kueue_dev::commands::test::list_tests(focus, label_filter, &ginkgo)
+        }
         TestCommands::Run {
             focus,
             label_filter,
             kubeconfig,
+            ginkgo,
+            test_path,
+            report_dir,
+            repeat,
+            summary_json,
         } => {
             let kc = kubeconfig.map(PathBuf::from);
-            kueue_dev::commands::test::run_tests_with_retry(focus, label_filter, kc)
+            let ginkgo = ginkgo.into_options(test_path, report_dir);
+            if repeat > 1 || summary_json.is_some() {
+                let summary_json = output_path(summary_json)?;
+                kueue_dev::commands::test::run_tests_repeated(
+                    focus,
+                    label_filter,
+                    kc,
+                    &ginkgo,
+                    repeat,
+                    summary_json.as_deref(),
+                )
+            } else {
+                kueue_dev::commands::test::run_tests_with_retry(focus, label_filter, kc, &ginkgo)
+            }
         }
         TestCommands::Operator {
             r#type,
             name,
+            reuse,
+            calico_version,
             focus,
             label_filter,
             kubeconfig,
@@ -734,9 +1815,19 @@ fn handle_test_command(command: TestCommands) -> Result<()> {
             skip_kueue_cr,
             kueue_frameworks,
             kueue_namespace,
+            cr_labels,
+            cr_annotations,
+            ginkgo,
+            node_labels,
+            report_dir,
+            junit_out,
         } => {
             use kueue_dev::config::settings::Settings;
 
+            let mut ginkgo = ginkgo.into_options(None, report_dir);
+            ginkgo.junit_out = output_path(junit_out)?;
+            ginkgo.worker_labels = node_labels.into_worker_labels()?;
+
             match r#type.as_str() {
                 "kind" => {
                     use kueue_dev::commands::test::TestKindOptions;
@@ -747,23 +1838,38 @@ fn handle_test_command(command: TestCommands) -> Result<()> {
 
                     kueue_dev::commands::test::run_tests_kind(TestKindOptions {
                         cluster_name: name,
+                        reuse,
+                        calico_version,
                         focus,
                         label_filter,
                         images_file,
                         skip_kueue_cr,
                         kueue_frameworks,
                         kueue_namespace,
+                        kueue_cr_labels: cr_labels,
+                        kueue_cr_annotations: cr_annotations,
+                        ginkgo,
                     })
                 }
                 "openshift" => {
                     // For OpenShift, we expect the user to be logged in with oc
                     // The tests will use the current context
-                    kueue_dev::commands::test::run_tests_with_retry(focus, label_filter, None)
+                    kueue_dev::commands::test::run_tests_with_retry(
+                        focus,
+                        label_filter,
+                        None,
+                        &ginkgo,
+                    )
                 }
                 "kubeconfig" => {
                     // Use the provided or environment kubeconfig
                     let kc = kubeconfig.map(PathBuf::from);
-                    kueue_dev::commands::test::run_tests_with_retry(focus, label_filter, kc)
+                    kueue_dev::commands::test::run_tests_with_retry(
+                        focus,
+                        label_filter,
+                        kc,
+                        &ginkgo,
+                    )
                 }
                 _ => Err(anyhow::anyhow!("Invalid operator type: {}", r#type)),
             }
@@ -773,18 +1879,156 @@ fn handle_test_command(command: TestCommands) -> Result<()> {
             label_filter,
             kubeconfig,
             target,
+            flavor,
+            no_scale_down_operator,
+            no_restore_operator,
+            keep_network_policies,
+            ginkgo,
+            node_labels,
+            report_dir,
         } => {
+            use kueue_dev::commands::test::UpstreamKindSetup;
+
             let kc = kubeconfig.map(PathBuf::from);
-            kueue_dev::commands::test::test_upstream(focus, label_filter, kc, target)
+            let mut ginkgo = ginkgo.into_options(None, report_dir);
+            ginkgo.worker_labels = node_labels.into_worker_labels()?;
+            let kind_setup = UpstreamKindSetup {
+                scale_down_operator: !no_scale_down_operator,
+                restore_operator: !no_restore_operator,
+                delete_network_policies: !keep_network_policies,
+            };
+            kueue_dev::commands::test::test_upstream(
+                focus,
+                label_filter,
+                kc,
+                target,
+                flavor,
+                &ginkgo,
+                kind_setup,
+            )
         }
     }
 }
 
-fn handle_cleanup_command(kubeconfig: Option<String>) -> Result<()> {
+fn handle_cleanup_command(
+    kubeconfig: Option<String>,
+    scope: String,
+    dry_run: bool,
+    namespace_prefixes: Vec<String>,
+    namespaces: Vec<String>,
+    yes: bool,
+) -> Result<()> {
+    use kueue_dev::commands::cleanup::{CleanupScope, cleanup};
     use std::path::PathBuf;
+    use std::str::FromStr;
 
     let kc = kubeconfig.as_ref().map(PathBuf::from);
-    kueue_dev::commands::cleanup::cleanup(kc.as_deref())
+    let scope = CleanupScope::from_str(&scope)?;
+    cleanup(
+        kc.as_deref(),
+        scope,
+        dry_run,
+        &namespace_prefixes,
+        &namespaces,
+        yes,
+    )
+}
+
+fn handle_config_command(command: ConfigCommands) -> Result<()> {
+    use anyhow::Context;
+    use kueue_dev::config::edit;
+    use std::path::PathBuf;
+
+    match command {
+        ConfigCommands::Get { key } => {
+            println!("{}", edit::get_value(&Settings::load()?, &key)?);
+            Ok(())
+        }
+        ConfigCommands::Set { key, value, file } => {
+            let path = match file
+                .map(PathBuf::from)
+                .or_else(Settings::active_config_file)
+            {
+                Some(path) => path,
+                None => Settings::user_config_file()
+                    .ok_or_else(|| anyhow::anyhow!("Could not determine config directory"))?,
+            };
+
+            let contents = if path.exists() {
+                std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read config file: {}", path.display()))?
+            } else {
+                String::new()
+            };
+
+            let updated = edit::set_value(&contents, &key, &value)
+                .with_context(|| format!("Failed to update {}", path.display()))?;
+
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+            }
+            std::fs::write(&path, updated)
+                .with_context(|| format!("Failed to write config file: {}", path.display()))?;
+
+            log_info!("Set {} = {} in {}", key, value, path.display());
+            Ok(())
+        }
+    }
+}
+
+fn handle_netpol_command(command: NetpolCommands) -> Result<()> {
+    use std::path::{Path, PathBuf};
+
+    match command {
+        NetpolCommands::Backup { dir, kubeconfig } => {
+            let kc = kubeconfig.map(PathBuf::from);
+            kueue_dev::commands::netpol::backup(Path::new(&dir), kc.as_deref(), &[]).map(|_| ())
+        }
+        NetpolCommands::Restore { dir, kubeconfig } => {
+            let kc = kubeconfig.map(PathBuf::from);
+            kueue_dev::commands::netpol::restore(Path::new(&dir), kc.as_deref()).map(|_| ())
+        }
+    }
+}
+
+fn handle_wait_command(command: WaitCommands) -> Result<()> {
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    match command {
+        WaitCommands::Crds {
+            kubeconfig,
+            timeout,
+        } => {
+            let kc = kubeconfig.map(PathBuf::from);
+            log_info!("Waiting for Kueue CRDs to be established...");
+            kueue_dev::install::upstream::wait_for_kueue_crds(
+                kc.as_deref(),
+                Duration::from_secs(timeout),
+                Settings::load()?.behavior.show_progress,
+            )
+        }
+    }
+}
+
+fn handle_registry_command(command: RegistryCommands) -> Result<()> {
+    match command {
+        RegistryCommands::Start { port } => kueue_dev::commands::registry::start(port),
+        RegistryCommands::Stop => kueue_dev::commands::registry::stop(),
+    }
+}
+
+fn handle_deps_command(command: DepsCommands) -> Result<()> {
+    match command {
+        DepsCommands::Install {
+            dependency,
+            version,
+            name,
+            kubeconfig,
+        } => kueue_dev::commands::deps::install(&dependency, version, kubeconfig, &name),
+        DepsCommands::List => kueue_dev::commands::deps::list(),
+    }
 }
 
 fn handle_images_command(command: ImagesCommands) -> Result<()> {
@@ -796,7 +2040,56 @@ fn handle_images_command(command: ImagesCommands) -> Result<()> {
             components,
             images,
             parallel,
-        } => kueue_dev::commands::build::build_and_push(components, images, parallel),
+            push_to,
+            local_registry,
+            platform,
+            tag_suffix,
+            tag_sha,
+            no_push,
+        } => kueue_dev::commands::build::build_and_push(kueue_dev::commands::build::BuildOptions {
+            components,
+            images_file: images,
+            parallel,
+            push_to,
+            local_registry,
+            platforms: platform,
+            tag_suffix,
+            tag_sha,
+            no_push,
+        }),
+        ImagesCommands::Catalog {
+            images,
+            tag,
+            from_index,
+            no_push,
+        } => {
+            kueue_dev::commands::build::build_catalog(kueue_dev::commands::build::CatalogOptions {
+                images_file: images,
+                tag,
+                from_index,
+                no_push,
+            })
+        }
+        ImagesCommands::RebuildOperand {
+            cluster,
+            images,
+            namespace,
+            kubeconfig,
+        } => kueue_dev::commands::build::rebuild_operand(
+            &cluster,
+            images,
+            namespace,
+            kubeconfig.as_deref().map(std::path::Path::new),
+        ),
+        ImagesCommands::Prune {
+            images,
+            older_than,
+            dry_run,
+        } => kueue_dev::commands::prune::prune(kueue_dev::commands::prune::PruneOptions {
+            images_file: images,
+            older_than,
+            dry_run,
+        }),
         ImagesCommands::List { file } => {
             let path = PathBuf::from(&file);
             let config = ImageConfig::load(&path)?;
@@ -808,11 +2101,55 @@ fn handle_images_command(command: ImagesCommands) -> Result<()> {
             }
             Ok(())
         }
-        ImagesCommands::Load { name, images } => {
+        ImagesCommands::Diff { old, new } => {
+            use colored::Colorize;
+            use kueue_dev::config::images::{ImageChange, diff};
+
+            let old_config = ImageConfig::load(&PathBuf::from(&old))?;
+            let new_config = ImageConfig::load(&PathBuf::from(&new))?;
+            let changes = diff(&old_config, &new_config);
+
+            if !kueue_dev::utils::is_quiet() {
+                if changes.is_empty() {
+                    println!("No image changes between {} and {}", old, new);
+                }
+                for change in &changes {
+                    match change {
+                        ImageChange::Added { name, image } => {
+                            println!("{}", format!("+ {}: {}", name, image).green())
+                        }
+                        ImageChange::Removed { name, image } => {
+                            println!("{}", format!("- {}: {}", name, image).red())
+                        }
+                        ImageChange::Changed { name, old, new } => {
+                            println!("{}", format!("~ {}:", name).yellow());
+                            println!("{}", format!("    - {}", old).red());
+                            println!("{}", format!("    + {}", new).green());
+                        }
+                    }
+                }
+            }
+
+            if !changes.is_empty() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
+        ImagesCommands::Load {
+            name,
+            images,
+            from_archive,
+            max_parallel,
+        } => {
             use kueue_dev::config::settings::Settings;
-            use kueue_dev::k8s::images::load_images_to_kind;
+            use kueue_dev::k8s::images::{load_archives_to_kind, load_images_to_kind};
             use kueue_dev::utils::ContainerRuntime;
 
+            if let Some(archive) = from_archive {
+                let runtime = ContainerRuntime::detect()?;
+                return load_archives_to_kind(&name, &PathBuf::from(archive), &runtime);
+            }
+
             // Use provided images file or fall back to config file setting
             let settings = Settings::load()?;
             let images_file = images.unwrap_or(settings.defaults.images_file);
@@ -822,14 +2159,23 @@ fn handle_images_command(command: ImagesCommands) -> Result<()> {
             let runtime = ContainerRuntime::detect()?;
             log_info!("Using container runtime: {}", runtime);
 
-            load_images_to_kind(&name, &config, &runtime, true)
+            load_images_to_kind(
+                &name,
+                &config,
+                &runtime,
+                true,
+                max_parallel as usize,
+                settings.behavior.parallel_operations,
+            )
         }
     }
 }
 
-fn handle_check_command() -> Result<()> {
-    log_info!("Checking all prerequisites...");
-    log_info!("");
+fn handle_check_command(summary_only: bool) -> Result<()> {
+    if !summary_only {
+        log_info!("Checking all prerequisites...");
+        log_info!("");
+    }
 
     // Create owned prerequisite objects
     let kubectl = CommonPrereqs::kubectl();
@@ -839,26 +2185,17 @@ fn handle_check_command() -> Result<()> {
     let operator_sdk = CommonPrereqs::operator_sdk();
     let kustomize = CommonPrereqs::kustomize();
     let helm = CommonPrereqs::helm();
+    let opm = CommonPrereqs::opm();
 
     // Build vector of all prerequisites
     let prereqs: Vec<&dyn Prerequisite> = vec![&kubectl, &kind_prereq, &go, &oc, &operator_sdk];
 
-    // Optional prerequisites for upstream deployment
-    let optional_prereqs: Vec<&dyn Prerequisite> = vec![&kustomize, &helm];
+    // Optional prerequisites for upstream deployment and catalog builds
+    let optional_prereqs: Vec<&dyn Prerequisite> = vec![&kustomize, &helm, &opm];
 
     // Check container runtime
-    let container_runtime_available = match ContainerRuntime::detect() {
-        Ok(runtime) => {
-            log_info!("✓ Container runtime: {}", runtime);
-            true
-        }
-        Err(_) => {
-            log_error!("✗ Container runtime: Neither docker nor podman found");
-            false
-        }
-    };
-
-    log_info!("");
+    let container_runtime = ContainerRuntime::detect().ok();
+    let container_runtime_available = container_runtime.is_some();
 
     // Check all prerequisites
     let (found, missing) = CommonPrereqs::check_all(&prereqs);
@@ -866,33 +2203,41 @@ fn handle_check_command() -> Result<()> {
     // Check optional prerequisites
     let (optional_found, optional_missing) = CommonPrereqs::check_all(&optional_prereqs);
 
-    // Display found tools
-    if !found.is_empty() {
-        log_info!("Required tools:");
-        for tool in &found {
-            log_info!("  ✓ {}", tool);
+    if !summary_only {
+        match container_runtime {
+            Some(runtime) => log_info!("✓ Container runtime: {}", runtime),
+            None => log_error!("✗ Container runtime: Neither docker nor podman found"),
         }
         log_info!("");
-    }
 
-    // Display missing tools
-    if !missing.is_empty() {
-        log_error!("Missing required tools:");
-        for (name, hint) in &missing {
-            log_error!("  ✗ {} - {}", name, hint);
+        // Display found tools
+        if !found.is_empty() {
+            log_info!("Required tools:");
+            for tool in &found {
+                log_info!("  ✓ {}", tool);
+            }
+            log_info!("");
         }
-        log_info!("");
-    }
 
-    // Display optional tools (for upstream deployment)
-    log_info!("Optional tools (for upstream deployment):");
-    for tool in &optional_found {
-        log_info!("  ✓ {}", tool);
-    }
-    for (name, _hint) in &optional_missing {
-        log_warn!("  - {} (not installed)", name);
+        // Display missing tools
+        if !missing.is_empty() {
+            log_error!("Missing required tools:");
+            for (name, hint) in &missing {
+                log_error!("  ✗ {} - {}", name, hint);
+            }
+            log_info!("");
+        }
+
+        // Display optional tools (for upstream deployment and catalog builds)
+        log_info!("Optional tools (for upstream deployment and catalog builds):");
+        for tool in &optional_found {
+            log_info!("  ✓ {}", tool);
+        }
+        for (name, _hint) in &optional_missing {
+            log_warn!("  - {} (not installed)", name);
+        }
+        log_info!("");
     }
-    log_info!("");
 
     // Summary
     log_info!("==========================================");
@@ -925,7 +2270,7 @@ fn handle_check_command() -> Result<()> {
         std::process::exit(1);
     } else {
         log_info!("✓ All required prerequisites satisfied!");
-        if !optional_missing.is_empty() {
+        if !optional_missing.is_empty() && !summary_only {
             log_info!(
                 "  (Optional tools missing: install kustomize/helm for 'deploy upstream' commands)"
             );
@@ -934,20 +2279,110 @@ fn handle_check_command() -> Result<()> {
     }
 }
 
-fn handle_interactive_command(kubeconfig: Option<String>) -> Result<()> {
+fn handle_interactive_command(kubeconfig: Option<String>, namespace: Option<String>) -> Result<()> {
+    use std::path::PathBuf;
+
+    let kc = kubeconfig.as_ref().map(PathBuf::from);
+    kueue_dev::commands::interactive::show_menu(kc.as_deref(), namespace.as_deref())
+}
+
+fn handle_logs_command(
+    target: kueue_dev::commands::interactive::LogTarget,
+    kubeconfig: Option<String>,
+    namespace: Option<String>,
+) -> Result<()> {
+    use kueue_dev::commands::interactive::{MenuContext, logs};
+    use std::path::PathBuf;
+
+    let kc = kubeconfig.as_ref().map(PathBuf::from);
+    logs(
+        &MenuContext::detect(kc.as_deref(), namespace.as_deref())?,
+        target,
+    )
+}
+
+fn handle_port_forward_command(command: PortForwardCommands) -> Result<()> {
+    use kueue_dev::commands::interactive::{MenuContext, port_forward_prometheus};
+    use std::path::PathBuf;
+
+    match command {
+        PortForwardCommands::Prometheus { kubeconfig } => {
+            let kc = kubeconfig.as_ref().map(PathBuf::from);
+            port_forward_prometheus(&MenuContext::detect(kc.as_deref(), None)?)
+        }
+    }
+}
+
+fn handle_info_command(kubeconfig: Option<String>) -> Result<()> {
+    use kueue_dev::commands::interactive::{MenuContext, show_cluster_info};
     use std::path::PathBuf;
 
     let kc = kubeconfig.as_ref().map(PathBuf::from);
-    kueue_dev::commands::interactive::show_menu(kc.as_deref())
+    show_cluster_info(&MenuContext::detect(kc.as_deref(), None)?)
 }
 
-fn handle_completion_command(shell: Shell) -> Result<()> {
+fn handle_completion_command(shell: Shell, install: bool) -> Result<()> {
     let mut cmd = Cli::command();
-    generate(shell, &mut cmd, "kueue-dev", &mut io::stdout());
+    if install {
+        let mut script = Vec::new();
+        generate(shell, &mut cmd, "kueue-dev", &mut script);
+        kueue_dev::commands::completion::install(shell, &script)?;
+    } else {
+        generate(shell, &mut cmd, "kueue-dev", &mut io::stdout());
+    }
     Ok(())
 }
 
-fn handle_version_command() -> Result<()> {
+/// Resolve an output file given on the command line against the invocation
+/// directory, since deploy and test commands change into the operator source
+/// before writing it
+fn output_path(path: Option<String>) -> Result<Option<std::path::PathBuf>> {
+    path.map(|path| {
+        std::path::absolute(&path)
+            .map_err(|e| anyhow::anyhow!("Failed to resolve output path {}: {}", path, e))
+    })
+    .transpose()
+}
+
+fn handle_version_command(operator: bool, kubeconfig: Option<String>) -> Result<()> {
+    use kueue_dev::commands::deployment_info::DeploymentInfo;
+    use kueue_dev::k8s::kubectl;
+    use kueue_dev::k8s::target::KubeTarget;
+    use std::path::PathBuf;
+
     println!("kueue-dev {}", get_version());
+
+    if !operator {
+        return Ok(());
+    }
+
+    let kc = kubeconfig.as_ref().map(PathBuf::from);
+    let target = KubeTarget::new(kc.as_deref());
+    let namespace = Settings::load()?.operator.namespace;
+
+    match kubectl::get_operator_version(&namespace, &target) {
+        Ok(version) => println!("operator {}", version),
+        Err(e) => println!("operator: not available ({})", e),
+    }
+
+    match kubectl::get_kueue_manager_version(&namespace, &target) {
+        Ok(version) => println!("kueue-controller-manager {}", version),
+        Err(e) => println!("kueue-controller-manager: not available ({})", e),
+    }
+
+    // Recorded by deploy; missing for clusters deployed some other way
+    if let Ok(info) = DeploymentInfo::read(&namespace, &target) {
+        println!(
+            "deployed {} by kueue-dev {}",
+            info.deployed_at, info.cli_version
+        );
+        for (name, image) in &info.images {
+            println!("  image {}: {}", name, image);
+        }
+        for (name, version) in &info.versions {
+            println!("  {} {}", name, version);
+        }
+    }
+
     Ok(())
 }
@@ -1,6 +1,7 @@
 //! Container runtime detection and operations (Docker/Podman)
 
 use anyhow::{Context, Result, anyhow};
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,27 +64,99 @@ impl ContainerRuntime {
         Ok(())
     }
 
-    /// Load an image into a kind cluster
+    /// Load an image into a kind cluster.
+    /// With podman, falls back to piping `podman save` into `kind load image-archive`.
     pub fn load_to_kind(&self, image: &str, cluster_name: &str) -> Result<()> {
         crate::log_info!("Loading image into kind cluster: {}", image);
 
+        let status = self
+            .kind_command(&["load", "docker-image", image, "--name", cluster_name])
+            .status()
+            .with_context(|| {
+                format!(
+                    "Failed to load image {} to kind cluster {}",
+                    image, cluster_name
+                )
+            })?;
+
+        if !status.success() {
+            if !matches!(self, ContainerRuntime::Podman) {
+                return Err(anyhow!("Failed to load image {} to kind cluster", image));
+            }
+
+            crate::log_info!("Retrying with podman save/load...");
+            self.load_archive_to_kind(image, cluster_name)?;
+        }
+
+        Ok(())
+    }
+
+    /// `kind` command with the provider matching this runtime
+    fn kind_command(&self, args: &[&str]) -> Command {
         let mut cmd = Command::new("kind");
-        cmd.args(["load", "docker-image", image, "--name", cluster_name]);
+        cmd.args(args);
 
         // For podman, set the experimental provider
         if matches!(self, ContainerRuntime::Podman) {
             cmd.env("KIND_EXPERIMENTAL_PROVIDER", "podman");
         }
 
-        let status = cmd.status().with_context(|| {
-            format!(
-                "Failed to load image {} to kind cluster {}",
-                image, cluster_name
-            )
-        })?;
+        cmd
+    }
 
+    /// Load an image archive (`docker save` or OCI format) into a kind cluster
+    pub fn load_archive_file_to_kind(&self, archive: &Path, cluster_name: &str) -> Result<()> {
+        let archive = archive.to_string_lossy();
+        let status = self
+            .kind_command(&[
+                "load",
+                "image-archive",
+                archive.as_ref(),
+                "--name",
+                cluster_name,
+            ])
+            .status()
+            .with_context(|| format!("Failed to load {} into kind cluster", archive))?;
         if !status.success() {
-            return Err(anyhow!("Failed to load image {} to kind cluster", image));
+            return Err(anyhow!("kind load image-archive failed for {}", archive));
+        }
+        Ok(())
+    }
+
+    /// Save an image with the runtime and load the archive into a kind cluster
+    fn load_archive_to_kind(&self, image: &str, cluster_name: &str) -> Result<()> {
+        let save_output = Command::new(self.command())
+            .args(["save", image])
+            .output()
+            .with_context(|| format!("Failed to run {} save", self.command()))?;
+
+        if !save_output.status.success() {
+            return Err(anyhow!("{} save failed", self.command()));
+        }
+
+        let mut kind_load = self
+            .kind_command(&[
+                "load",
+                "image-archive",
+                "/dev/stdin",
+                "--name",
+                cluster_name,
+            ])
+            .stdin(std::process::Stdio::piped())
+            .spawn()
+            .context("Failed to spawn kind load")?;
+
+        {
+            use std::io::Write;
+            let stdin = kind_load.stdin.as_mut().expect("Failed to open stdin");
+            stdin
+                .write_all(&save_output.stdout)
+                .context("Failed to write to kind stdin")?;
+        }
+
+        let status = kind_load.wait().context("Failed to wait for kind load")?;
+        if !status.success() {
+            return Err(anyhow!("kind load image-archive failed"));
         }
 
         Ok(())
@@ -109,6 +182,48 @@ impl ContainerRuntime {
         Ok(())
     }
 
+    /// Directory where the runtime stores images and containers
+    pub fn storage_root(&self) -> Result<PathBuf> {
+        let format = match self {
+            ContainerRuntime::Docker => "{{.DockerRootDir}}",
+            ContainerRuntime::Podman => "{{.Store.GraphRoot}}",
+        };
+
+        let output = Command::new(self.command())
+            .args(["info", "--format", format])
+            .output()
+            .with_context(|| format!("Failed to run {} info", self.command()))?;
+
+        if !output.status.success() {
+            return Err(anyhow!("{} info failed", self.command()));
+        }
+
+        let root = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if root.is_empty() {
+            return Err(anyhow!("{} did not report a storage root", self.command()));
+        }
+
+        Ok(PathBuf::from(root))
+    }
+
+    /// Reclaimable space per category from `system df`, e.g. ("Images", "3.2GB (45%)")
+    pub fn reclaimable_space(&self) -> Result<Vec<(String, String)>> {
+        let output = Command::new(self.command())
+            .args(["system", "df", "--format", "{{.Type}}\t{{.Reclaimable}}"])
+            .output()
+            .with_context(|| format!("Failed to run {} system df", self.command()))?;
+
+        if !output.status.success() {
+            return Err(anyhow!("{} system df failed", self.command()));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(kind, size)| (kind.trim().to_string(), size.trim().to_string()))
+            .collect())
+    }
+
     /// Get list of images
     pub fn list_images(&self) -> Result<Vec<String>> {
         let output = Command::new(self.command())
@@ -130,6 +245,165 @@ impl ContainerRuntime {
     }
 }
 
+/// An image in the runtime's local store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalImage {
+    pub repository: String,
+    pub tag: String,
+    pub id: String,
+    /// Creation time as seconds since the Unix epoch, when it could be parsed
+    pub created: Option<u64>,
+}
+
+impl LocalImage {
+    /// `repository:tag`
+    pub fn reference(&self) -> String {
+        format!("{}:{}", self.repository, self.tag)
+    }
+}
+
+impl ContainerRuntime {
+    /// Tagged images in the local store
+    pub fn list_local_images(&self) -> Result<Vec<LocalImage>> {
+        let output = Command::new(self.command())
+            .args([
+                "images",
+                "--format",
+                "{{.Repository}}\t{{.Tag}}\t{{.ID}}\t{{.CreatedAt}}",
+            ])
+            .output()
+            .with_context(|| format!("Failed to run {} images", self.command()))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "{} images failed:\n{}",
+                self.command(),
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(parse_local_images(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// Remove an image from the local store
+    pub fn remove_image(&self, reference: &str) -> Result<()> {
+        let output = Command::new(self.command())
+            .args(["rmi", reference])
+            .output()
+            .with_context(|| format!("Failed to run {} rmi", self.command()))?;
+
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Failed to remove {}:\n{}",
+                reference,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Image references present on the nodes of a kind cluster
+    pub fn kind_cluster_images(&self, cluster_name: &str) -> Result<Vec<String>> {
+        let output = self
+            .kind_command(&["get", "nodes", "--name", cluster_name])
+            .output()
+            .context("Failed to run kind get nodes")?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "Failed to list nodes of kind cluster {}",
+                cluster_name
+            ));
+        }
+
+        let mut images = Vec::new();
+        for node in String::from_utf8_lossy(&output.stdout).split_whitespace() {
+            let output = Command::new(self.command())
+                .args(["exec", node, "crictl", "images", "-o", "json"])
+                .output()
+                .with_context(|| format!("Failed to list images on node {}", node))?;
+            if !output.status.success() {
+                return Err(anyhow!("Failed to list images on node {}", node));
+            }
+
+            let list: serde_json::Value = serde_json::from_slice(&output.stdout)
+                .with_context(|| format!("Failed to parse image list from node {}", node))?;
+            let tags = list["images"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .flat_map(|image| image["repoTags"].as_array().into_iter().flatten())
+                .filter_map(|tag| tag.as_str().map(String::from));
+            images.extend(tags);
+        }
+
+        Ok(images)
+    }
+}
+
+/// Parse `images --format '{{.Repository}}\t{{.Tag}}\t{{.ID}}\t{{.CreatedAt}}'`,
+/// dropping untagged (`<none>`) images
+fn parse_local_images(output: &str) -> Vec<LocalImage> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let repository = fields.next()?.trim();
+            let tag = fields.next()?.trim();
+            let id = fields.next().unwrap_or_default().trim();
+            let created = fields.next().and_then(parse_created_at);
+            if repository == "<none>" || tag == "<none>" || repository.is_empty() {
+                return None;
+            }
+            Some(LocalImage {
+                repository: repository.to_string(),
+                tag: tag.to_string(),
+                id: id.to_string(),
+                created,
+            })
+        })
+        .collect()
+}
+
+/// Parse the `CreatedAt` column, e.g. `2025-01-02 15:04:05 -0700 MST` (docker) or
+/// `2025-01-02 15:04:05.123456789 +0000 UTC` (podman), into Unix seconds
+fn parse_created_at(value: &str) -> Option<u64> {
+    let mut parts = value.split_whitespace();
+    let date = parts.next()?;
+    let time = parts.next()?;
+    let offset = parts.next().unwrap_or("+0000");
+
+    let mut ymd = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (year, month, day) = (ymd.next()??, ymd.next()??, ymd.next()??);
+
+    let time = time.split('.').next()?;
+    let mut hms = time.splitn(3, ':').map(|p| p.parse::<i64>().ok());
+    let (hour, minute, second) = (hms.next()??, hms.next()??, hms.next()??);
+
+    let (sign, digits) = match offset.as_bytes().first()? {
+        b'+' => (1, &offset[1..]),
+        b'-' => (-1, &offset[1..]),
+        _ => return None,
+    };
+    if digits.len() != 4 {
+        return None;
+    }
+    let offset_secs =
+        sign * (digits[..2].parse::<i64>().ok()? * 3600 + digits[2..].parse::<i64>().ok()? * 60);
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let secs = days * 86400 + hour * 3600 + minute * 60 + second - offset_secs;
+    u64::try_from(secs).ok()
+}
+
 impl std::fmt::Display for ContainerRuntime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.command())
@@ -139,6 +413,47 @@ impl std::fmt::Display for ContainerRuntime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::ffi::OsStr;
+
+    fn kind_provider(runtime: ContainerRuntime) -> Option<String> {
+        runtime
+            .kind_command(&["load", "docker-image", "img"])
+            .get_envs()
+            .find(|(key, _)| *key == OsStr::new("KIND_EXPERIMENTAL_PROVIDER"))
+            .and_then(|(_, value)| value.map(|v| v.to_string_lossy().to_string()))
+    }
+
+    #[test]
+    fn test_parse_local_images() {
+        let output = "quay.io/org/kueue\tdev\tabc123\t2025-01-02 15:04:05 -0700 MST\n\
+                      <none>\t<none>\tdef456\t2025-01-01 00:00:00 +0000 UTC\n\
+                      localhost/kueue\tlatest\t0a1b2c\t2024-12-31 23:59:59.5 +0000 UTC\n";
+        let images = parse_local_images(output);
+        assert_eq!(images.len(), 2);
+        assert_eq!(images[0].reference(), "quay.io/org/kueue:dev");
+        assert_eq!(images[0].created, Some(1735855445));
+        assert_eq!(images[1].id, "0a1b2c");
+        assert_eq!(images[1].created, Some(1735689599));
+    }
+
+    #[test]
+    fn test_parse_created_at() {
+        assert_eq!(parse_created_at("1970-01-01 00:00:00 +0000 UTC"), Some(0));
+        assert_eq!(
+            parse_created_at("2024-02-29 12:00:00 +0100 CET"),
+            Some(1709204400)
+        );
+        assert_eq!(parse_created_at("3 weeks ago"), None);
+    }
+
+    #[test]
+    fn test_kind_command_sets_podman_provider() {
+        assert_eq!(
+            kind_provider(ContainerRuntime::Podman).as_deref(),
+            Some("podman")
+        );
+        assert_eq!(kind_provider(ContainerRuntime::Docker), None);
+    }
 
     #[test]
     fn test_detect_runtime() {
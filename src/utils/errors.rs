@@ -28,6 +28,15 @@ impl KueueDevError {
         self
     }
 
+    /// Append diagnostic details to the error message
+    pub fn with_details(mut self, details: impl AsRef<str>) -> Self {
+        let details = details.as_ref().trim_end();
+        if !details.is_empty() {
+            self.message = format!("{}\n{}", self.message, details);
+        }
+        self
+    }
+
     /// Add a documentation link
     pub fn with_docs(mut self, link: impl Into<String>) -> Self {
         self.docs_link = Some(link.into());
@@ -37,18 +46,22 @@ impl KueueDevError {
     /// Display the error with suggestions
     pub fn display(&self) {
         crate::log_error!("{}", self.message);
+        self.print_suggestions();
+    }
 
+    /// Print the suggestions and documentation link to stderr
+    pub fn print_suggestions(&self) {
         if !self.suggestions.is_empty() {
-            println!();
-            println!("{}", "Suggestions:".yellow().bold());
+            eprintln!();
+            eprintln!("{}", "Suggestions:".yellow().bold());
             for suggestion in &self.suggestions {
-                println!("  {} {}", "→".blue(), suggestion);
+                eprintln!("  {} {}", "→".blue(), suggestion);
             }
         }
 
         if let Some(docs) = &self.docs_link {
-            println!();
-            println!("{} {}", "📚 Documentation:".cyan(), docs);
+            eprintln!();
+            eprintln!("{} {}", "📚 Documentation:".cyan(), docs);
         }
     }
 
@@ -178,6 +191,20 @@ impl KueueDevError {
             .suggest("Verify credentials and cluster accessibility")
     }
 
+    /// Container runtime ran out of disk space
+    pub fn disk_full(operation: &str, runtime_cmd: &str) -> Self {
+        Self::new(format!("{} failed: no space left on device", operation))
+            .suggest(format!(
+                "Remove unused images and build cache: {} system prune -a",
+                runtime_cmd
+            ))
+            .suggest(format!(
+                "Check the runtime's storage usage: {} system df",
+                runtime_cmd
+            ))
+            .suggest("Free up space on the filesystem holding the container storage")
+    }
+
     /// OLM not installed error
     pub fn olm_not_installed() -> Self {
         Self::new("OLM (Operator Lifecycle Manager) is not installed on the cluster")
@@ -202,7 +229,7 @@ pub fn enhance_error(err: anyhow::Error) -> KueueDevError {
         return KueueDevError::cluster_not_found(cluster_name);
     }
 
-    if err_str.contains("connection refused") || err_str.contains("timeout") {
+    if is_transient_error(&err_str) {
         return KueueDevError::connection_timeout("cluster");
     }
 
@@ -220,6 +247,31 @@ pub fn enhance_error(err: anyhow::Error) -> KueueDevError {
         .suggest("Check logs for additional context")
 }
 
+/// Check whether an error message looks like a transient connectivity failure
+/// (e.g. the API server is still starting up) that is worth retrying
+pub fn is_transient_error(err_str: &str) -> bool {
+    err_str.contains("connection refused") || err_str.contains("timeout")
+}
+
+/// Lines of command output that report the disk (or quota) being full
+pub fn disk_full_lines(output: &str) -> Vec<&str> {
+    const PATTERNS: &[&str] = &[
+        "no space left on device",
+        "disk quota exceeded",
+        "not enough space",
+        "enospc",
+    ];
+
+    output
+        .lines()
+        .filter(|line| {
+            let line = line.to_lowercase();
+            PATTERNS.iter().any(|pattern| line.contains(pattern))
+        })
+        .map(str::trim)
+        .collect()
+}
+
 /// Extract cluster name from error message
 fn extract_cluster_name(msg: &str) -> Option<&str> {
     // Try to extract cluster name from common error patterns
@@ -242,12 +294,56 @@ mod tests {
         assert_eq!(err.suggestions.len(), 2);
     }
 
+    #[test]
+    fn test_error_with_details() {
+        let err = KueueDevError::deployment_not_ready("kueue-controller-manager", "kueue-system")
+            .with_details("pod/foo: phase=Running restarts=5 waiting=CrashLoopBackOff");
+        assert!(
+            err.message
+                .starts_with("Deployment kueue-system/kueue-controller-manager")
+        );
+        assert!(err.to_string().contains("CrashLoopBackOff"));
+
+        let err = KueueDevError::new("plain").with_details("  \n");
+        assert_eq!(err.message, "plain");
+    }
+
     #[test]
     fn test_error_with_docs() {
         let err = KueueDevError::new("test error").with_docs("https://example.com");
         assert!(err.docs_link.is_some());
     }
 
+    #[test]
+    fn test_is_transient_error() {
+        assert!(is_transient_error(
+            "dial tcp 127.0.0.1:6443: connect: connection refused"
+        ));
+        assert!(is_transient_error("i/o timeout"));
+        assert!(!is_transient_error("Error from server (NotFound)"));
+    }
+
+    #[test]
+    fn test_disk_full_lines() {
+        let stderr = "STEP 3/7: RUN make build\n\
+                      go: downloading k8s.io/api v0.33.0\n\
+                      write /var/tmp/buildah123/layer: no space left on device\n\
+                      Error: building at STEP \"RUN make build\": exit status 1\n";
+        assert_eq!(
+            disk_full_lines(stderr),
+            vec!["write /var/tmp/buildah123/layer: no space left on device"]
+        );
+        assert_eq!(
+            disk_full_lines("failed to copy: ENOSPC: disk quota exceeded").len(),
+            1
+        );
+        assert!(disk_full_lines("Error: unauthorized: authentication required").is_empty());
+
+        let err = KueueDevError::disk_full("Image build for quay.io/x:dev", "podman");
+        assert!(err.to_string().contains("no space left on device"));
+        assert!(err.suggestions[0].contains("podman system prune"));
+    }
+
     #[test]
     fn test_error_suggestions() {
         let err = KueueDevError::new("test")
@@ -0,0 +1,99 @@
+//! Shared HTTP client for manifest and release downloads
+
+use anyhow::{Context, Result};
+use reqwest::blocking::{Client, ClientBuilder};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// User-Agent sent with every request
+pub const USER_AGENT: &str = concat!("kueue-dev/", env!("CARGO_PKG_VERSION"));
+
+/// Time allowed to establish a connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Time allowed for a whole request, including reading the body
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(300);
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// Builder with the User-Agent and timeouts. Proxies are read from
+/// `HTTPS_PROXY`, `HTTP_PROXY` and `NO_PROXY`.
+fn builder() -> ClientBuilder {
+    Client::builder()
+        .user_agent(USER_AGENT)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .timeout(REQUEST_TIMEOUT)
+}
+
+/// The shared client, built on first use
+pub fn client() -> Result<&'static Client> {
+    if let Some(client) = CLIENT.get() {
+        return Ok(client);
+    }
+    let client = builder().build().context("Failed to create HTTP client")?;
+    Ok(CLIENT.get_or_init(|| client))
+}
+
+/// GET `url`, failing on non-success status codes
+fn get(url: &str) -> Result<reqwest::blocking::Response> {
+    client()?
+        .get(url)
+        .send()
+        .and_then(|response| response.error_for_status())
+        .with_context(|| format!("Failed to download {}", url))
+}
+
+/// Download `url` as text
+pub fn download_text(url: &str) -> Result<String> {
+    get(url)?
+        .text()
+        .with_context(|| format!("Failed to read response from {}", url))
+}
+
+/// Download `url` into the file at `path`, replacing its contents
+pub fn download_to_file(url: &str, path: &Path) -> Result<()> {
+    let mut response = get(url)?;
+    let mut file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    response
+        .copy_to(&mut file)
+        .with_context(|| format!("Failed to write {} to {}", url, path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_client_sends_user_agent() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = [0u8; 4096];
+            let len = stream.read(&mut request).unwrap();
+            stream
+                .write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok")
+                .unwrap();
+            String::from_utf8_lossy(&request[..len]).to_lowercase()
+        });
+
+        // Bypass any proxy configured in the environment
+        let client = builder().no_proxy().build().unwrap();
+        let body = client.get(&url).send().unwrap().text().unwrap();
+        assert_eq!(body, "ok");
+
+        let request = server.join().unwrap();
+        assert!(
+            request.contains(&format!("user-agent: {}\r\n", USER_AGENT.to_lowercase())),
+            "{}",
+            request
+        );
+        assert!(USER_AGENT.starts_with("kueue-dev/"));
+    }
+}
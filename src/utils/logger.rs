@@ -1,6 +1,52 @@
 //! Logging utilities using the tracing framework
 
+use std::ffi::OsStr;
 use std::fmt::Display;
+use std::process::Stdio;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set when stdout is reserved for machine-readable output (e.g. `--print-export`)
+static STDOUT_RESERVED: AtomicBool = AtomicBool::new(false);
+
+/// Set by `--quiet`
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Only warnings and errors are logged; informational output is suppressed
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Whether `--quiet` was given
+pub fn is_quiet() -> bool {
+    QUIET.load(Ordering::Relaxed)
+}
+
+/// Reserve stdout for machine-readable output. Child processes that honor
+/// [`child_stdout`] write to stderr instead so their output doesn't mix in.
+pub fn reserve_stdout() {
+    STDOUT_RESERVED.store(true, Ordering::Relaxed);
+}
+
+/// Stdout to hand to a child process: inherited, or stderr when stdout is reserved
+pub fn child_stdout() -> Stdio {
+    if STDOUT_RESERVED.load(Ordering::Relaxed) {
+        Stdio::from(std::io::stderr())
+    } else {
+        Stdio::inherit()
+    }
+}
+
+/// Whether output should be colored. Colors are off with `--no-color`, a
+/// non-empty `NO_COLOR` (https://no-color.org) or `colors.enabled = false`.
+pub fn colors_enabled(no_color_flag: bool, no_color_env: Option<&OsStr>, setting: bool) -> bool {
+    !no_color_flag && no_color_env.is_none_or(OsStr::is_empty) && setting
+}
+
+/// Turn off colors in messages built with `colored`, such as the preflight
+/// and error displays
+pub fn disable_colors() {
+    colored::control::set_override(false);
+}
 
 /// Log an informational message (wrapper around tracing::info)
 pub fn log_info<T: Display>(msg: T) {
@@ -52,4 +98,13 @@ mod tests {
         log_warn("Test warning message");
         log_error("Test error message");
     }
+
+    #[test]
+    fn test_colors_enabled() {
+        assert!(colors_enabled(false, None, true));
+        assert!(colors_enabled(false, Some(OsStr::new("")), true));
+        assert!(!colors_enabled(true, None, true));
+        assert!(!colors_enabled(false, Some(OsStr::new("1")), true));
+        assert!(!colors_enabled(false, None, false));
+    }
 }
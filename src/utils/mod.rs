@@ -2,6 +2,7 @@
 
 pub mod container;
 pub mod errors;
+pub mod http;
 pub mod logger;
 pub mod paths;
 pub mod preflight;
@@ -10,14 +11,22 @@ pub mod progress;
 pub mod prompt;
 
 // Re-export commonly used items
-pub use container::ContainerRuntime;
-pub use errors::{KueueDevError, display_error_and_exit, enhance_error};
-pub use logger::{log_error, log_info, log_warn};
+pub use container::{ContainerRuntime, LocalImage};
+pub use errors::{
+    KueueDevError, disk_full_lines, display_error_and_exit, enhance_error, is_transient_error,
+};
+pub use logger::{
+    child_stdout, colors_enabled, disable_colors, is_quiet, log_error, log_info, log_warn,
+    reserve_stdout,
+};
 pub use paths::{
     ensure_operator_source_directory, get_operator_source_path, operator_source_join,
-    operator_source_path, set_cli_operator_source,
+    operator_source_path, resolve_kubeconfig_value, set_cli_operator_source,
 };
 pub use preflight::{PreflightChecker, run_preflight_with_confirm};
 pub use prereqs::{CommonPrereqs, Prerequisite};
-pub use progress::{create_progress_bar, create_spinner, with_spinner, with_spinner_result};
-pub use prompt::{confirm, confirm_default_yes, wait_for_enter};
+pub use progress::{
+    ImageLoadProgress, WaitProgress, create_progress_bar, create_spinner, with_spinner,
+    with_spinner_result,
+};
+pub use prompt::{confirm, confirm_default_yes, input_with_default, wait_for_enter};
@@ -100,6 +100,50 @@ pub fn operator_source_join(relative_path: &str) -> PathBuf {
     }
 }
 
+/// Resolve a kubeconfig value that may be a single file or a list of files joined
+/// with the platform path separator (e.g. `KUBECONFIG=a:b:c`).
+///
+/// A single path must exist and is canonicalized. A multi-path value is passed
+/// through as a merged view (kubectl and friends merge it themselves), keeping
+/// only the entries that exist; at least one entry must exist.
+pub fn resolve_kubeconfig_value(value: &std::ffi::OsStr) -> Result<PathBuf> {
+    let paths: Vec<PathBuf> = std::env::split_paths(value)
+        .filter(|p| !p.as_os_str().is_empty())
+        .collect();
+
+    match paths.len() {
+        0 => Err(anyhow::anyhow!("Kubeconfig path is empty")),
+        1 => {
+            let path = paths.into_iter().next().unwrap();
+            if !path.exists() {
+                return Err(anyhow::anyhow!(
+                    "Kubeconfig not found at {}. Please create cluster first.",
+                    path.display()
+                ));
+            }
+            Ok(path.canonicalize().unwrap_or(path))
+        }
+        _ => {
+            let existing: Vec<PathBuf> = paths
+                .iter()
+                .filter(|p| p.exists())
+                .map(|p| p.canonicalize().unwrap_or_else(|_| p.clone()))
+                .collect();
+
+            if existing.is_empty() {
+                return Err(anyhow::anyhow!(
+                    "None of the kubeconfig files in {} exist",
+                    value.to_string_lossy()
+                ));
+            }
+
+            let joined =
+                std::env::join_paths(&existing).context("Failed to join kubeconfig paths")?;
+            Ok(PathBuf::from(joined))
+        }
+    }
+}
+
 /// Get a path relative to the operator source directory as a Path reference
 /// If operator source path is configured, uses that path.
 /// Otherwise, assumes we're already in the operator source directory and uses current dir.
@@ -117,6 +161,47 @@ mod tests {
         assert!(path.ends_with("deploy"));
     }
 
+    #[test]
+    fn test_resolve_kubeconfig_single_path() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let resolved = resolve_kubeconfig_value(file.path().as_os_str()).unwrap();
+        assert_eq!(resolved, file.path().canonicalize().unwrap());
+    }
+
+    #[test]
+    fn test_resolve_kubeconfig_missing_single_path() {
+        let result = resolve_kubeconfig_value(std::ffi::OsStr::new("/nonexistent/kubeconfig"));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_resolve_kubeconfig_multiple_paths() {
+        let a = tempfile::NamedTempFile::new().unwrap();
+        let b = tempfile::NamedTempFile::new().unwrap();
+        let value = std::env::join_paths([
+            a.path(),
+            std::path::Path::new("/nonexistent/kubeconfig"),
+            b.path(),
+        ])
+        .unwrap();
+
+        let resolved = resolve_kubeconfig_value(&value).unwrap();
+        let parts: Vec<PathBuf> = std::env::split_paths(resolved.as_os_str()).collect();
+        assert_eq!(
+            parts,
+            vec![
+                a.path().canonicalize().unwrap(),
+                b.path().canonicalize().unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_kubeconfig_multiple_paths_none_exist() {
+        let value = std::env::join_paths(["/nonexistent/a", "/nonexistent/b"]).unwrap();
+        assert!(resolve_kubeconfig_value(&value).is_err());
+    }
+
     #[test]
     fn test_get_operator_source_path() {
         // This test may return None if config is not set, which is valid
@@ -1,14 +1,21 @@
 //! Preflight validation checks before deployment
 
+use crate::k8s::target::KubeTarget;
+use crate::utils::ContainerRuntime;
 use anyhow::Result;
 use colored::Colorize;
 use std::path::Path;
 use std::process::Command;
 
+/// Free space threshold used if the config file cannot be read
+const DEFAULT_MIN_FREE_DISK_GB: u64 = 10;
+
 /// Result of a preflight check
 #[derive(Debug, Clone)]
 pub enum CheckResult {
     Pass(String),
+    /// Informational note; not counted as a warning
+    Info(String),
     Warn(String),
     Fail(String),
 }
@@ -27,6 +34,9 @@ impl CheckResult {
             CheckResult::Pass(msg) => {
                 println!("  {} {}", "✓".green(), msg);
             }
+            CheckResult::Info(msg) => {
+                println!("  {} {}", "ℹ".blue(), msg);
+            }
             CheckResult::Warn(msg) => {
                 println!("  {} {}", "⚠".yellow(), msg);
             }
@@ -55,7 +65,20 @@ impl PreflightChecker {
         self.check_cluster_connection(kubeconfig)?;
         self.check_cluster_version(kubeconfig)?;
         self.check_node_count(kubeconfig)?;
-        self.check_existing_installation(kubeconfig)?;
+
+        let settings = crate::config::settings::Settings::load().ok();
+        let operator_namespace = settings
+            .as_ref()
+            .map_or(crate::install::operator::DEFAULT_OPERATOR_NAMESPACE, |s| {
+                s.operator.namespace.as_str()
+            });
+        self.check_existing_installation(kubeconfig, operator_namespace)?;
+        self.check_kind_available()?;
+
+        let min_free_gb = settings
+            .as_ref()
+            .map_or(DEFAULT_MIN_FREE_DISK_GB, |s| s.behavior.min_free_disk_gb);
+        self.check_disk_space(min_free_gb)?;
 
         Ok(())
     }
@@ -90,10 +113,7 @@ impl PreflightChecker {
 
     /// Check if cluster is reachable
     fn check_cluster_connection(&mut self, kubeconfig: Option<&Path>) -> Result<()> {
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
+        let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
         cmd.args(["cluster-info"]);
 
@@ -113,10 +133,7 @@ impl PreflightChecker {
 
     /// Check Kubernetes version
     fn check_cluster_version(&mut self, kubeconfig: Option<&Path>) -> Result<()> {
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
+        let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
         cmd.args(["version", "--short", "--output=json"]);
 
@@ -146,10 +163,7 @@ impl PreflightChecker {
 
     /// Check node count
     fn check_node_count(&mut self, kubeconfig: Option<&Path>) -> Result<()> {
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
+        let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
         cmd.args(["get", "nodes", "--no-headers"]);
 
@@ -183,13 +197,14 @@ impl PreflightChecker {
     }
 
     /// Check for existing kueue installation
-    fn check_existing_installation(&mut self, kubeconfig: Option<&Path>) -> Result<()> {
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
+    fn check_existing_installation(
+        &mut self,
+        kubeconfig: Option<&Path>,
+        operator_namespace: &str,
+    ) -> Result<()> {
+        let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
-        cmd.args(["get", "namespace", "openshift-kueue-operator"]);
+        cmd.args(["get", "namespace", operator_namespace]);
 
         match cmd.output() {
             Ok(output) if output.status.success() => {
@@ -207,13 +222,63 @@ impl PreflightChecker {
         Ok(())
     }
 
+    /// Check that the kind binary is installed and runs
+    pub fn check_kind_available(&mut self) -> Result<()> {
+        match Command::new("kind").arg("version").output() {
+            Ok(output) if output.status.success() => {
+                let version = String::from_utf8_lossy(&output.stdout);
+                self.checks.push(CheckResult::Pass(format!(
+                    "kind is available: {}",
+                    version.trim()
+                )));
+            }
+            Ok(_) => {
+                self.checks.push(CheckResult::Warn(
+                    "kind is installed but 'kind version' failed".to_string(),
+                ));
+            }
+            Err(_) => {
+                self.checks.push(CheckResult::Warn(
+                    "kind not found (needed for kind cluster workflows)".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check free disk space for the container runtime's image storage
+    pub fn check_disk_space(&mut self, min_free_gb: u64) -> Result<()> {
+        let Ok(runtime) = ContainerRuntime::detect() else {
+            self.checks.push(CheckResult::Warn(
+                "No container runtime (docker/podman) found".to_string(),
+            ));
+            return Ok(());
+        };
+
+        // The storage root may live inside a VM (e.g. Docker Desktop), where it
+        // cannot be inspected from the host
+        let free_kb = runtime
+            .storage_root()
+            .ok()
+            .and_then(|root| available_kb(&root));
+        let Some(free_kb) = free_kb else {
+            self.checks.push(CheckResult::Info(format!(
+                "Could not determine free disk space for {} storage",
+                runtime
+            )));
+            return Ok(());
+        };
+
+        self.checks
+            .push(disk_space_result(&runtime, free_kb, min_free_gb));
+        Ok(())
+    }
+
     /// Check if specific CRDs exist
     pub fn check_crds(&mut self, crds: &[&str], kubeconfig: Option<&Path>) -> Result<()> {
         for crd in crds {
-            let mut cmd = Command::new("kubectl");
-            if let Some(kc) = kubeconfig {
-                cmd.env("KUBECONFIG", kc);
-            }
+            let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
             cmd.args(["get", "crd", crd]);
 
@@ -236,10 +301,7 @@ impl PreflightChecker {
 
     /// Check cluster resources
     pub fn check_resources(&mut self, kubeconfig: Option<&Path>) -> Result<()> {
-        let mut cmd = Command::new("kubectl");
-        if let Some(kc) = kubeconfig {
-            cmd.env("KUBECONFIG", kc);
-        }
+        let mut cmd = KubeTarget::new(kubeconfig).kubectl();
 
         cmd.args(["top", "nodes"]);
 
@@ -250,10 +312,9 @@ impl PreflightChecker {
                 ));
             }
             _ => {
-                self.checks.push(CheckResult::Warn(
-                    "Could not check cluster resources (metrics-server may not be installed)"
-                        .to_string(),
-                ));
+                // kind clusters ship without metrics-server, so this is expected there
+                let is_kind = crate::k8s::kind::is_kind_context(kubeconfig).unwrap_or(false);
+                self.checks.push(metrics_unavailable_result(is_kind));
             }
         }
 
@@ -261,6 +322,68 @@ impl PreflightChecker {
     }
 }
 
+/// Free space in KiB on the filesystem holding `path`
+fn available_kb(path: &Path) -> Option<u64> {
+    let output = Command::new("df").arg("-Pk").arg(path).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    parse_df_available_kb(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse the "Available" column of POSIX `df -Pk` output
+fn parse_df_available_kb(output: &str) -> Option<u64> {
+    output
+        .lines()
+        .nth(1)?
+        .split_whitespace()
+        .nth(3)?
+        .parse()
+        .ok()
+}
+
+fn disk_space_result(runtime: &ContainerRuntime, free_kb: u64, min_free_gb: u64) -> CheckResult {
+    let free_gb = free_kb as f64 / (1024.0 * 1024.0);
+    if free_kb >= min_free_gb * 1024 * 1024 {
+        return CheckResult::Pass(format!("{} storage has {:.1} GiB free", runtime, free_gb));
+    }
+
+    let reclaimable = runtime
+        .reclaimable_space()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(kind, size)| format!("{} {}", kind, size))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let hint = if reclaimable.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "; reclaimable: {} (try '{} system prune')",
+            reclaimable, runtime
+        )
+    };
+
+    CheckResult::Warn(format!(
+        "{} storage has only {:.1} GiB free (recommended: >= {} GiB){}",
+        runtime, free_gb, min_free_gb, hint
+    ))
+}
+
+/// Result for a cluster where `kubectl top` does not work
+fn metrics_unavailable_result(is_kind: bool) -> CheckResult {
+    if is_kind {
+        CheckResult::Info(
+            "Resource metrics not available (kind clusters do not include metrics-server)"
+                .to_string(),
+        )
+    } else {
+        CheckResult::Warn(
+            "Could not check cluster resources (metrics-server may not be installed)".to_string(),
+        )
+    }
+}
+
 impl Default for PreflightChecker {
     fn default() -> Self {
         Self::new()
@@ -314,6 +437,32 @@ mod tests {
         assert!(!fail.is_warning());
     }
 
+    #[test]
+    fn test_metrics_unavailable_result() {
+        let kind = metrics_unavailable_result(true);
+        assert!(matches!(kind, CheckResult::Info(_)));
+        assert!(!kind.is_warning());
+        assert!(!kind.is_error());
+
+        assert!(metrics_unavailable_result(false).is_warning());
+    }
+
+    #[test]
+    fn test_parse_df_available_kb() {
+        let output = "Filesystem     1024-blocks      Used Available Capacity Mounted on\n\
+                      /dev/nvme0n1p2   486903968 401234560  60880544      87% /\n";
+        assert_eq!(parse_df_available_kb(output), Some(60880544));
+        assert_eq!(parse_df_available_kb("Filesystem 1024-blocks\n"), None);
+        assert_eq!(parse_df_available_kb(""), None);
+    }
+
+    #[test]
+    fn test_disk_space_result_passes_above_threshold() {
+        // 20 GiB free with a 10 GiB threshold
+        let result = disk_space_result(&ContainerRuntime::Docker, 20 * 1024 * 1024, 10);
+        assert!(matches!(result, CheckResult::Pass(_)));
+    }
+
     #[test]
     fn test_preflight_checker_new() {
         let checker = PreflightChecker::new();
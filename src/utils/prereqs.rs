@@ -111,6 +111,14 @@ impl CommonPrereqs {
         CommandPrereq::new("helm", "Install from: https://helm.sh/docs/intro/install/")
     }
 
+    /// Get opm (OLM catalog builder) prerequisite
+    pub fn opm() -> CommandPrereq {
+        CommandPrereq::new(
+            "opm",
+            "Install from: https://github.com/operator-framework/operator-registry/releases",
+        )
+    }
+
     /// Check all prerequisites and return detailed results
     /// Returns (found_tools, missing_tools)
     pub fn check_all(prereqs: &[&dyn Prerequisite]) -> (Vec<String>, Vec<(String, String)>) {
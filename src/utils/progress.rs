@@ -1,11 +1,19 @@
 //! Progress indicators for long-running operations
 
-use indicatif::{MultiProgress, ProgressBar, ProgressStyle};
+use indicatif::{MultiProgress, ProgressBar, ProgressDrawTarget, ProgressStyle};
 use std::time::Duration;
 
+/// Hide `pb` under `--quiet`
+fn hide_if_quiet(pb: ProgressBar) -> ProgressBar {
+    if super::is_quiet() {
+        pb.set_draw_target(ProgressDrawTarget::hidden());
+    }
+    pb
+}
+
 /// Create a spinner for indeterminate operations
 pub fn create_spinner(message: &str) -> ProgressBar {
-    let pb = ProgressBar::new_spinner();
+    let pb = hide_if_quiet(ProgressBar::new_spinner());
     pb.set_style(
         ProgressStyle::default_spinner()
             .template("{spinner:.cyan} {msg}")
@@ -19,7 +27,7 @@ pub fn create_spinner(message: &str) -> ProgressBar {
 
 /// Create a progress bar for determinate operations
 pub fn create_progress_bar(len: u64, message: &str) -> ProgressBar {
-    let pb = ProgressBar::new(len);
+    let pb = hide_if_quiet(ProgressBar::new(len));
     pb.set_style(
         ProgressStyle::default_bar()
             .template("{msg} [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
@@ -30,9 +38,14 @@ pub fn create_progress_bar(len: u64, message: &str) -> ProgressBar {
     pb
 }
 
-/// Create a multi-progress container for parallel operations
+/// Create a multi-progress container for parallel operations; its bars are
+/// hidden under `--quiet`
 pub fn create_multi_progress() -> MultiProgress {
-    MultiProgress::new()
+    if super::is_quiet() {
+        MultiProgress::with_draw_target(ProgressDrawTarget::hidden())
+    } else {
+        MultiProgress::new()
+    }
 }
 
 /// Progress wrapper for downloads
@@ -1,7 +1,7 @@
 //! User prompt utilities for interactive confirmation
 
 use anyhow::Result;
-use dialoguer::Confirm;
+use dialoguer::{Confirm, Input};
 
 /// Ask user for yes/no confirmation
 /// Respects the confirm_destructive setting from config
@@ -33,6 +33,16 @@ pub fn confirm_default_yes(prompt: &str) -> Result<bool> {
     Ok(result)
 }
 
+/// Ask user for a line of text, returning `default` when nothing is entered
+pub fn input_with_default(prompt: &str, default: &str) -> Result<String> {
+    let result = Input::new()
+        .with_prompt(prompt)
+        .default(default.to_string())
+        .interact_text()?;
+
+    Ok(result)
+}
+
 /// Wait for user to press Enter
 pub fn wait_for_enter(message: &str) -> Result<()> {
     use std::io::{self, BufRead};